@@ -0,0 +1,292 @@
+//! Example game contract built on the `pallet-chain-extension` runtime extension.
+//!
+//! The contract holds `pallet_erc1155` tokens, rewards players with them and trades them on the
+//! `pallet_dex` pool. Build it with `cargo contract build` and deploy it with
+//! `instantiate_with_code` passing the pool address.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_env::{DefaultEnvironment, Environment};
+use ink_lang as ink;
+
+pub type TokenId = u32;
+
+/// Mirrors the function ids and status codes of `pallet-chain-extension`.
+#[ink::chain_extension]
+pub trait Erc1155Extension {
+	type ErrorCode = ExtensionError;
+
+	#[ink(extension = 0x0001)]
+	fn balance_of(
+		owner: ink_env::AccountId,
+		token_id: TokenId,
+	) -> Result<ink_env::Balance, ExtensionError>;
+
+	#[ink(extension = 0x0002)]
+	fn total_supply(token_id: TokenId) -> Result<ink_env::Balance, ExtensionError>;
+
+	#[ink(extension = 0x0003)]
+	fn is_approved_for_all(
+		owner: ink_env::AccountId,
+		operator: ink_env::AccountId,
+	) -> Result<bool, ExtensionError>;
+
+	#[ink(extension = 0x0004)]
+	fn transfer(
+		to: ink_env::AccountId,
+		token_id: TokenId,
+		amount: ink_env::Balance,
+	) -> Result<(), ExtensionError>;
+
+	#[ink(extension = 0x0005)]
+	fn set_approval_for_all(
+		operator: ink_env::AccountId,
+		approved: bool,
+	) -> Result<(), ExtensionError>;
+
+	#[ink(extension = 0x0101)]
+	fn quote(
+		token_id: TokenId,
+		amount: ink_env::Balance,
+	) -> Result<ink_env::Balance, ExtensionError>;
+
+	#[ink(extension = 0x0102)]
+	fn buy_token(
+		token_id: TokenId,
+		amount: ink_env::Balance,
+	) -> Result<ink_env::Balance, ExtensionError>;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ExtensionError {
+	/// `pallet_erc1155` rejected the call.
+	TokenError,
+	/// `pallet_dex` rejected the call.
+	DexError,
+	/// The output of the extension could not be decoded.
+	InvalidOutput,
+}
+
+impl ink_env::chain_extension::FromStatusCode for ExtensionError {
+	fn from_status_code(status_code: u32) -> Result<(), Self> {
+		match status_code {
+			0 => Ok(()),
+			1 => Err(Self::TokenError),
+			2 => Err(Self::DexError),
+			_ => panic!("unknown status code"),
+		}
+	}
+}
+
+impl From<scale::Error> for ExtensionError {
+	fn from(_: scale::Error) -> Self {
+		Self::InvalidOutput
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum CustomEnvironment {}
+
+impl Environment for CustomEnvironment {
+	const MAX_EVENT_TOPICS: usize = <DefaultEnvironment as Environment>::MAX_EVENT_TOPICS;
+
+	type AccountId = <DefaultEnvironment as Environment>::AccountId;
+	type Balance = <DefaultEnvironment as Environment>::Balance;
+	type Hash = <DefaultEnvironment as Environment>::Hash;
+	type BlockNumber = <DefaultEnvironment as Environment>::BlockNumber;
+	type Timestamp = <DefaultEnvironment as Environment>::Timestamp;
+
+	type ChainExtension = Erc1155Extension;
+}
+
+#[ink::contract(env = crate::CustomEnvironment)]
+mod game {
+	use super::{ExtensionError, TokenId};
+
+	#[ink(storage)]
+	pub struct Game {
+		owner: AccountId,
+		pool: AccountId,
+	}
+
+	#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+	#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+	pub enum Error {
+		NotOwner,
+		/// The pool returns less than the requested minimum.
+		Slippage,
+		Extension(ExtensionError),
+	}
+
+	impl From<ExtensionError> for Error {
+		fn from(error: ExtensionError) -> Self {
+			Self::Extension(error)
+		}
+	}
+
+	#[ink(event)]
+	pub struct Rewarded {
+		#[ink(topic)]
+		player: AccountId,
+		token_id: TokenId,
+		amount: Balance,
+	}
+
+	#[ink(event)]
+	pub struct Swapped {
+		token_id: TokenId,
+		amount: Balance,
+		bought: Balance,
+	}
+
+	impl Game {
+		/// Approves `pool` so the contract can sell its tokens on the dex.
+		#[ink(constructor)]
+		pub fn new(pool: AccountId) -> Self {
+			Self::env()
+				.extension()
+				.set_approval_for_all(pool, true)
+				.expect("approving an operator cannot fail");
+			Self { owner: Self::env().caller(), pool }
+		}
+
+		#[ink(message)]
+		pub fn pool(&self) -> AccountId {
+			self.pool
+		}
+
+		/// Tokens of `token_id` held by the contract.
+		#[ink(message)]
+		pub fn balance_of(&self, token_id: TokenId) -> Result<Balance, Error> {
+			Ok(self.env().extension().balance_of(self.env().account_id(), token_id)?)
+		}
+
+		/// Pay out `amount` of `token_id` from the contract to `player`.
+		#[ink(message)]
+		pub fn reward(
+			&mut self,
+			player: AccountId,
+			token_id: TokenId,
+			amount: Balance,
+		) -> Result<(), Error> {
+			self.ensure_owner()?;
+			self.env().extension().transfer(player, token_id, amount)?;
+			self.env().emit_event(Rewarded { player, token_id, amount });
+			Ok(())
+		}
+
+		/// Sell `amount` of `token_id` on the dex for at least `min_bought` of the paired token.
+		#[ink(message)]
+		pub fn swap(
+			&mut self,
+			token_id: TokenId,
+			amount: Balance,
+			min_bought: Balance,
+		) -> Result<Balance, Error> {
+			self.ensure_owner()?;
+			if self.env().extension().quote(token_id, amount)? < min_bought {
+				return Err(Error::Slippage)
+			}
+			let bought = self.env().extension().buy_token(token_id, amount)?;
+			self.env().emit_event(Swapped { token_id, amount, bought });
+			Ok(bought)
+		}
+
+		fn ensure_owner(&self) -> Result<(), Error> {
+			if self.env().caller() != self.owner {
+				return Err(Error::NotOwner)
+			}
+			Ok(())
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use ink_lang as ink;
+		use scale::Encode;
+
+		const TRANSFER: u32 = 0x0004;
+		const SET_APPROVAL_FOR_ALL: u32 = 0x0005;
+		const DEX_QUOTE: u32 = 0x0101;
+		const DEX_BUY_TOKEN: u32 = 0x0102;
+
+		/// Answers a single function id with a fixed status code and output.
+		struct MockExtension {
+			func_id: u32,
+			status: u32,
+			output: Vec<u8>,
+		}
+
+		impl ink_env::test::ChainExtension for MockExtension {
+			fn func_id(&self) -> u32 {
+				self.func_id
+			}
+
+			fn call(&mut self, _input: &[u8], output: &mut Vec<u8>) -> u32 {
+				output.extend(&self.output);
+				self.status
+			}
+		}
+
+		fn register(func_id: u32, status: u32, output: impl Encode) {
+			ink_env::test::register_chain_extension(MockExtension {
+				func_id,
+				status,
+				output: output.encode(),
+			});
+		}
+
+		fn pool() -> AccountId {
+			AccountId::from([0x42; 32])
+		}
+
+		fn player() -> AccountId {
+			AccountId::from([0x07; 32])
+		}
+
+		fn new_game() -> Game {
+			register(SET_APPROVAL_FOR_ALL, 0, ());
+			Game::new(pool())
+		}
+
+		#[ink::test]
+		fn reward_works() {
+			let mut game = new_game();
+			register(TRANSFER, 0, ());
+			assert_eq!(game.reward(player(), 1, 10), Ok(()));
+			assert_eq!(ink_env::test::recorded_events().count(), 1);
+		}
+
+		#[ink::test]
+		fn reward_fails() {
+			let mut game = new_game();
+			register(TRANSFER, 1, ());
+			assert_eq!(
+				game.reward(player(), 1, 10),
+				Err(Error::Extension(ExtensionError::TokenError))
+			);
+			assert_eq!(ink_env::test::recorded_events().count(), 0);
+		}
+
+		#[ink::test]
+		fn swap_works() {
+			let mut game = new_game();
+			register(DEX_QUOTE, 0, 497u128);
+			register(DEX_BUY_TOKEN, 0, 497u128);
+			assert_eq!(game.swap(1, 100, 490), Ok(497));
+		}
+
+		#[ink::test]
+		fn swap_fails() {
+			let mut game = new_game();
+			register(DEX_QUOTE, 0, 497u128);
+			assert_eq!(game.swap(1, 100, 500), Err(Error::Slippage));
+
+			register(DEX_QUOTE, 2, ());
+			assert_eq!(game.swap(1, 100, 0), Err(Error::Extension(ExtensionError::DexError)));
+		}
+	}
+}
@@ -0,0 +1,107 @@
+//! HTTP/JSON API over the indexed records.
+//!
+//! - `GET /status`: the highest indexed and the highest final block.
+//! - `GET /accounts/<ss58>/transfers`: erc1155 transfers from or to the account.
+//! - `GET /accounts/<ss58>/swaps`: dex swaps of the account.
+//! - `GET /accounts/<ss58>/liquidity`: liquidity the account added to or removed from the dex.
+//!
+//! Account queries return the newest records first and take `limit` (at most 1000, 100 by
+//! default), `before` (the id of the last record of the previous page), `token_id` and
+//! `finalized=true` to leave out records of blocks that may still be reverted.
+
+use crate::{
+	db::{Db, Page},
+	Result,
+};
+use node_template_runtime::AccountId;
+use serde_json::{json, Value};
+use sp_core::crypto::Ss58Codec;
+use tiny_http::{Header, Method, Response, Server};
+
+const DEFAULT_LIMIT: u32 = 100;
+const MAX_LIMIT: u32 = 1_000;
+
+/// An error status and its message.
+type Error = (u16, String);
+
+fn bad_request(message: impl Into<String>) -> Error {
+	(400, message.into())
+}
+
+fn internal(e: Box<dyn std::error::Error + Send + Sync>) -> Error {
+	log::error!("query failed: {}", e);
+	(500, "internal error".into())
+}
+
+pub fn serve(addr: &str, db: Db) -> Result<()> {
+	let server = Server::http(addr)?;
+	log::info!("serving on http://{}", addr);
+	let content_type = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+		.expect("static header is valid; qed");
+	for request in server.incoming_requests() {
+		let result = match request.method() {
+			Method::Get => handle(&db, request.url()),
+			_ => Err((405, "only GET is supported".into())),
+		};
+		let (status, body) = match result {
+			Ok(body) => (200, body),
+			Err((status, message)) => (status, json!({ "error": message })),
+		};
+		let response = Response::from_string(body.to_string())
+			.with_status_code(status)
+			.with_header(content_type.clone());
+		if let Err(e) = request.respond(response) {
+			log::debug!("failed to respond: {}", e);
+		}
+	}
+	Ok(())
+}
+
+pub fn handle(db: &Db, url: &str) -> std::result::Result<Value, Error> {
+	let (path, query) = url.split_once('?').unwrap_or((url, ""));
+	let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+	match segments.as_slice() {
+		["status"] => {
+			let tip = db.tip().map_err(internal)?.map(|(number, _)| number);
+			let finalized = db.finalized().map_err(internal)?;
+			Ok(json!({ "tip": tip, "finalized": finalized }))
+		},
+		["accounts", account, records] => {
+			// the stored form, whatever SS58 format the account was given in
+			let account = AccountId::from_ss58check(account)
+				.map_err(|_| bad_request("invalid account"))?
+				.to_ss58check();
+			let page = parse_page(query)?;
+			let records = match *records {
+				"transfers" =>
+					serde_json::to_value(db.transfers(&account, &page).map_err(internal)?),
+				"swaps" => serde_json::to_value(db.swaps(&account, &page).map_err(internal)?),
+				"liquidity" =>
+					serde_json::to_value(db.liquidity(&account, &page).map_err(internal)?),
+				_ => return Err((404, "not found".into())),
+			};
+			records.map_err(|e| internal(e.into()))
+		},
+		_ => Err((404, "not found".into())),
+	}
+}
+
+pub fn parse_page(query: &str) -> std::result::Result<Page, Error> {
+	let mut page =
+		Page { before: None, limit: DEFAULT_LIMIT, token_id: None, finalized_only: false };
+	for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+		let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+		let invalid = || bad_request(format!("invalid {}", key));
+		match key {
+			"before" => page.before = Some(value.parse().map_err(|_| invalid())?),
+			"limit" => page.limit = value.parse().map_err(|_| invalid())?,
+			"token_id" => page.token_id = Some(value.parse().map_err(|_| invalid())?),
+			"finalized" => page.finalized_only = value.parse().map_err(|_| invalid())?,
+			_ => return Err(bad_request(format!("unknown parameter {}", key))),
+		}
+	}
+	if page.limit == 0 || page.limit > MAX_LIMIT {
+		return Err(bad_request(format!("limit must be between 1 and {}", MAX_LIMIT)))
+	}
+	Ok(page)
+}
@@ -0,0 +1,379 @@
+//! SQLite storage of the indexed blocks and their records.
+//!
+//! Records reference their block and are deleted with it when a fork is reverted. Row ids are
+//! never reused, so they stay valid as `before` cursors across reverts.
+
+use crate::{
+	decode::{Kind, Record},
+	Result,
+};
+use node_template_runtime::{AccountId, BlockNumber, Hash};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use serde::Serialize;
+use sp_core::{crypto::Ss58Codec, hexdisplay::HexDisplay};
+use std::{path::Path, time::Duration};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS blocks (
+	number INTEGER PRIMARY KEY,
+	hash BLOB NOT NULL,
+	parent_hash BLOB NOT NULL,
+	finalized INTEGER NOT NULL DEFAULT 0
+);
+CREATE TABLE IF NOT EXISTS transfers (
+	id INTEGER PRIMARY KEY AUTOINCREMENT,
+	block_number INTEGER NOT NULL REFERENCES blocks (number) ON DELETE CASCADE,
+	event_index INTEGER NOT NULL,
+	extrinsic_index INTEGER,
+	operator TEXT NOT NULL,
+	from_account TEXT,
+	to_account TEXT,
+	token_id INTEGER NOT NULL,
+	amount TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS transfers_block ON transfers (block_number);
+CREATE INDEX IF NOT EXISTS transfers_from ON transfers (from_account, id);
+CREATE INDEX IF NOT EXISTS transfers_to ON transfers (to_account, id);
+CREATE TABLE IF NOT EXISTS swaps (
+	id INTEGER PRIMARY KEY AUTOINCREMENT,
+	block_number INTEGER NOT NULL REFERENCES blocks (number) ON DELETE CASCADE,
+	event_index INTEGER NOT NULL,
+	extrinsic_index INTEGER,
+	account TEXT NOT NULL,
+	sold_token INTEGER NOT NULL,
+	sold_amount TEXT NOT NULL,
+	bought_token INTEGER NOT NULL,
+	bought_amount TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS swaps_block ON swaps (block_number);
+CREATE INDEX IF NOT EXISTS swaps_account ON swaps (account, id);
+CREATE TABLE IF NOT EXISTS liquidity (
+	id INTEGER PRIMARY KEY AUTOINCREMENT,
+	block_number INTEGER NOT NULL REFERENCES blocks (number) ON DELETE CASCADE,
+	event_index INTEGER NOT NULL,
+	extrinsic_index INTEGER,
+	account TEXT NOT NULL,
+	deposit INTEGER NOT NULL,
+	first_token INTEGER NOT NULL,
+	first_amount TEXT NOT NULL,
+	second_token INTEGER NOT NULL,
+	second_amount TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS liquidity_block ON liquidity (block_number);
+CREATE INDEX IF NOT EXISTS liquidity_account ON liquidity (account, id);
+";
+
+/// Columns of `Location`, selected first by every record query.
+const LOCATION_COLUMNS: &str =
+	"x.id, x.block_number, b.hash, b.finalized, x.event_index, x.extrinsic_index";
+
+/// A page of the records of an account, newest first.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Page {
+	/// Only records with a lower id.
+	pub before: Option<i64>,
+	pub limit: u32,
+	/// Only records involving this token.
+	pub token_id: Option<u32>,
+	/// Leave out records of blocks that may still be reverted.
+	pub finalized_only: bool,
+}
+
+/// Where a record comes from.
+#[derive(Debug, Serialize)]
+pub struct Location {
+	pub id: i64,
+	pub block_number: BlockNumber,
+	pub block_hash: String,
+	pub finalized: bool,
+	pub event_index: u32,
+	pub extrinsic_index: Option<u32>,
+}
+
+impl Location {
+	fn from_row(row: &Row) -> rusqlite::Result<Self> {
+		Ok(Location {
+			id: row.get(0)?,
+			block_number: row.get(1)?,
+			block_hash: to_hex(&row.get::<_, Vec<u8>>(2)?),
+			finalized: row.get(3)?,
+			event_index: row.get(4)?,
+			extrinsic_index: row.get(5)?,
+		})
+	}
+}
+
+#[derive(Debug, Serialize)]
+pub struct Transfer {
+	#[serde(flatten)]
+	pub location: Location,
+	pub operator: String,
+	pub from: Option<String>,
+	pub to: Option<String>,
+	pub token_id: u32,
+	/// Balances are strings, JSON numbers lose precision above 2^53.
+	pub amount: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Swap {
+	#[serde(flatten)]
+	pub location: Location,
+	pub account: String,
+	pub sold_token: u32,
+	pub sold_amount: String,
+	pub bought_token: u32,
+	pub bought_amount: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Liquidity {
+	#[serde(flatten)]
+	pub location: Location,
+	pub account: String,
+	/// Liquidity added to the pool, removed otherwise.
+	pub deposit: bool,
+	pub first_token: u32,
+	pub first_amount: String,
+	pub second_token: u32,
+	pub second_amount: String,
+}
+
+/// `?1` to `?5` of the record queries.
+macro_rules! page_params {
+	($account:expr, $page:expr) => {
+		params![
+			$account,
+			$page.before.unwrap_or(i64::MAX),
+			$page.token_id,
+			$page.finalized_only,
+			$page.limit
+		]
+	};
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+	format!("0x{}", HexDisplay::from(&bytes))
+}
+
+fn ss58(who: &AccountId) -> String {
+	who.to_ss58check()
+}
+
+pub struct Db {
+	conn: Connection,
+}
+
+impl Db {
+	pub fn open(path: &Path) -> Result<Self> {
+		let conn = Connection::open(path)?;
+		// the indexer writes while the API reads from another connection
+		conn.busy_timeout(Duration::from_secs(5))?;
+		conn.execute_batch("PRAGMA journal_mode = WAL;")?;
+		Self::init(conn)
+	}
+
+	#[cfg(test)]
+	pub fn open_in_memory() -> Result<Self> {
+		Self::init(Connection::open_in_memory()?)
+	}
+
+	fn init(conn: Connection) -> Result<Self> {
+		conn.execute_batch("PRAGMA foreign_keys = ON;")?;
+		conn.execute_batch(SCHEMA)?;
+		Ok(Db { conn })
+	}
+
+	/// Highest indexed block.
+	pub fn tip(&self) -> Result<Option<(BlockNumber, Hash)>> {
+		let tip = self
+			.conn
+			.query_row("SELECT number, hash FROM blocks ORDER BY number DESC LIMIT 1", [], |row| {
+				Ok((row.get(0)?, row.get::<_, Vec<u8>>(1)?))
+			})
+			.optional()?;
+		Ok(tip.map(|(number, hash)| (number, Hash::from_slice(&hash))))
+	}
+
+	pub fn block_hash(&self, number: BlockNumber) -> Result<Option<Hash>> {
+		let hash = self
+			.conn
+			.query_row("SELECT hash FROM blocks WHERE number = ?1", [number], |row| {
+				row.get::<_, Vec<u8>>(0)
+			})
+			.optional()?;
+		Ok(hash.map(|hash| Hash::from_slice(&hash)))
+	}
+
+	/// Highest indexed block known to be final.
+	pub fn finalized(&self) -> Result<Option<BlockNumber>> {
+		let finalized = self.conn.query_row(
+			"SELECT MAX(number) FROM blocks WHERE finalized = 1",
+			[],
+			|row| row.get(0),
+		)?;
+		Ok(finalized)
+	}
+
+	/// Mark `number` and the blocks before it final, the caller checked they are canonical.
+	pub fn finalize(&self, number: BlockNumber) -> Result<()> {
+		self.conn.execute(
+			"UPDATE blocks SET finalized = 1 WHERE number <= ?1 AND finalized = 0",
+			[number],
+		)?;
+		Ok(())
+	}
+
+	/// Delete `number`, the blocks after it and their records.
+	pub fn revert(&self, number: BlockNumber) -> Result<()> {
+		self.conn.execute("DELETE FROM blocks WHERE number >= ?1", [number])?;
+		Ok(())
+	}
+
+	pub fn insert_block(
+		&mut self,
+		number: BlockNumber,
+		hash: Hash,
+		parent_hash: Hash,
+		records: &[Record],
+	) -> Result<()> {
+		let tx = self.conn.transaction()?;
+		tx.execute(
+			"INSERT INTO blocks (number, hash, parent_hash) VALUES (?1, ?2, ?3)",
+			params![number, hash.as_bytes(), parent_hash.as_bytes()],
+		)?;
+		for Record { event_index, extrinsic_index, kind } in records {
+			match kind {
+				Kind::Transfer { operator, from, to, token_id, amount } => tx.execute(
+					"INSERT INTO transfers (block_number, event_index, extrinsic_index, operator,
+					from_account, to_account, token_id, amount)
+					VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+					params![
+						number,
+						event_index,
+						extrinsic_index,
+						ss58(operator),
+						from.as_ref().map(ss58),
+						to.as_ref().map(ss58),
+						token_id,
+						amount.to_string(),
+					],
+				)?,
+				Kind::Swap { who, sold_token, sold_amount, bought_token, bought_amount } => tx
+					.execute(
+						"INSERT INTO swaps (block_number, event_index, extrinsic_index, account,
+						sold_token, sold_amount, bought_token, bought_amount)
+						VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+						params![
+							number,
+							event_index,
+							extrinsic_index,
+							ss58(who),
+							sold_token,
+							sold_amount.to_string(),
+							bought_token,
+							bought_amount.to_string(),
+						],
+					)?,
+				Kind::Liquidity {
+					who,
+					deposit,
+					first_token,
+					first_amount,
+					second_token,
+					second_amount,
+				} => tx.execute(
+					"INSERT INTO liquidity (block_number, event_index, extrinsic_index, account,
+					deposit, first_token, first_amount, second_token, second_amount)
+					VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+					params![
+						number,
+						event_index,
+						extrinsic_index,
+						ss58(who),
+						deposit,
+						first_token,
+						first_amount.to_string(),
+						second_token,
+						second_amount.to_string(),
+					],
+				)?,
+			};
+		}
+		tx.commit()?;
+		Ok(())
+	}
+
+	/// Transfers from or to `account`, in SS58.
+	pub fn transfers(&self, account: &str, page: &Page) -> Result<Vec<Transfer>> {
+		let mut statement = self.conn.prepare_cached(&format!(
+			"SELECT {}, x.operator, x.from_account, x.to_account, x.token_id, x.amount
+			FROM transfers x JOIN blocks b ON b.number = x.block_number
+			WHERE (x.from_account = ?1 OR x.to_account = ?1) AND x.id < ?2
+				AND (?3 IS NULL OR x.token_id = ?3) AND (?4 = 0 OR b.finalized = 1)
+			ORDER BY x.id DESC LIMIT ?5",
+			LOCATION_COLUMNS
+		))?;
+		let rows = statement.query_map(page_params!(account, page), |row| {
+			Ok(Transfer {
+				location: Location::from_row(row)?,
+				operator: row.get(6)?,
+				from: row.get(7)?,
+				to: row.get(8)?,
+				token_id: row.get(9)?,
+				amount: row.get(10)?,
+			})
+		})?;
+		Ok(rows.collect::<rusqlite::Result<_>>()?)
+	}
+
+	/// Tokens `account` sold to the dex.
+	pub fn swaps(&self, account: &str, page: &Page) -> Result<Vec<Swap>> {
+		let mut statement = self.conn.prepare_cached(&format!(
+			"SELECT {}, x.account, x.sold_token, x.sold_amount, x.bought_token, x.bought_amount
+			FROM swaps x JOIN blocks b ON b.number = x.block_number
+			WHERE x.account = ?1 AND x.id < ?2
+				AND (?3 IS NULL OR x.sold_token = ?3 OR x.bought_token = ?3)
+				AND (?4 = 0 OR b.finalized = 1)
+			ORDER BY x.id DESC LIMIT ?5",
+			LOCATION_COLUMNS
+		))?;
+		let rows = statement.query_map(page_params!(account, page), |row| {
+			Ok(Swap {
+				location: Location::from_row(row)?,
+				account: row.get(6)?,
+				sold_token: row.get(7)?,
+				sold_amount: row.get(8)?,
+				bought_token: row.get(9)?,
+				bought_amount: row.get(10)?,
+			})
+		})?;
+		Ok(rows.collect::<rusqlite::Result<_>>()?)
+	}
+
+	/// Liquidity `account` added to or removed from the dex.
+	pub fn liquidity(&self, account: &str, page: &Page) -> Result<Vec<Liquidity>> {
+		let mut statement = self.conn.prepare_cached(&format!(
+			"SELECT {}, x.account, x.deposit, x.first_token, x.first_amount, x.second_token,
+				x.second_amount
+			FROM liquidity x JOIN blocks b ON b.number = x.block_number
+			WHERE x.account = ?1 AND x.id < ?2
+				AND (?3 IS NULL OR x.first_token = ?3 OR x.second_token = ?3)
+				AND (?4 = 0 OR b.finalized = 1)
+			ORDER BY x.id DESC LIMIT ?5",
+			LOCATION_COLUMNS
+		))?;
+		let rows = statement.query_map(page_params!(account, page), |row| {
+			Ok(Liquidity {
+				location: Location::from_row(row)?,
+				account: row.get(6)?,
+				deposit: row.get(7)?,
+				first_token: row.get(8)?,
+				first_amount: row.get(9)?,
+				second_token: row.get(10)?,
+				second_amount: row.get(11)?,
+			})
+		})?;
+		Ok(rows.collect::<rusqlite::Result<_>>()?)
+	}
+}
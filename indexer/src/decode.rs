@@ -0,0 +1,119 @@
+//! The records the indexer keeps from the events of a block.
+
+use crate::rpc::EventRecords;
+use frame_system::Phase;
+use node_template_runtime::{AccountId, Balance, Event};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+	/// Position of the event in the block, shared by the transfers of a batch.
+	pub event_index: u32,
+	/// Extrinsic that emitted the event, `None` for `on_initialize` and `on_finalize`.
+	pub extrinsic_index: Option<u32>,
+	pub kind: Kind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Kind {
+	/// `from` is `None` for mints and `to` for burns.
+	Transfer {
+		operator: AccountId,
+		from: Option<AccountId>,
+		to: Option<AccountId>,
+		token_id: u32,
+		amount: Balance,
+	},
+	Swap {
+		who: AccountId,
+		sold_token: u32,
+		sold_amount: Balance,
+		bought_token: u32,
+		bought_amount: Balance,
+	},
+	Liquidity {
+		who: AccountId,
+		deposit: bool,
+		first_token: u32,
+		first_amount: Balance,
+		second_token: u32,
+		second_amount: Balance,
+	},
+}
+
+/// Mints and burns are transfers from and to the default account.
+fn account(who: AccountId) -> Option<AccountId> {
+	Some(who).filter(|who| who != &AccountId::default())
+}
+
+fn kinds(event: Event) -> Vec<Kind> {
+	use pallet_dex::Event as Dex;
+	use pallet_erc1155::Event as Erc1155;
+	match event {
+		Event::Erc1155(Erc1155::TransferSingle { operator, from, to, id, value }) => {
+			vec![Kind::Transfer {
+				operator,
+				from: account(from),
+				to: account(to),
+				token_id: id,
+				amount: value,
+			}]
+		},
+		Event::Erc1155(Erc1155::TransferBatch { operator, from, to, ids, values }) => ids
+			.into_iter()
+			.zip(values)
+			.map(|(token_id, amount)| Kind::Transfer {
+				operator: operator.clone(),
+				from: account(from.clone()),
+				to: account(to.clone()),
+				token_id,
+				amount,
+			})
+			.collect(),
+		Event::Dex(Dex::TokenBought(who, sold_token, sold_amount, bought_token, bought_amount)) => {
+			vec![Kind::Swap { who, sold_token, sold_amount, bought_token, bought_amount }]
+		},
+		Event::Dex(Dex::Deposited(who, first_token, first_amount, second_token, second_amount)) => {
+			vec![Kind::Liquidity {
+				who,
+				deposit: true,
+				first_token,
+				first_amount,
+				second_token,
+				second_amount,
+			}]
+		},
+		Event::Dex(Dex::Withdrawed(
+			who,
+			first_token,
+			first_amount,
+			second_token,
+			second_amount,
+		)) => vec![Kind::Liquidity {
+			who,
+			deposit: false,
+			first_token,
+			first_amount,
+			second_token,
+			second_amount,
+		}],
+		_ => Vec::new(),
+	}
+}
+
+pub fn records(events: EventRecords) -> Vec<Record> {
+	events
+		.into_iter()
+		.enumerate()
+		.flat_map(|(event_index, record)| {
+			let extrinsic_index = match record.phase {
+				Phase::ApplyExtrinsic(index) => Some(index),
+				_ => None,
+			};
+			kinds(record.event).into_iter().map(move |kind| Record {
+				event_index: event_index as u32,
+				extrinsic_index,
+				kind,
+			})
+		})
+		.collect()
+}
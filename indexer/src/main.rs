@@ -0,0 +1,60 @@
+//! Indexes the `pallet_erc1155` transfers and `pallet_dex` swaps and liquidity changes of a node
+//! into SQLite and serves them over HTTP, the chain itself keeps no history of them.
+//!
+//! Events are decoded with the types of `node_template_runtime`, the indexer has to be built from
+//! the same tree as the runtime of the chain it follows.
+
+mod api;
+mod db;
+mod decode;
+mod rpc;
+mod sync;
+
+#[cfg(test)]
+mod tests;
+
+use std::{path::PathBuf, thread, time::Duration};
+use structopt::StructOpt;
+
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "indexer")]
+struct Opt {
+	/// HTTP JSON-RPC endpoint of the node.
+	#[structopt(long, default_value = "http://127.0.0.1:9933")]
+	rpc: String,
+
+	/// SQLite database, created if missing.
+	#[structopt(long, parse(from_os_str), default_value = "indexer.sqlite")]
+	db: PathBuf,
+
+	/// Address the HTTP API listens on.
+	#[structopt(long, default_value = "127.0.0.1:8080")]
+	listen: String,
+
+	/// First block to index when the database is empty.
+	#[structopt(long, default_value = "0")]
+	from: u32,
+
+	/// Milliseconds between polls of the node for new blocks.
+	#[structopt(long, default_value = "2000")]
+	poll_interval: u64,
+}
+
+fn main() -> Result<()> {
+	env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+	let opt = Opt::from_args();
+
+	let mut indexer =
+		sync::Indexer::new(rpc::Client::new(&opt.rpc), db::Db::open(&opt.db)?, opt.from);
+	let poll_interval = Duration::from_millis(opt.poll_interval);
+	thread::spawn(move || loop {
+		if let Err(e) = indexer.sync() {
+			log::warn!("sync failed: {}", e);
+		}
+		thread::sleep(poll_interval);
+	});
+
+	api::serve(&opt.listen, db::Db::open(&opt.db)?)
+}
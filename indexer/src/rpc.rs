@@ -0,0 +1,58 @@
+//! Blocking client for the JSON-RPC endpoint of the node.
+
+use crate::Result;
+use codec::Decode;
+use frame_system::EventRecord;
+use node_template_runtime::{BlockNumber, Event, Hash, Header};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use sp_core::{hashing::twox_128, hexdisplay::HexDisplay, Bytes};
+
+pub type EventRecords = Vec<EventRecord<Event, Hash>>;
+
+pub struct Client {
+	url: String,
+	agent: ureq::Agent,
+}
+
+impl Client {
+	pub fn new(url: &str) -> Self {
+		Client { url: url.into(), agent: ureq::agent() }
+	}
+
+	fn call<R: DeserializeOwned>(&self, method: &str, params: Value) -> Result<R> {
+		let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+		let mut response: Value = self.agent.post(&self.url).send_json(request)?.into_json()?;
+		if let Some(error) = response.get("error") {
+			return Err(format!("{} failed: {}", method, error).into())
+		}
+		Ok(serde_json::from_value(response["result"].take())?)
+	}
+
+	pub fn finalized_head(&self) -> Result<Hash> {
+		self.call("chain_getFinalizedHead", json!([]))
+	}
+
+	/// Header of the block `hash`, of the best block if `None`.
+	pub fn header(&self, hash: Option<Hash>) -> Result<Header> {
+		let header: Option<Header> = self.call("chain_getHeader", json!([hash]))?;
+		header.ok_or_else(|| format!("unknown block {:?}", hash).into())
+	}
+
+	/// Hash of the block `number` of the best chain.
+	pub fn block_hash(&self, number: BlockNumber) -> Result<Option<Hash>> {
+		self.call("chain_getBlockHash", json!([number]))
+	}
+
+	pub fn events(&self, hash: Hash) -> Result<EventRecords> {
+		let key = [twox_128(b"System"), twox_128(b"Events")].concat();
+		let key = format!("0x{}", HexDisplay::from(&key));
+		let events: Option<Bytes> = self.call("state_getStorage", json!([key, hash]))?;
+		match events {
+			Some(events) => EventRecords::decode(&mut &events[..]).map_err(|e| {
+				format!("undecodable events in {:?}, is the runtime up to date? {}", hash, e).into()
+			}),
+			None => Ok(Vec::new()),
+		}
+	}
+}
@@ -0,0 +1,85 @@
+//! Follows the best chain of the node.
+//!
+//! Blocks are indexed as soon as they are in the best chain, so records show up without waiting
+//! for finality. When the node switches to another fork, the indexed blocks that left the best
+//! chain are reverted, down to the last block known to be final at most.
+
+use crate::{db::Db, decode, rpc::Client, Result};
+use node_template_runtime::{BlockNumber, Hash};
+
+pub struct Indexer {
+	client: Client,
+	db: Db,
+	/// First block to index when the database is empty.
+	from: BlockNumber,
+}
+
+impl Indexer {
+	pub fn new(client: Client, db: Db, from: BlockNumber) -> Self {
+		Indexer { client, db, from }
+	}
+
+	/// Catch up with the best block of the node.
+	pub fn sync(&mut self) -> Result<()> {
+		let finalized_hash = self.client.finalized_head()?;
+		let finalized = self.client.header(Some(finalized_hash))?.number;
+		let best = self.client.header(None)?.number;
+
+		self.revert_forks()?;
+		let start = match self.db.tip()? {
+			Some((number, _)) => number + 1,
+			None => self.from,
+		};
+		for number in start..=best {
+			let hash = match self.client.block_hash(number)? {
+				Some(hash) => hash,
+				None => break,
+			};
+			let header = self.client.header(Some(hash))?;
+			if let Some((_, tip)) = self.db.tip()? {
+				if header.parent_hash != tip {
+					// the best chain changed since `revert_forks`, the next sync reverts
+					break
+				}
+			}
+			let records = decode::records(self.client.events(hash)?);
+			self.db.insert_block(number, hash, header.parent_hash, &records)?;
+			log::debug!("indexed #{} {:?}, {} records", number, hash, records.len());
+		}
+
+		self.finalize(finalized, finalized_hash)
+	}
+
+	/// Revert indexed blocks until the tip is in the best chain again.
+	fn revert_forks(&mut self) -> Result<()> {
+		let finalized = self.db.finalized()?;
+		while let Some((number, hash)) = self.db.tip()? {
+			if finalized.map_or(false, |finalized| number <= finalized) ||
+				self.client.block_hash(number)? == Some(hash)
+			{
+				break
+			}
+			log::info!("reverting #{} {:?}, no longer in the best chain", number, hash);
+			self.db.revert(number)?;
+		}
+		Ok(())
+	}
+
+	/// Mark the indexed blocks up to `finalized` final, if they are the ones that were finalized.
+	fn finalize(&mut self, finalized: BlockNumber, finalized_hash: Hash) -> Result<()> {
+		let number = match self.db.tip()? {
+			Some((tip, _)) => tip.min(finalized),
+			None => return Ok(()),
+		};
+		// below the finalized block the best chain can't change anymore
+		let expected = if number == finalized {
+			Some(finalized_hash)
+		} else {
+			self.client.block_hash(number)?
+		};
+		if expected.is_some() && self.db.block_hash(number)? == expected {
+			self.db.finalize(number)?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,216 @@
+use crate::{
+	api,
+	db::{Db, Page},
+	decode::{self, Kind, Record},
+};
+use frame_system::{EventRecord, Phase};
+use node_template_runtime::{AccountId, Event, Hash};
+use sp_core::crypto::Ss58Codec;
+
+fn alice() -> AccountId {
+	AccountId::new([1; 32])
+}
+
+fn bob() -> AccountId {
+	AccountId::new([2; 32])
+}
+
+fn record(phase: Phase, event: Event) -> EventRecord<Event, Hash> {
+	EventRecord { phase, event, topics: Vec::new() }
+}
+
+fn transfer(event_index: u32, from: Option<AccountId>, to: Option<AccountId>) -> Record {
+	Record {
+		event_index,
+		extrinsic_index: Some(0),
+		kind: Kind::Transfer { operator: alice(), from, to, token_id: 10, amount: 5 },
+	}
+}
+
+fn page() -> Page {
+	Page { before: None, limit: 100, token_id: None, finalized_only: false }
+}
+
+fn hash(number: u32) -> Hash {
+	Hash::repeat_byte(number as u8 + 1)
+}
+
+/// Blocks `0..=tip` with a transfer from Alice to Bob in each.
+fn db(tip: u32) -> Db {
+	let mut db = Db::open_in_memory().unwrap();
+	for number in 0..=tip {
+		let parent_hash = if number == 0 { Hash::zero() } else { hash(number - 1) };
+		db.insert_block(
+			number,
+			hash(number),
+			parent_hash,
+			&[transfer(0, Some(alice()), Some(bob()))],
+		)
+		.unwrap();
+	}
+	db
+}
+
+#[test]
+fn decode_should_work() {
+	let events = vec![
+		// not indexed
+		record(Phase::Initialization, Event::Dex(pallet_dex::Event::PauseChanged(true))),
+		record(
+			Phase::ApplyExtrinsic(1),
+			Event::Erc1155(pallet_erc1155::Event::TransferSingle {
+				operator: alice(),
+				from: AccountId::default(),
+				to: bob(),
+				id: 10,
+				value: 5,
+			}),
+		),
+		record(
+			Phase::ApplyExtrinsic(2),
+			Event::Erc1155(pallet_erc1155::Event::TransferBatch {
+				operator: alice(),
+				from: alice(),
+				to: bob(),
+				ids: vec![10, 11],
+				values: vec![5, 6],
+			}),
+		),
+		record(
+			Phase::ApplyExtrinsic(3),
+			Event::Dex(pallet_dex::Event::TokenBought(bob(), 10, 5, 11, 4)),
+		),
+		record(Phase::Finalization, Event::Dex(pallet_dex::Event::Withdrawed(bob(), 10, 1, 11, 2))),
+	];
+	let batch = |token_id, amount| Record {
+		event_index: 2,
+		extrinsic_index: Some(2),
+		kind: Kind::Transfer {
+			operator: alice(),
+			from: Some(alice()),
+			to: Some(bob()),
+			token_id,
+			amount,
+		},
+	};
+	assert_eq!(
+		decode::records(events),
+		vec![
+			Record {
+				event_index: 1,
+				extrinsic_index: Some(1),
+				kind: Kind::Transfer {
+					operator: alice(),
+					from: None,
+					to: Some(bob()),
+					token_id: 10,
+					amount: 5
+				},
+			},
+			batch(10, 5),
+			batch(11, 6),
+			Record {
+				event_index: 3,
+				extrinsic_index: Some(3),
+				kind: Kind::Swap {
+					who: bob(),
+					sold_token: 10,
+					sold_amount: 5,
+					bought_token: 11,
+					bought_amount: 4
+				},
+			},
+			Record {
+				event_index: 4,
+				extrinsic_index: None,
+				kind: Kind::Liquidity {
+					who: bob(),
+					deposit: false,
+					first_token: 10,
+					first_amount: 1,
+					second_token: 11,
+					second_amount: 2
+				},
+			},
+		]
+	);
+}
+
+#[test]
+fn revert_should_work() {
+	let db = db(3);
+	assert_eq!(db.tip().unwrap(), Some((3, hash(3))));
+	db.revert(2).unwrap();
+	assert_eq!(db.tip().unwrap(), Some((1, hash(1))));
+	assert_eq!(db.block_hash(2).unwrap(), None);
+	// the records of the reverted blocks are gone with them
+	let transfers = db.transfers(&bob().to_ss58check(), &page()).unwrap();
+	assert_eq!(transfers.iter().map(|t| t.location.block_number).collect::<Vec<_>>(), vec![1, 0]);
+}
+
+#[test]
+fn finalize_should_work() {
+	let db = db(3);
+	assert_eq!(db.finalized().unwrap(), None);
+	db.finalize(1).unwrap();
+	assert_eq!(db.finalized().unwrap(), Some(1));
+	let finalized = Page { finalized_only: true, ..page() };
+	let transfers = db.transfers(&alice().to_ss58check(), &finalized).unwrap();
+	assert_eq!(transfers.len(), 2);
+	assert!(transfers.iter().all(|t| t.location.finalized));
+}
+
+#[test]
+fn transfers_should_work() {
+	let mut db = db(0);
+	let records = [transfer(0, None, Some(alice())), transfer(1, Some(alice()), None)];
+	db.insert_block(1, hash(1), hash(0), &records).unwrap();
+
+	let transfers = db.transfers(&alice().to_ss58check(), &page()).unwrap();
+	assert_eq!(transfers.iter().map(|t| t.location.id).collect::<Vec<_>>(), vec![3, 2, 1]);
+	assert_eq!(transfers[0].from, Some(alice().to_ss58check()));
+	assert_eq!(transfers[0].to, None);
+	assert_eq!(transfers[0].amount, "5");
+	assert_eq!(transfers[0].location.block_hash, format!("{:?}", hash(1)));
+
+	let next = Page { before: Some(3), limit: 1, ..page() };
+	let transfers = db.transfers(&alice().to_ss58check(), &next).unwrap();
+	assert_eq!(transfers.iter().map(|t| t.location.id).collect::<Vec<_>>(), vec![2]);
+	let other_token = Page { token_id: Some(11), ..page() };
+	assert!(db.transfers(&alice().to_ss58check(), &other_token).unwrap().is_empty());
+}
+
+#[test]
+fn api_should_work() {
+	let db = db(1);
+	db.finalize(0).unwrap();
+	assert_eq!(
+		api::handle(&db, "/status").unwrap(),
+		serde_json::json!({ "tip": 1, "finalized": 0 })
+	);
+
+	let url = format!("/accounts/{}/transfers?limit=1&finalized=true", bob().to_ss58check());
+	let transfers = api::handle(&db, &url).unwrap();
+	assert_eq!(transfers.as_array().unwrap().len(), 1);
+	assert_eq!(transfers[0]["block_number"], 0);
+	assert_eq!(transfers[0]["to"], bob().to_ss58check());
+	assert_eq!(transfers[0]["amount"], "5");
+
+	let url = format!("/accounts/{}/swaps", bob().to_ss58check());
+	assert_eq!(api::handle(&db, &url).unwrap(), serde_json::json!([]));
+	assert_eq!(api::handle(&db, "/accounts/bob/swaps").unwrap_err().0, 400);
+	assert_eq!(api::handle(&db, "/blocks").unwrap_err().0, 404);
+}
+
+#[test]
+fn parse_page_should_work() {
+	assert_eq!(api::parse_page("").unwrap(), page());
+	assert_eq!(
+		api::parse_page("before=7&limit=10&token_id=3&finalized=true").unwrap(),
+		Page { before: Some(7), limit: 10, token_id: Some(3), finalized_only: true }
+	);
+	assert_eq!(api::parse_page("limit=0").unwrap_err().0, 400);
+	assert_eq!(api::parse_page("limit=1001").unwrap_err().0, 400);
+	assert_eq!(api::parse_page("before=x").unwrap_err().0, 400);
+	assert_eq!(api::parse_page("offset=1").unwrap_err().0, 400);
+}
@@ -0,0 +1,207 @@
+//! Runs the indexer against `node-template --dev`.
+//!
+//! Ignored by default, build the node first and run with `cargo test -p indexer -- --ignored`.
+//! `NODE_BINARY` overrides the path of the node, `target/release/node-template` by default.
+
+use codec::Encode;
+use node_template_runtime::{
+	AccountId, Call, Hash, Signature, SignedExtra, UncheckedExtrinsic, VERSION,
+};
+use serde_json::{json, Value};
+use sp_core::{crypto::Ss58Codec, sr25519, Bytes, Pair};
+use sp_runtime::generic::{Era, SignedPayload};
+use std::{
+	env,
+	path::PathBuf,
+	process::{Child, Command},
+	thread,
+	time::{Duration, Instant},
+};
+
+const RPC_PORT: u16 = 19933;
+const API: &str = "127.0.0.1:18080";
+// `//Alice`, endowed in the dev chain spec
+const ALICE: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+const FIRST_TOKEN: u32 = 10;
+const SECOND_TOKEN: u32 = 11;
+
+/// Kills the process when dropped, also when an assertion fails.
+struct Process(Child);
+
+impl Drop for Process {
+	fn drop(&mut self) {
+		let _ = self.0.kill();
+		let _ = self.0.wait();
+	}
+}
+
+fn get(path: &str) -> Option<Value> {
+	ureq::get(&format!("http://{}{}", API, path)).call().ok()?.into_json().ok()
+}
+
+fn rpc(method: &str, params: Value) -> Result<Value, String> {
+	let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+	let mut response: Value = ureq::post(&format!("http://127.0.0.1:{}", RPC_PORT))
+		.send_json(request)
+		.map_err(|e| e.to_string())?
+		.into_json()
+		.map_err(|e| e.to_string())?;
+	match response.get("error") {
+		Some(error) => Err(format!("{} failed: {}", method, error)),
+		None => Ok(response["result"].take()),
+	}
+}
+
+fn account(pair: &sr25519::Pair) -> AccountId {
+	AccountId::from(pair.public())
+}
+
+/// Sign `call` with the next nonce of `signer` and submit it, immortal and without a tip.
+fn submit(signer: &sr25519::Pair, genesis_hash: Hash, call: Call) {
+	let nonce = rpc("system_accountNextIndex", json!([account(signer).to_ss58check()]))
+		.unwrap()
+		.as_u64()
+		.expect("nonce is a number") as u32;
+	let extra: SignedExtra = (
+		frame_system::CheckSpecVersion::new(),
+		frame_system::CheckTxVersion::new(),
+		frame_system::CheckGenesis::new(),
+		frame_system::CheckEra::from(Era::Immortal),
+		frame_system::CheckNonce::from(nonce),
+		frame_system::CheckWeight::new(),
+		pallet_token_payment::ChargeTokenPayment::new(0, None),
+	);
+	// `SignedPayload::new` reads the genesis hash from storage, there is none outside the node
+	let payload = SignedPayload::from_raw(
+		call,
+		extra,
+		(VERSION.spec_version, VERSION.transaction_version, genesis_hash, genesis_hash, (), (), ()),
+	);
+	let signature = payload.using_encoded(|payload| signer.sign(payload));
+	let (call, extra, _) = payload.deconstruct();
+	let extrinsic = UncheckedExtrinsic::new_signed(
+		call,
+		account(signer).into(),
+		Signature::from(signature),
+		extra,
+	);
+	rpc("author_submitExtrinsic", json!([Bytes(extrinsic.encode())])).unwrap();
+}
+
+/// The single record of `path`, once it is in a final block.
+fn wait_for_final_record(path: &str, deadline: Instant) -> Value {
+	loop {
+		let records = get(path).and_then(|records| records.as_array().cloned());
+		if let Some([record]) = records.as_deref() {
+			return record.clone()
+		}
+		assert!(Instant::now() < deadline, "{} not indexed in a final block", path);
+		thread::sleep(Duration::from_secs(1));
+	}
+}
+
+/// `record` comes from an extrinsic of a final block of the best chain of the node.
+fn assert_block_hash(record: &Value) {
+	let hash = rpc("chain_getBlockHash", json!([record["block_number"]])).unwrap();
+	assert_eq!(record["block_hash"], hash);
+	assert_eq!(record["finalized"], json!(true));
+	assert!(record["extrinsic_index"].is_u64());
+}
+
+#[test]
+#[ignore]
+fn dev_node_should_work() {
+	let node = env::var_os("NODE_BINARY").map(PathBuf::from).unwrap_or_else(|| {
+		PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../target/release/node-template")
+	});
+	let db = env::temp_dir().join(format!("indexer-{}.sqlite", std::process::id()));
+	let _node = Process(
+		Command::new(node)
+			.args(["--dev", "--tmp", "--port", "30444", "--ws-port", "19944"])
+			.args(["--rpc-port", RPC_PORT.to_string().as_str()])
+			.spawn()
+			.expect("node binary runs"),
+	);
+	let _indexer = Process(
+		Command::new(env!("CARGO_BIN_EXE_indexer"))
+			.args(["--rpc", format!("http://127.0.0.1:{}", RPC_PORT).as_str(), "--listen", API])
+			.args(["--poll-interval", "500"])
+			.arg("--db")
+			.arg(&db)
+			.spawn()
+			.expect("indexer binary runs"),
+	);
+
+	let deadline = Instant::now() + Duration::from_secs(30);
+	let genesis_hash = loop {
+		if let Ok(hash) = rpc("chain_getBlockHash", json!([0])) {
+			break serde_json::from_value::<Hash>(hash).expect("genesis hash is a hash")
+		}
+		assert!(Instant::now() < deadline, "node RPC not reachable");
+		thread::sleep(Duration::from_secs(1));
+	};
+	let alice = sr25519::Pair::from_string("//Alice", None).unwrap();
+	let bob = account(&sr25519::Pair::from_string("//Bob", None).unwrap());
+	let pool = account(&sr25519::Pair::from_string("//Charlie", None).unwrap());
+	assert_eq!(account(&alice).to_ss58check(), ALICE);
+
+	// the nonces are read from the pool, each extrinsic has to be in it before the next is signed
+	let calls = vec![
+		Call::Erc1155(pallet_erc1155::Call::init {
+			token_ids: vec![FIRST_TOKEN, SECOND_TOKEN].try_into().unwrap(),
+			initial_supplies: vec![1_000_000, 1_000_000].try_into().unwrap(),
+		}),
+		Call::Erc1155(pallet_erc1155::Call::transfer_from_single {
+			from: account(&alice),
+			to: bob.clone(),
+			token_id: FIRST_TOKEN,
+			amount: 100,
+			data: Default::default(),
+		}),
+		Call::Erc1155(pallet_erc1155::Call::set_approval_for_all {
+			operator: pool.clone(),
+			approved: true,
+		}),
+		Call::Dex(pallet_dex::Call::init {
+			pool_address: pool,
+			first_token_id: FIRST_TOKEN,
+			first_token_amount: 100_000,
+			second_token_id: SECOND_TOKEN,
+			second_token_amount: 100_000,
+		}),
+		Call::Dex(pallet_dex::Call::buy_token { token_id: FIRST_TOKEN, amount: 1_000 }),
+	];
+	for call in calls {
+		submit(&alice, genesis_hash, call);
+	}
+
+	// blocks are authored every 6 seconds and finalized shortly after
+	let deadline = Instant::now() + Duration::from_secs(120);
+	let transfer = wait_for_final_record(
+		&format!("/accounts/{}/transfers?finalized=true", bob.to_ss58check()),
+		deadline,
+	);
+	assert_block_hash(&transfer);
+	assert_eq!(transfer["operator"], ALICE);
+	assert_eq!(transfer["from"], ALICE);
+	assert_eq!(transfer["to"], bob.to_ss58check());
+	assert_eq!(transfer["token_id"], FIRST_TOKEN);
+	assert_eq!(transfer["amount"], "100");
+
+	let swap =
+		wait_for_final_record(&format!("/accounts/{}/swaps?finalized=true", ALICE), deadline);
+	assert_block_hash(&swap);
+	assert_eq!(swap["account"], ALICE);
+	assert_eq!(swap["sold_token"], FIRST_TOKEN);
+	assert_eq!(swap["sold_amount"], "1000");
+	assert_eq!(swap["bought_token"], SECOND_TOKEN);
+	let bought: u128 = swap["bought_amount"].as_str().unwrap().parse().unwrap();
+	assert!(bought > 0 && bought < 1_000);
+
+	let status = get("/status").unwrap();
+	assert!(status["tip"].as_u64() >= status["finalized"].as_u64());
+	assert!(status["finalized"].as_u64() >= swap["block_number"].as_u64());
+
+	drop(_indexer);
+	let _ = std::fs::remove_file(&db);
+}
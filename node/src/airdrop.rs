@@ -0,0 +1,87 @@
+//! Off-chain helper for `pallet_airdrop`: builds the Merkle root of the claims and their proofs.
+
+use node_template_runtime::{AccountId, Balance};
+use pallet_airdrop::merkle;
+use sp_core::{crypto::Ss58Codec, hexdisplay::HexDisplay};
+use std::{fs, path::PathBuf};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+pub struct AirdropCmd {
+	/// CSV file with one `account,token_id,amount` claim per line, accounts in SS58. Empty lines
+	/// and lines starting with `#` are skipped, the claims are indexed in file order.
+	#[structopt(parse(from_os_str))]
+	pub input: PathBuf,
+
+	/// Write the root and the proofs as JSON to this file instead of stdout.
+	#[structopt(long, parse(from_os_str))]
+	pub output: Option<PathBuf>,
+}
+
+struct Claim {
+	account: AccountId,
+	token_id: u32,
+	amount: Balance,
+}
+
+fn parse_claim(line_number: usize, line: &str) -> sc_cli::Result<Claim> {
+	let error = |what: &str| format!("line {}: invalid {}", line_number, what);
+	let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+	match fields.as_slice() {
+		[account, token_id, amount] => Ok(Claim {
+			account: AccountId::from_ss58check(account).map_err(|_| error("account"))?,
+			token_id: token_id.parse().map_err(|_| error("token id"))?,
+			amount: amount.parse().map_err(|_| error("amount"))?,
+		}),
+		_ => Err(format!("line {}: expected `account,token_id,amount`", line_number).into()),
+	}
+}
+
+fn to_hex(hash: &merkle::Hash) -> String {
+	format!("0x{}", HexDisplay::from(hash))
+}
+
+impl AirdropCmd {
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let input = fs::read_to_string(&self.input)?;
+		let claims = input
+			.lines()
+			.enumerate()
+			.filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+			.map(|(i, line)| parse_claim(i + 1, line))
+			.collect::<sc_cli::Result<Vec<_>>>()?;
+		if claims.len() > u32::MAX as usize {
+			return Err("too many claims".into())
+		}
+
+		let leaves: Vec<merkle::Hash> = claims
+			.iter()
+			.enumerate()
+			.map(|(index, claim)| {
+				merkle::leaf_hash(index as u32, &claim.account, &claim.token_id, &claim.amount)
+			})
+			.collect();
+		let proofs: Vec<serde_json::Value> = claims
+			.iter()
+			.enumerate()
+			.map(|(index, claim)| {
+				serde_json::json!({
+					"index": index,
+					"account": claim.account.to_ss58check(),
+					"token_id": claim.token_id,
+					// as a string, JSON numbers lose precision above 2^53
+					"amount": claim.amount.to_string(),
+					"proof": merkle::proof(&leaves, index).iter().map(to_hex).collect::<Vec<_>>(),
+				})
+			})
+			.collect();
+		let json = serde_json::json!({ "root": to_hex(&merkle::root(&leaves)), "claims": proofs });
+		let json = serde_json::to_string_pretty(&json).map_err(|e| e.to_string())?;
+
+		match &self.output {
+			Some(path) => fs::write(path, json)?,
+			None => println!("{}", json),
+		}
+		Ok(())
+	}
+}
@@ -1,6 +1,6 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	AccountId, AuraConfig, BalancesConfig, EVMConfig, GenesisConfig, GrandpaConfig, Signature,
+	SudoConfig, SystemConfig, TokenPaymentConfig, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -147,8 +147,11 @@ fn testnet_genesis(
 		},
 		sudo: SudoConfig {
 			// Assign network admin rights.
-			key: root_key,
+			key: root_key.clone(),
 		},
 		transaction_payment: Default::default(),
+		evm: EVMConfig { accounts: Default::default() },
+		// the root key issues the token the dex prices fees against
+		token_payment: TokenPaymentConfig { native_token_issuer: Some(root_key) },
 	}
 }
@@ -38,4 +38,7 @@ pub enum Subcommand {
 	/// The custom benchmark subcommand benchmarking runtime pallets.
 	#[structopt(name = "benchmark", about = "Benchmark runtime pallets.")]
 	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
+
+	/// Build the Merkle tree of an airdrop and the proofs of its claims.
+	Airdrop(crate::airdrop::AirdropCmd),
 }
@@ -0,0 +1,91 @@
+//! Benchmarking setup for pallet-airdrop
+
+use super::*;
+
+use crate::Pallet as Airdrop;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+use sp_std::{convert::TryInto, vec::Vec};
+use traits::{Erc1155, Erc1155Mintable};
+
+const SEED: u32 = 0;
+
+/// Airdrop 1000 of each of `n` fresh tokens under `merkle_root`, token ids start at 0.
+fn setup<T: Config>(n: u32, merkle_root: merkle::Hash) -> T::AccountId
+where
+	T::Tokens: Erc1155Mintable<T::AccountId>,
+	TokenIdOf<T>: From<u32>,
+{
+	let caller: T::AccountId = whitelisted_caller();
+	let token_ids: Vec<TokenIdOf<T>> = (0..n).map(Into::into).collect();
+	for &token_id in token_ids.iter() {
+		T::Tokens::create(&caller, token_id).unwrap();
+		T::Tokens::mint(&caller, token_id, 1_000u32.into()).unwrap();
+	}
+	let amounts = token_ids.iter().map(|_| 1_000u32.into()).collect::<Vec<BalanceOf<T>>>();
+	Airdrop::<T>::create(
+		RawOrigin::Signed(caller.clone()).into(),
+		merkle_root,
+		token_ids.try_into().unwrap(),
+		amounts.try_into().unwrap(),
+		T::MinClaimPeriod::get(),
+	)
+	.unwrap();
+	caller
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T::Tokens: Erc1155Mintable<T::AccountId>,
+			TokenIdOf<T>: From<u32>,
+	}
+
+	create {
+		let n in 1 .. T::MaxTokens::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let token_ids: Vec<TokenIdOf<T>> = (0..n).map(Into::into).collect();
+		for &token_id in token_ids.iter() {
+			T::Tokens::create(&caller, token_id)?;
+			T::Tokens::mint(&caller, token_id, 1_000u32.into())?;
+		}
+		let amounts = token_ids.iter().map(|_| 1_000u32.into()).collect::<Vec<BalanceOf<T>>>();
+	}: _(
+		RawOrigin::Signed(caller),
+		[0; 32],
+		token_ids.try_into().unwrap(),
+		amounts.try_into().unwrap(),
+		T::MinClaimPeriod::get()
+	)
+	verify {
+		assert!(Airdrop::<T>::get_airdrop(0).is_some());
+	}
+
+	claim {
+		let p in 0 .. T::MaxProofLength::get();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let token_id: TokenIdOf<T> = 0u32.into();
+		let amount: BalanceOf<T> = 1_000u32.into();
+		let proof: Vec<merkle::Hash> = (0..p).map(|i| [i as u8; 32]).collect();
+		let leaf = merkle::leaf_hash(0, &recipient, &token_id, &amount);
+		let merkle_root = proof.iter().fold(leaf, |node, sibling| merkle::root(&[node, *sibling]));
+		let caller = setup::<T>(1, merkle_root);
+	}: _(RawOrigin::Signed(caller), 0, 0, recipient.clone(), token_id, amount, proof.try_into().unwrap())
+	verify {
+		assert_eq!(T::Tokens::balance_of(&recipient, token_id), Ok(amount));
+	}
+
+	reclaim {
+		let n in 1 .. T::MaxTokens::get();
+		let caller = setup::<T>(n, [0; 32]);
+		let expiry = frame_system::Pallet::<T>::block_number().saturating_add(T::MinClaimPeriod::get());
+		frame_system::Pallet::<T>::set_block_number(expiry);
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert!(Airdrop::<T>::get_airdrop(0).is_none());
+	}
+
+	impl_benchmark_test_suite!(Airdrop, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,239 @@
+//! Merkle airdrops of `pallet_erc1155` tokens.
+//!
+//! A distributor escrows the tokens of an airdrop with the pallet and publishes the Merkle root of
+//! its `(index, account, token_id, amount)` claims, built off-chain with the `merkle` module or
+//! the node's `airdrop` subcommand. Anybody can submit a claim with its proof, the tokens go to the
+//! claim's account and the index is marked in a bitmap so it can't be claimed twice. After the
+//! claim period the distributor takes back what is left.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+pub mod merkle;
+mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{merkle, Airdrop, WeightInfo};
+	use frame_support::{
+		dispatch::DispatchResult, ensure, pallet_prelude::*, transactional, PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AccountIdConversion, CheckedAdd, CheckedSub, Saturating, Zero};
+	use traits::Erc1155;
+
+	pub type AirdropId = u64;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type TokenIdOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::TokenId;
+
+	pub type AirdropOf<T> =
+		Airdrop<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		type Tokens: Erc1155<Self::AccountId>;
+		/// Account holding the unclaimed tokens.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// Maximum number of token ids in one airdrop.
+		#[pallet::constant]
+		type MaxTokens: Get<u32>;
+		/// Maximum depth of the tree, `2^MaxProofLength` claims.
+		#[pallet::constant]
+		type MaxProofLength: Get<u32>;
+		/// Shortest claim period before the distributor can reclaim.
+		#[pallet::constant]
+		type MinClaimPeriod: Get<Self::BlockNumber>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_airdrop_id)]
+	pub(super) type NextAirdropId<T: Config> = StorageValue<_, AirdropId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_airdrop)]
+	pub(super) type Airdrops<T: Config> = StorageMap<_, Blake2_128Concat, AirdropId, AirdropOf<T>>;
+
+	/// Unclaimed tokens of an airdrop, claims can't take more than was escrowed for it.
+	#[pallet::storage]
+	#[pallet::getter(fn get_escrow)]
+	pub(super) type Escrow<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AirdropId,
+		Blake2_128Concat,
+		TokenIdOf<T>,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Claimed indices of an airdrop, 32 per word. Airdrop ids are never reused, so the bits
+	/// outlive the airdrop harmlessly.
+	#[pallet::storage]
+	pub(super) type ClaimedBitmap<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, AirdropId, Twox64Concat, u32, u32, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		Created {
+			airdrop_id: AirdropId,
+			distributor: T::AccountId,
+			merkle_root: merkle::Hash,
+			expires_at: T::BlockNumber,
+		},
+		Claimed {
+			airdrop_id: AirdropId,
+			index: u32,
+			account: T::AccountId,
+			token_id: TokenIdOf<T>,
+			amount: BalanceOf<T>,
+		},
+		/// The unclaimed tokens went back to the distributor.
+		Reclaimed { airdrop_id: AirdropId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The token ids and amounts differ in length.
+		LengthMismatch,
+		ZeroAmount,
+		InvalidClaimPeriod,
+		UnknownAirdrop,
+		AirdropExpired,
+		AirdropNotExpired,
+		AlreadyClaimed,
+		InvalidProof,
+		/// The claim exceeds the tokens left in escrow.
+		InsufficientEscrow,
+		NotDistributor,
+		Overflow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Escrow `amounts` of `token_ids` for the claims under `merkle_root`, claimable for
+		/// `claim_period` blocks.
+		#[pallet::weight(T::WeightInfo::create(token_ids.len() as u32))]
+		#[transactional]
+		pub fn create(
+			origin: OriginFor<T>,
+			merkle_root: merkle::Hash,
+			token_ids: BoundedVec<TokenIdOf<T>, T::MaxTokens>,
+			amounts: BoundedVec<BalanceOf<T>, T::MaxTokens>,
+			claim_period: T::BlockNumber,
+		) -> DispatchResult {
+			let distributor = ensure_signed(origin)?;
+			ensure!(token_ids.len() == amounts.len(), Error::<T>::LengthMismatch);
+			ensure!(claim_period >= T::MinClaimPeriod::get(), Error::<T>::InvalidClaimPeriod);
+			let airdrop_id = Self::get_next_airdrop_id();
+			NextAirdropId::<T>::put(airdrop_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+			for (&token_id, &amount) in token_ids.iter().zip(amounts.iter()) {
+				ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+				T::Tokens::transfer(&distributor, &Self::account_id(), token_id, amount)?;
+				Escrow::<T>::try_mutate(airdrop_id, token_id, |escrow| -> DispatchResult {
+					*escrow = escrow.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+					Ok(())
+				})?;
+			}
+			let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(claim_period);
+			Airdrops::<T>::insert(
+				airdrop_id,
+				Airdrop { distributor: distributor.clone(), merkle_root, expires_at },
+			);
+			Self::deposit_event(Event::Created {
+				airdrop_id,
+				distributor,
+				merkle_root,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Send the tokens of a claim to its account, anybody can submit it.
+		#[pallet::weight(T::WeightInfo::claim(proof.len() as u32))]
+		#[transactional]
+		pub fn claim(
+			origin: OriginFor<T>,
+			airdrop_id: AirdropId,
+			index: u32,
+			account: T::AccountId,
+			token_id: TokenIdOf<T>,
+			amount: BalanceOf<T>,
+			proof: BoundedVec<merkle::Hash, T::MaxProofLength>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let airdrop = Self::get_airdrop(airdrop_id).ok_or(Error::<T>::UnknownAirdrop)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < airdrop.expires_at,
+				Error::<T>::AirdropExpired
+			);
+			ensure!(!Self::is_claimed(airdrop_id, index), Error::<T>::AlreadyClaimed);
+			let leaf = merkle::leaf_hash(index, &account, &token_id, &amount);
+			ensure!(merkle::verify(&airdrop.merkle_root, leaf, &proof), Error::<T>::InvalidProof);
+			Escrow::<T>::try_mutate(airdrop_id, token_id, |escrow| -> DispatchResult {
+				*escrow = escrow.checked_sub(&amount).ok_or(Error::<T>::InsufficientEscrow)?;
+				Ok(())
+			})?;
+			T::Tokens::transfer(&Self::account_id(), &account, token_id, amount)?;
+			ClaimedBitmap::<T>::mutate(airdrop_id, index / 32, |word| *word |= 1 << (index % 32));
+			Self::deposit_event(Event::Claimed { airdrop_id, index, account, token_id, amount });
+			Ok(())
+		}
+
+		/// Take back the unclaimed tokens of an expired airdrop.
+		#[pallet::weight(T::WeightInfo::reclaim(T::MaxTokens::get()))]
+		#[transactional]
+		pub fn reclaim(origin: OriginFor<T>, airdrop_id: AirdropId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let airdrop = Self::get_airdrop(airdrop_id).ok_or(Error::<T>::UnknownAirdrop)?;
+			ensure!(airdrop.distributor == sender, Error::<T>::NotDistributor);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= airdrop.expires_at,
+				Error::<T>::AirdropNotExpired
+			);
+			for (token_id, amount) in Escrow::<T>::drain_prefix(airdrop_id) {
+				if !amount.is_zero() {
+					T::Tokens::transfer(&Self::account_id(), &sender, token_id, amount)?;
+				}
+			}
+			Airdrops::<T>::remove(airdrop_id);
+			Self::deposit_event(Event::Reclaimed { airdrop_id });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding the tokens of all airdrops.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		pub fn is_claimed(airdrop_id: AirdropId, index: u32) -> bool {
+			ClaimedBitmap::<T>::get(airdrop_id, index / 32) & (1 << (index % 32)) != 0
+		}
+	}
+}
@@ -0,0 +1,70 @@
+//! Merkle tree over airdrop claims, shared by the pallet and the off-chain tooling.
+//!
+//! Leaves are the blake2-256 hash of the SCALE encoded `(index, account, token_id, amount)`.
+//! Pairs of nodes are hashed in sorted order, so proofs need no position bits, and the last node
+//! of an odd level is carried up unchanged.
+
+use codec::Encode;
+use sp_io::hashing::blake2_256;
+use sp_std::vec::Vec;
+
+pub type Hash = [u8; 32];
+
+pub fn leaf_hash<AccountId: Encode, TokenId: Encode, Balance: Encode>(
+	index: u32,
+	account: &AccountId,
+	token_id: &TokenId,
+	amount: &Balance,
+) -> Hash {
+	(index, account, token_id, amount).using_encoded(blake2_256)
+}
+
+fn hash_pair(a: &Hash, b: &Hash) -> Hash {
+	let (first, second) = if a <= b { (a, b) } else { (b, a) };
+	let mut pair = [0u8; 64];
+	pair[..32].copy_from_slice(first);
+	pair[32..].copy_from_slice(second);
+	blake2_256(&pair)
+}
+
+/// Whether `proof` leads from `leaf` to `root`.
+pub fn verify(root: &Hash, leaf: Hash, proof: &[Hash]) -> bool {
+	proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling)) == *root
+}
+
+fn next_level(level: &[Hash]) -> Vec<Hash> {
+	level
+		.chunks(2)
+		.map(|pair| match pair {
+			[a, b] => hash_pair(a, b),
+			[a] => *a,
+			_ => unreachable!("chunks are never empty; qed"),
+		})
+		.collect()
+}
+
+/// Root of the tree over `leaves`, all zero for no leaves.
+pub fn root(leaves: &[Hash]) -> Hash {
+	let mut level = leaves.to_vec();
+	while level.len() > 1 {
+		level = next_level(&level);
+	}
+	level.first().copied().unwrap_or_default()
+}
+
+/// Proof of the leaf at `index`, empty if it is out of range.
+pub fn proof(leaves: &[Hash], mut index: usize) -> Vec<Hash> {
+	let mut proof = Vec::new();
+	if index >= leaves.len() {
+		return proof
+	}
+	let mut level = leaves.to_vec();
+	while level.len() > 1 {
+		if let Some(sibling) = level.get(index ^ 1) {
+			proof.push(*sibling);
+		}
+		level = next_level(&level);
+		index /= 2;
+	}
+	proof
+}
@@ -0,0 +1,158 @@
+use crate as pallet_airdrop;
+use frame_support::{parameter_types, PalletId};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Erc1155: pallet_erc1155::{Pallet, Call, Storage, Event<T>},
+		Airdrop: pallet_airdrop::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u128>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u128;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const Decimals: u32 = 0;
+	pub const MaxCheckpoints: u32 = 4;
+	pub const MigrationItemsPerBlock: u32 = 2;
+	pub const MaxBatchSize: u32 = 10;
+	pub const MaxDataLength: u32 = 32;
+	pub const StringLimit: u32 = 50;
+	pub const AttributeDepositBase: u128 = 10;
+	pub const DepositPerByte: u128 = 1;
+	pub const KeyLimit: u32 = 16;
+	pub const ValueLimit: u32 = 32;
+	pub const MaxEmissionSchedules: u32 = 4;
+	pub const MaxLocks: u32 = 4;
+	pub const Erc1155PalletId: PalletId = PalletId(*b"py/e1155");
+	pub const MaxNestingDepth: u32 = 3;
+}
+
+impl pallet_erc1155::Config for Test {
+	type Event = Event;
+	type TokenId = u32;
+	type Balance = u128;
+	type CollectionId = u32;
+	type Decimals = Decimals;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type MaxCheckpoints = MaxCheckpoints;
+	type MigrationItemsPerBlock = MigrationItemsPerBlock;
+	type MaxBatchSize = MaxBatchSize;
+	type MaxDataLength = MaxDataLength;
+	type StringLimit = StringLimit;
+	type OnReceived = ();
+	type Currency = Balances;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type MaxEmissionSchedules = MaxEmissionSchedules;
+	type MaxLocks = MaxLocks;
+	type PalletId = Erc1155PalletId;
+	type MaxNestingDepth = MaxNestingDepth;
+	type OnDust = ();
+	type FeeExempt = frame_support::traits::Nothing;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const AirdropPalletId: PalletId = PalletId(*b"py/airdr");
+	pub const MaxTokens: u32 = 4;
+	pub const MaxProofLength: u32 = 8;
+	pub const MinClaimPeriod: u64 = 10;
+}
+
+impl pallet_airdrop::Config for Test {
+	type Event = Event;
+	type Tokens = Erc1155;
+	type PalletId = AirdropPalletId;
+	type MaxTokens = MaxTokens;
+	type MaxProofLength = MaxProofLength;
+	type MinClaimPeriod = MinClaimPeriod;
+	type WeightInfo = ();
+}
+
+pub const TOKEN_ID: u32 = 10;
+pub const OTHER_TOKEN_ID: u32 = 11;
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(ALICE, 1_000), (BOB, 1_000), (CHARLIE, 1_000)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		<Erc1155 as traits::Erc1155Mintable<u64>>::create(&ALICE, TOKEN_ID).unwrap();
+		<Erc1155 as traits::Erc1155Mintable<u64>>::mint(&ALICE, TOKEN_ID, 1_000).unwrap();
+		<Erc1155 as traits::Erc1155Mintable<u64>>::create(&ALICE, OTHER_TOKEN_ID).unwrap();
+		<Erc1155 as traits::Erc1155Mintable<u64>>::mint(&ALICE, OTHER_TOKEN_ID, 1_000).unwrap();
+	});
+	ext
+}
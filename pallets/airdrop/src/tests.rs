@@ -0,0 +1,206 @@
+use crate::{merkle, mock::*, Airdrop as AirdropInfo, Error};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_std::convert::TryInto;
+
+/// `(index, account, token_id, amount)`, the last index lives in the second bitmap word.
+const CLAIMS: [(u32, u64, u32, u128); 3] =
+	[(0, BOB, TOKEN_ID, 100), (1, CHARLIE, TOKEN_ID, 50), (40, BOB, OTHER_TOKEN_ID, 30)];
+
+fn balance_of(who: u64, token_id: u32) -> u128 {
+	Erc1155::get_balance(who, token_id)
+}
+
+fn leaves() -> Vec<merkle::Hash> {
+	CLAIMS
+		.iter()
+		.map(|(index, account, token_id, amount)| {
+			merkle::leaf_hash(*index, account, token_id, amount)
+		})
+		.collect()
+}
+
+fn proof(position: usize) -> BoundedVec<merkle::Hash, MaxProofLength> {
+	merkle::proof(&leaves(), position).try_into().unwrap()
+}
+
+fn create(token_amount: u128) {
+	assert_ok!(Airdrop::create(
+		Origin::signed(ALICE),
+		merkle::root(&leaves()),
+		vec![TOKEN_ID, OTHER_TOKEN_ID].try_into().unwrap(),
+		vec![token_amount, 30].try_into().unwrap(),
+		10
+	));
+}
+
+fn claim(position: usize) -> sp_runtime::DispatchResult {
+	let (index, account, token_id, amount) = CLAIMS[position];
+	Airdrop::claim(Origin::signed(CHARLIE), 0, index, account, token_id, amount, proof(position))
+}
+
+#[test]
+fn merkle_should_work() {
+	for n in 1..10u32 {
+		let leaves: Vec<_> =
+			(0..n).map(|i| merkle::leaf_hash(i, &ALICE, &TOKEN_ID, &1u128)).collect();
+		let root = merkle::root(&leaves);
+		for (i, leaf) in leaves.iter().enumerate() {
+			let proof = merkle::proof(&leaves, i);
+			assert!(merkle::verify(&root, *leaf, &proof));
+			let other = merkle::leaf_hash(i as u32, &BOB, &TOKEN_ID, &1u128);
+			assert!(!merkle::verify(&root, other, &proof));
+		}
+		assert!(merkle::proof(&leaves, n as usize).is_empty());
+	}
+	assert_eq!(merkle::root(&[]), [0; 32]);
+}
+
+#[test]
+fn create_should_work() {
+	new_test_ext().execute_with(|| {
+		create(150);
+		System::assert_last_event(Event::Airdrop(crate::Event::Created {
+			airdrop_id: 0,
+			distributor: ALICE,
+			merkle_root: merkle::root(&leaves()),
+			expires_at: 11,
+		}));
+		assert_eq!(
+			Airdrop::get_airdrop(0),
+			Some(AirdropInfo {
+				distributor: ALICE,
+				merkle_root: merkle::root(&leaves()),
+				expires_at: 11
+			})
+		);
+		assert_eq!(Airdrop::get_escrow(0, TOKEN_ID), 150);
+		assert_eq!(Airdrop::get_escrow(0, OTHER_TOKEN_ID), 30);
+		assert_eq!(balance_of(Airdrop::account_id(), TOKEN_ID), 150);
+		assert_eq!(balance_of(ALICE, OTHER_TOKEN_ID), 970);
+	});
+}
+
+#[test]
+fn create_should_fail() {
+	new_test_ext().execute_with(|| {
+		let root = merkle::root(&leaves());
+		assert_noop!(
+			Airdrop::create(
+				Origin::signed(ALICE),
+				root,
+				vec![TOKEN_ID, OTHER_TOKEN_ID].try_into().unwrap(),
+				vec![150].try_into().unwrap(),
+				10
+			),
+			Error::<Test>::LengthMismatch
+		);
+		assert_noop!(
+			Airdrop::create(
+				Origin::signed(ALICE),
+				root,
+				vec![TOKEN_ID].try_into().unwrap(),
+				vec![0].try_into().unwrap(),
+				10
+			),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			Airdrop::create(
+				Origin::signed(ALICE),
+				root,
+				vec![TOKEN_ID].try_into().unwrap(),
+				vec![150].try_into().unwrap(),
+				9
+			),
+			Error::<Test>::InvalidClaimPeriod
+		);
+	});
+}
+
+#[test]
+fn claim_should_work() {
+	new_test_ext().execute_with(|| {
+		create(150);
+		for position in 0..CLAIMS.len() {
+			assert_ok!(claim(position));
+		}
+		System::assert_last_event(Event::Airdrop(crate::Event::Claimed {
+			airdrop_id: 0,
+			index: 40,
+			account: BOB,
+			token_id: OTHER_TOKEN_ID,
+			amount: 30,
+		}));
+		assert_eq!(balance_of(BOB, TOKEN_ID), 100);
+		assert_eq!(balance_of(BOB, OTHER_TOKEN_ID), 30);
+		assert_eq!(balance_of(CHARLIE, TOKEN_ID), 50);
+		assert_eq!(Airdrop::get_escrow(0, TOKEN_ID), 0);
+		assert!(Airdrop::is_claimed(0, 0));
+		assert!(Airdrop::is_claimed(0, 40));
+		assert!(!Airdrop::is_claimed(0, 8));
+	});
+}
+
+#[test]
+fn claim_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(claim(0), Error::<Test>::UnknownAirdrop);
+		create(150);
+		assert_noop!(
+			Airdrop::claim(Origin::signed(BOB), 0, 0, BOB, TOKEN_ID, 101, proof(0)),
+			Error::<Test>::InvalidProof
+		);
+		assert_noop!(
+			Airdrop::claim(Origin::signed(BOB), 0, 0, BOB, TOKEN_ID, 100, proof(1)),
+			Error::<Test>::InvalidProof
+		);
+		assert_ok!(claim(0));
+		assert_noop!(claim(0), Error::<Test>::AlreadyClaimed);
+	});
+}
+
+#[test]
+fn claim_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		create(150);
+		System::set_block_number(11);
+		assert_noop!(claim(0), Error::<Test>::AirdropExpired);
+	});
+}
+
+#[test]
+fn claim_should_fail_3() {
+	new_test_ext().execute_with(|| {
+		// less escrowed than the tree hands out
+		create(120);
+		assert_ok!(claim(0));
+		assert_noop!(claim(1), Error::<Test>::InsufficientEscrow);
+	});
+}
+
+#[test]
+fn reclaim_should_work() {
+	new_test_ext().execute_with(|| {
+		create(150);
+		assert_ok!(claim(0));
+		System::set_block_number(11);
+		assert_noop!(Airdrop::reclaim(Origin::signed(BOB), 0), Error::<Test>::NotDistributor);
+		assert_ok!(Airdrop::reclaim(Origin::signed(ALICE), 0));
+		System::assert_last_event(Event::Airdrop(crate::Event::Reclaimed { airdrop_id: 0 }));
+		assert_eq!(balance_of(ALICE, TOKEN_ID), 900);
+		assert_eq!(balance_of(ALICE, OTHER_TOKEN_ID), 1_000);
+		assert_eq!(balance_of(Airdrop::account_id(), TOKEN_ID), 0);
+		assert_eq!(Airdrop::get_airdrop(0), None);
+		assert_eq!(Airdrop::get_escrow(0, TOKEN_ID), 0);
+		assert_noop!(Airdrop::reclaim(Origin::signed(ALICE), 0), Error::<Test>::UnknownAirdrop);
+	});
+}
+
+#[test]
+fn reclaim_should_fail() {
+	new_test_ext().execute_with(|| {
+		create(150);
+		System::set_block_number(10);
+		assert_noop!(Airdrop::reclaim(Origin::signed(ALICE), 0), Error::<Test>::AirdropNotExpired);
+	});
+}
@@ -0,0 +1,13 @@
+use crate::merkle::Hash;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Airdrop<AccountId, BlockNumber> {
+	pub distributor: AccountId,
+	/// Root of the tree of `(index, account, token_id, amount)` claims, see `merkle`.
+	pub merkle_root: Hash,
+	/// First block claims are closed and the distributor may take the rest back.
+	pub expires_at: BlockNumber,
+}
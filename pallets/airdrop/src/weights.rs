@@ -0,0 +1,87 @@
+//! Weights for pallet_airdrop.
+//!
+//! These are hand-written estimates that follow the storage accesses of each call, not the output
+//! of a benchmark run. Replace them with measured weights by running:
+//! ./target/release/node-template benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_airdrop --extrinsic '*' --steps 50 --repeat 20
+//! --output ./pallets/airdrop/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_airdrop.
+pub trait WeightInfo {
+	fn create(n: u32) -> Weight;
+	fn claim(p: u32) -> Weight;
+	fn reclaim(n: u32) -> Weight;
+}
+
+/// Weights for pallet_airdrop using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Airdrop NextAirdropId (r:1 w:1)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	// Storage: Airdrop Escrow (r:1 w:1)
+	// Storage: Airdrop Airdrops (r:0 w:1)
+	fn create(n: u32) -> Weight {
+		(21_530_000 as Weight)
+			.saturating_add((33_470_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Airdrop Airdrops (r:1 w:0)
+	// Storage: Airdrop ClaimedBitmap (r:1 w:1)
+	// Storage: Airdrop Escrow (r:1 w:1)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	fn claim(p: u32) -> Weight {
+		(52_310_000 as Weight)
+			.saturating_add((1_840_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Airdrop Airdrops (r:1 w:1)
+	// Storage: Airdrop Escrow (r:1 w:1)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	fn reclaim(n: u32) -> Weight {
+		(24_120_000 as Weight)
+			.saturating_add((31_650_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create(n: u32) -> Weight {
+		(21_530_000 as Weight)
+			.saturating_add((33_470_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	fn claim(p: u32) -> Weight {
+		(52_310_000 as Weight)
+			.saturating_add((1_840_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn reclaim(n: u32) -> Weight {
+		(24_120_000 as Weight)
+			.saturating_add((31_650_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+}
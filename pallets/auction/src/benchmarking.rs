@@ -0,0 +1,127 @@
+//! Benchmarking setup for pallet-auction
+
+use super::*;
+
+use crate::Pallet as Auction;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get, Hooks};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use traits::{Erc1155, Erc1155Mintable};
+
+const SEED: u32 = 0;
+
+fn english<T: Config>() -> AuctionKind<BalanceOf<T>> {
+	AuctionKind::English { reserve_price: 1_000u32.into(), min_increment: 10u32.into() }
+}
+
+/// Start `n` auctions of 10 units each paid in the native currency, ending in the same block.
+fn setup<T: Config>(n: u32, kind: AuctionKind<BalanceOf<T>>) -> (T::AccountId, TokenIdOf<T>)
+where
+	T::Tokens: Erc1155Mintable<T::AccountId>,
+	TokenIdOf<T>: From<u32>,
+{
+	let caller: T::AccountId = whitelisted_caller();
+	let token_id: TokenIdOf<T> = 0u32.into();
+	T::Tokens::create(&caller, token_id).unwrap();
+	T::Tokens::mint(&caller, token_id, (10 * n.max(1)).into()).unwrap();
+	for _ in 0..n {
+		Auction::<T>::create(
+			RawOrigin::Signed(caller.clone()).into(),
+			token_id,
+			10u32.into(),
+			PaymentAsset::Native,
+			kind,
+			T::MaxDuration::get(),
+		)
+		.unwrap();
+	}
+	(caller, token_id)
+}
+
+fn funded_bidder<T: Config>(index: u32) -> T::AccountId {
+	let bidder: T::AccountId = account("bidder", index, SEED);
+	T::Currency::make_free_balance_be(&bidder, NativeBalanceOf::<T>::max_value() / 2u32.into());
+	bidder
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T::Tokens: Erc1155Mintable<T::AccountId>,
+			TokenIdOf<T>: From<u32>,
+	}
+
+	create {
+		let caller: T::AccountId = whitelisted_caller();
+		let token_id: TokenIdOf<T> = 0u32.into();
+		T::Tokens::create(&caller, token_id)?;
+		T::Tokens::mint(&caller, token_id, 10u32.into())?;
+	}: _(RawOrigin::Signed(caller), token_id, 10u32.into(), PaymentAsset::Native, english::<T>(), T::MaxDuration::get())
+	verify {
+		assert!(Auction::<T>::get_auction(0).is_some());
+	}
+
+	bid {
+		setup::<T>(1, english::<T>());
+		let previous = funded_bidder::<T>(0);
+		Auction::<T>::bid(RawOrigin::Signed(previous).into(), 0, 1_000u32.into())?;
+		let bidder = funded_bidder::<T>(1);
+		// late enough to extend the auction
+		frame_system::Pallet::<T>::set_block_number(T::MaxDuration::get() - 1u32.into());
+	}: _(RawOrigin::Signed(bidder.clone()), 0, 2_000u32.into())
+	verify {
+		assert_eq!(
+			Auction::<T>::get_auction(0).unwrap().highest_bid,
+			Some((bidder, 2_000u32.into()))
+		);
+	}
+
+	buy {
+		let (_, token_id) = setup::<T>(
+			1,
+			AuctionKind::Dutch { start_price: 2_000u32.into(), end_price: 1_000u32.into() },
+		);
+		let buyer = funded_bidder::<T>(0);
+	}: _(RawOrigin::Signed(buyer.clone()), 0)
+	verify {
+		assert_eq!(T::Tokens::balance_of(&buyer, token_id)?, 10u32.into());
+	}
+
+	cancel {
+		let (caller, token_id) = setup::<T>(1, english::<T>());
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert_eq!(T::Tokens::balance_of(&caller, token_id)?, 10u32.into());
+	}
+
+	settle {
+		let (_, token_id) = setup::<T>(1, english::<T>());
+		let bidder = funded_bidder::<T>(0);
+		Auction::<T>::bid(RawOrigin::Signed(bidder.clone()).into(), 0, 1_000u32.into())?;
+		frame_system::Pallet::<T>::set_block_number(
+			Auction::<T>::get_auction(0).unwrap().end,
+		);
+	}: _(RawOrigin::Signed(bidder.clone()), 0)
+	verify {
+		assert_eq!(T::Tokens::balance_of(&bidder, token_id)?, 10u32.into());
+	}
+
+	on_initialize {
+		let n in 0 .. T::MaxEndingPerBlock::get();
+		setup::<T>(n, english::<T>());
+		for i in 0..n {
+			let bidder = funded_bidder::<T>(i);
+			Auction::<T>::bid(RawOrigin::Signed(bidder).into(), i as AuctionId, 1_000u32.into())?;
+		}
+		let end = frame_system::Pallet::<T>::block_number() + T::MaxDuration::get();
+	}: {
+		Auction::<T>::on_initialize(end);
+	}
+	verify {
+		assert_eq!(Auction::<T>::get_next_auction_id(), n as AuctionId);
+		assert!(Auction::<T>::get_ending_at(end).is_empty());
+	}
+
+	impl_benchmark_test_suite!(Auction, crate::mock::new_test_ext(), crate::mock::Test);
+}
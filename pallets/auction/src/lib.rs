@@ -0,0 +1,435 @@
+//! English and Dutch auctions of `pallet_erc1155` items.
+//!
+//! The seller escrows the lot with the pallet and picks whether bids are paid in the native
+//! currency or in an erc1155 token. English auctions escrow the highest bid, native bids are
+//! reserved under the pallet id and token bids held by the pallet, and refund it when outbid. A bid
+//! within `Config::ExtensionPeriod` of the end pushes the end back to stop sniping. Dutch auctions
+//! sell to the first buyer at the linearly decaying price. Auctions still open at their end are
+//! settled in `on_initialize` of their end block.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{Auction, AuctionKind, PaymentAsset, WeightInfo};
+	use frame_support::{
+		dispatch::DispatchResult,
+		ensure,
+		pallet_prelude::*,
+		traits::{BalanceStatus, Currency, ExistenceRequirement, NamedReservableCurrency},
+		transactional, PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AccountIdConversion, CheckedAdd, Zero};
+	use traits::Erc1155;
+
+	pub type AuctionId = u64;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type TokenIdOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::TokenId;
+
+	pub type NativeBalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type AuctionOf<T> = Auction<
+		<T as frame_system::Config>::AccountId,
+		TokenIdOf<T>,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		type Tokens: Erc1155<Self::AccountId>;
+		/// Currency of native bids, reserved under the pallet id while they lead.
+		type Currency: NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
+		/// Account holding the lots and token bids, also names the reserves of native bids.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		#[pallet::constant]
+		type MinDuration: Get<Self::BlockNumber>;
+		#[pallet::constant]
+		type MaxDuration: Get<Self::BlockNumber>;
+		/// Bids this close to the end of an English auction move the end to this far after the bid.
+		#[pallet::constant]
+		type ExtensionPeriod: Get<Self::BlockNumber>;
+		/// Maximum number of auctions settled in one block.
+		#[pallet::constant]
+		type MaxEndingPerBlock: Get<u32>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_auction_id)]
+	pub(super) type NextAuctionId<T: Config> = StorageValue<_, AuctionId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_auction)]
+	pub(super) type Auctions<T: Config> = StorageMap<_, Blake2_128Concat, AuctionId, AuctionOf<T>>;
+
+	/// Auctions to settle at the start of a block.
+	#[pallet::storage]
+	#[pallet::getter(fn get_ending_at)]
+	pub(super) type EndingAt<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<AuctionId, T::MaxEndingPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		Created {
+			auction_id: AuctionId,
+			seller: T::AccountId,
+			token_id: TokenIdOf<T>,
+			amount: BalanceOf<T>,
+			asset: PaymentAsset<TokenIdOf<T>>,
+			kind: AuctionKind<BalanceOf<T>>,
+			end: T::BlockNumber,
+		},
+		/// `bidder` leads with `amount`, `end` is the possibly extended end of the auction.
+		BidPlaced {
+			auction_id: AuctionId,
+			bidder: T::AccountId,
+			amount: BalanceOf<T>,
+			end: T::BlockNumber,
+		},
+		/// The lot went to `winner` for `price`, or back to the seller if there is no winner.
+		Settled {
+			auction_id: AuctionId,
+			winner: Option<T::AccountId>,
+			price: Option<BalanceOf<T>>,
+		},
+		/// Settlement in `on_initialize` failed, it can be retried with `settle`.
+		SettlementFailed {
+			auction_id: AuctionId,
+			error: DispatchError,
+		},
+		Cancelled {
+			auction_id: AuctionId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		ZeroAmount,
+		InvalidDuration,
+		/// Dutch auctions must start above their end price, English increments must not be zero.
+		InvalidPrice,
+		UnknownAuction,
+		NotSeller,
+		NotEnglish,
+		NotDutch,
+		OwnAuction,
+		AuctionEnded,
+		AuctionNotEnded,
+		BidTooLow,
+		/// English auctions can't be cancelled once they have a bid.
+		HasBids,
+		/// Too many auctions end in the same block.
+		TooManyEndings,
+		Overflow,
+		/// Less of a native bid is reserved than it was escrowed with.
+		InsufficientReserve,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let ending = EndingAt::<T>::take(now);
+			for &auction_id in ending.iter() {
+				if let Err(error) = Self::do_settle(auction_id) {
+					Self::deposit_event(Event::SettlementFailed { auction_id, error });
+				}
+			}
+			T::WeightInfo::on_initialize(ending.len() as u32)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Auction `amount` of `token_id` for `duration` blocks.
+		#[pallet::weight(T::WeightInfo::create())]
+		#[transactional]
+		pub fn create(
+			origin: OriginFor<T>,
+			token_id: TokenIdOf<T>,
+			amount: BalanceOf<T>,
+			asset: PaymentAsset<TokenIdOf<T>>,
+			kind: AuctionKind<BalanceOf<T>>,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(
+				!duration.is_zero() &&
+					duration >= T::MinDuration::get() &&
+					duration <= T::MaxDuration::get(),
+				Error::<T>::InvalidDuration
+			);
+			match kind {
+				AuctionKind::English { min_increment, .. } => {
+					ensure!(!min_increment.is_zero(), Error::<T>::InvalidPrice)
+				},
+				AuctionKind::Dutch { start_price, end_price } => {
+					ensure!(start_price > end_price, Error::<T>::InvalidPrice)
+				},
+			}
+			T::Tokens::transfer(&seller, &Self::account_id(), token_id, amount)?;
+			let auction_id = Self::get_next_auction_id();
+			NextAuctionId::<T>::put(auction_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+			let start = frame_system::Pallet::<T>::block_number();
+			let end = start.checked_add(&duration).ok_or(Error::<T>::Overflow)?;
+			Self::schedule(auction_id, end)?;
+			Auctions::<T>::insert(
+				auction_id,
+				Auction {
+					seller: seller.clone(),
+					token_id,
+					amount,
+					asset,
+					kind,
+					start,
+					end,
+					highest_bid: None,
+				},
+			);
+			Self::deposit_event(Event::Created {
+				auction_id,
+				seller,
+				token_id,
+				amount,
+				asset,
+				kind,
+				end,
+			});
+			Ok(())
+		}
+
+		/// Bid `amount` on an English auction, the previous highest bid is refunded.
+		#[pallet::weight(T::WeightInfo::bid())]
+		#[transactional]
+		pub fn bid(
+			origin: OriginFor<T>,
+			auction_id: AuctionId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			let mut auction = Self::get_auction(auction_id).ok_or(Error::<T>::UnknownAuction)?;
+			ensure!(auction.seller != bidder, Error::<T>::OwnAuction);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < auction.end, Error::<T>::AuctionEnded);
+			let min_bid = match (&auction.kind, &auction.highest_bid) {
+				(AuctionKind::English { reserve_price, .. }, None) => *reserve_price,
+				(AuctionKind::English { min_increment, .. }, Some((_, highest))) =>
+					highest.checked_add(min_increment).ok_or(Error::<T>::Overflow)?,
+				(AuctionKind::Dutch { .. }, _) => return Err(Error::<T>::NotEnglish.into()),
+			};
+			ensure!(!amount.is_zero() && amount >= min_bid, Error::<T>::BidTooLow);
+			if let Some((previous, previous_amount)) = auction.highest_bid.take() {
+				Self::refund_bid(&previous, &auction.asset, previous_amount)?;
+			}
+			Self::escrow_bid(&bidder, &auction.asset, amount)?;
+			let extended_end = now.saturating_add(T::ExtensionPeriod::get());
+			if extended_end > auction.end {
+				Self::unschedule(auction_id, auction.end);
+				Self::schedule(auction_id, extended_end)?;
+				auction.end = extended_end;
+			}
+			auction.highest_bid = Some((bidder.clone(), amount));
+			let end = auction.end;
+			Auctions::<T>::insert(auction_id, auction);
+			Self::deposit_event(Event::BidPlaced { auction_id, bidder, amount, end });
+			Ok(())
+		}
+
+		/// Buy the lot of a Dutch auction at its current price.
+		#[pallet::weight(T::WeightInfo::buy())]
+		#[transactional]
+		pub fn buy(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			let auction = Self::get_auction(auction_id).ok_or(Error::<T>::UnknownAuction)?;
+			ensure!(auction.seller != buyer, Error::<T>::OwnAuction);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < auction.end, Error::<T>::AuctionEnded);
+			let price = auction.dutch_price_at(now).ok_or(Error::<T>::NotDutch)?;
+			match auction.asset {
+				PaymentAsset::Native => T::Currency::transfer(
+					&buyer,
+					&auction.seller,
+					Self::native(price)?,
+					ExistenceRequirement::KeepAlive,
+				)?,
+				PaymentAsset::Token(token_id) =>
+					T::Tokens::transfer(&buyer, &auction.seller, token_id, price)?,
+			}
+			T::Tokens::transfer(&Self::account_id(), &buyer, auction.token_id, auction.amount)?;
+			Self::unschedule(auction_id, auction.end);
+			Auctions::<T>::remove(auction_id);
+			Self::deposit_event(Event::Settled {
+				auction_id,
+				winner: Some(buyer),
+				price: Some(price),
+			});
+			Ok(())
+		}
+
+		/// Withdraw a Dutch auction or an English auction without bids.
+		#[pallet::weight(T::WeightInfo::cancel())]
+		#[transactional]
+		pub fn cancel(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let auction = Self::get_auction(auction_id).ok_or(Error::<T>::UnknownAuction)?;
+			ensure!(auction.seller == sender, Error::<T>::NotSeller);
+			ensure!(auction.highest_bid.is_none(), Error::<T>::HasBids);
+			T::Tokens::transfer(&Self::account_id(), &sender, auction.token_id, auction.amount)?;
+			Self::unschedule(auction_id, auction.end);
+			Auctions::<T>::remove(auction_id);
+			Self::deposit_event(Event::Cancelled { auction_id });
+			Ok(())
+		}
+
+		/// Settle an ended auction whose settlement in `on_initialize` failed.
+		#[pallet::weight(T::WeightInfo::settle())]
+		pub fn settle(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let auction = Self::get_auction(auction_id).ok_or(Error::<T>::UnknownAuction)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= auction.end,
+				Error::<T>::AuctionNotEnded
+			);
+			Self::do_settle(auction_id)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding the lots and token bids of all open auctions.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		fn reserve_id() -> [u8; 8] {
+			T::PalletId::get().0
+		}
+
+		fn native(amount: BalanceOf<T>) -> Result<NativeBalanceOf<T>, Error<T>> {
+			let amount: u128 = amount.try_into().map_err(|_| Error::<T>::Overflow)?;
+			amount.try_into().map_err(|_| Error::<T>::Overflow)
+		}
+
+		fn schedule(auction_id: AuctionId, end: T::BlockNumber) -> DispatchResult {
+			EndingAt::<T>::try_mutate(end, |ending| {
+				ending.try_push(auction_id).map_err(|_| Error::<T>::TooManyEndings.into())
+			})
+		}
+
+		fn unschedule(auction_id: AuctionId, end: T::BlockNumber) {
+			EndingAt::<T>::mutate_exists(end, |ending| {
+				if let Some(ids) = ending {
+					ids.retain(|&id| id != auction_id);
+					if ids.is_empty() {
+						*ending = None;
+					}
+				}
+			});
+		}
+
+		fn escrow_bid(
+			who: &T::AccountId,
+			asset: &PaymentAsset<TokenIdOf<T>>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			match *asset {
+				PaymentAsset::Native =>
+					T::Currency::reserve_named(&Self::reserve_id(), who, Self::native(amount)?),
+				PaymentAsset::Token(token_id) =>
+					T::Tokens::transfer(who, &Self::account_id(), token_id, amount),
+			}
+		}
+
+		fn refund_bid(
+			who: &T::AccountId,
+			asset: &PaymentAsset<TokenIdOf<T>>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			match *asset {
+				PaymentAsset::Native => {
+					let remainder = T::Currency::unreserve_named(
+						&Self::reserve_id(),
+						who,
+						Self::native(amount)?,
+					);
+					ensure!(remainder.is_zero(), Error::<T>::InsufficientReserve);
+					Ok(())
+				},
+				PaymentAsset::Token(token_id) =>
+					T::Tokens::transfer(&Self::account_id(), who, token_id, amount),
+			}
+		}
+
+		/// Hand the lot to the highest bidder and the bid to the seller, or return the lot to the
+		/// seller if nobody bid.
+		#[transactional]
+		fn do_settle(auction_id: AuctionId) -> DispatchResult {
+			let auction = Auctions::<T>::take(auction_id).ok_or(Error::<T>::UnknownAuction)?;
+			let (winner, price) = match auction.highest_bid {
+				Some((bidder, amount)) => {
+					match auction.asset {
+						PaymentAsset::Native => {
+							let remainder = T::Currency::repatriate_reserved_named(
+								&Self::reserve_id(),
+								&bidder,
+								&auction.seller,
+								Self::native(amount)?,
+								BalanceStatus::Free,
+							)?;
+							ensure!(remainder.is_zero(), Error::<T>::InsufficientReserve);
+						},
+						PaymentAsset::Token(token_id) => T::Tokens::transfer(
+							&Self::account_id(),
+							&auction.seller,
+							token_id,
+							amount,
+						)?,
+					}
+					(bidder, Some(amount))
+				},
+				None => (auction.seller.clone(), None),
+			};
+			T::Tokens::transfer(&Self::account_id(), &winner, auction.token_id, auction.amount)?;
+			Self::deposit_event(Event::Settled {
+				auction_id,
+				winner: price.map(|_| winner),
+				price,
+			});
+			Ok(())
+		}
+	}
+}
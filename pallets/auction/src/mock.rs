@@ -0,0 +1,170 @@
+use crate as pallet_auction;
+use frame_support::{parameter_types, traits::Hooks, PalletId};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Erc1155: pallet_erc1155::{Pallet, Call, Storage, Event<T>},
+		Auction: pallet_auction::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u128>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 1;
+	pub const MaxReserves: u32 = 50;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u128;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const Decimals: u32 = 0;
+	pub const MaxCheckpoints: u32 = 4;
+	pub const MigrationItemsPerBlock: u32 = 2;
+	pub const MaxBatchSize: u32 = 10;
+	pub const MaxDataLength: u32 = 32;
+	pub const StringLimit: u32 = 50;
+	pub const AttributeDepositBase: u128 = 10;
+	pub const DepositPerByte: u128 = 1;
+	pub const KeyLimit: u32 = 16;
+	pub const ValueLimit: u32 = 32;
+	pub const MaxEmissionSchedules: u32 = 4;
+	pub const MaxLocks: u32 = 4;
+	pub const Erc1155PalletId: PalletId = PalletId(*b"py/e1155");
+	pub const MaxNestingDepth: u32 = 3;
+}
+
+impl pallet_erc1155::Config for Test {
+	type Event = Event;
+	type TokenId = u32;
+	type Balance = u128;
+	type CollectionId = u32;
+	type Decimals = Decimals;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type MaxCheckpoints = MaxCheckpoints;
+	type MigrationItemsPerBlock = MigrationItemsPerBlock;
+	type MaxBatchSize = MaxBatchSize;
+	type MaxDataLength = MaxDataLength;
+	type StringLimit = StringLimit;
+	type OnReceived = ();
+	type Currency = Balances;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type MaxEmissionSchedules = MaxEmissionSchedules;
+	type MaxLocks = MaxLocks;
+	type PalletId = Erc1155PalletId;
+	type MaxNestingDepth = MaxNestingDepth;
+	type OnDust = ();
+	type FeeExempt = frame_support::traits::Nothing;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const AuctionPalletId: PalletId = PalletId(*b"py/auctn");
+	pub const MinDuration: u64 = 10;
+	pub const MaxDuration: u64 = 100;
+	pub const ExtensionPeriod: u64 = 5;
+	pub const MaxEndingPerBlock: u32 = 2;
+}
+
+impl pallet_auction::Config for Test {
+	type Event = Event;
+	type Tokens = Erc1155;
+	type Currency = Balances;
+	type PalletId = AuctionPalletId;
+	type MinDuration = MinDuration;
+	type MaxDuration = MaxDuration;
+	type ExtensionPeriod = ExtensionPeriod;
+	type MaxEndingPerBlock = MaxEndingPerBlock;
+	type WeightInfo = ();
+}
+
+pub const ITEM_ID: u32 = 1;
+pub const PAYMENT_ID: u32 = 2;
+pub const ALICE: u64 = 1;
+pub const BOB: u64 = 2;
+pub const CHARLIE: u64 = 3;
+
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		Auction::on_initialize(System::block_number());
+	}
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(ALICE, 1_000), (BOB, 1_000), (CHARLIE, 1_000)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		<Erc1155 as traits::Erc1155Mintable<u64>>::create(&ALICE, ITEM_ID).unwrap();
+		<Erc1155 as traits::Erc1155Mintable<u64>>::mint(&ALICE, ITEM_ID, 10).unwrap();
+		<Erc1155 as traits::Erc1155Mintable<u64>>::create(&BOB, PAYMENT_ID).unwrap();
+		<Erc1155 as traits::Erc1155Mintable<u64>>::mint(&BOB, PAYMENT_ID, 1_000).unwrap();
+		<Erc1155 as traits::Erc1155Mintable<u64>>::mint(&CHARLIE, PAYMENT_ID, 1_000).unwrap();
+	});
+	ext
+}
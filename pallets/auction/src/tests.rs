@@ -0,0 +1,326 @@
+use crate::{mock::*, AuctionKind, Error, PaymentAsset};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, Get, NamedReservableCurrency, ReservableCurrency},
+};
+
+const ENGLISH: AuctionKind<u128> = AuctionKind::English { reserve_price: 100, min_increment: 10 };
+const DUTCH: AuctionKind<u128> = AuctionKind::Dutch { start_price: 200, end_price: 100 };
+
+fn balance_of(who: u64, token_id: u32) -> u128 {
+	Erc1155::get_balance(who, token_id)
+}
+
+fn create(asset: PaymentAsset<u32>, kind: AuctionKind<u128>) {
+	assert_ok!(Auction::create(Origin::signed(ALICE), ITEM_ID, 5, asset, kind, 20));
+}
+
+#[test]
+fn create_should_work() {
+	new_test_ext().execute_with(|| {
+		create(PaymentAsset::Native, ENGLISH);
+		System::assert_last_event(Event::Auction(crate::Event::Created {
+			auction_id: 0,
+			seller: ALICE,
+			token_id: ITEM_ID,
+			amount: 5,
+			asset: PaymentAsset::Native,
+			kind: ENGLISH,
+			end: 21,
+		}));
+		assert_eq!(balance_of(ALICE, ITEM_ID), 5);
+		assert_eq!(balance_of(Auction::account_id(), ITEM_ID), 5);
+		assert_eq!(Auction::get_ending_at(21).into_inner(), vec![0]);
+		assert_eq!(Auction::get_next_auction_id(), 1);
+	});
+}
+
+#[test]
+fn create_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Auction::create(Origin::signed(ALICE), ITEM_ID, 0, PaymentAsset::Native, ENGLISH, 20),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			Auction::create(Origin::signed(ALICE), ITEM_ID, 5, PaymentAsset::Native, ENGLISH, 9),
+			Error::<Test>::InvalidDuration
+		);
+		assert_noop!(
+			Auction::create(Origin::signed(ALICE), ITEM_ID, 5, PaymentAsset::Native, ENGLISH, 101),
+			Error::<Test>::InvalidDuration
+		);
+		assert_noop!(
+			Auction::create(
+				Origin::signed(ALICE),
+				ITEM_ID,
+				5,
+				PaymentAsset::Native,
+				AuctionKind::English { reserve_price: 100, min_increment: 0 },
+				20
+			),
+			Error::<Test>::InvalidPrice
+		);
+		assert_noop!(
+			Auction::create(
+				Origin::signed(ALICE),
+				ITEM_ID,
+				5,
+				PaymentAsset::Native,
+				AuctionKind::Dutch { start_price: 100, end_price: 100 },
+				20
+			),
+			Error::<Test>::InvalidPrice
+		);
+	});
+}
+
+#[test]
+fn create_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Auction::create(
+			Origin::signed(ALICE),
+			ITEM_ID,
+			1,
+			PaymentAsset::Native,
+			ENGLISH,
+			20
+		));
+		assert_ok!(Auction::create(
+			Origin::signed(ALICE),
+			ITEM_ID,
+			1,
+			PaymentAsset::Native,
+			DUTCH,
+			20
+		));
+		assert_noop!(
+			Auction::create(Origin::signed(ALICE), ITEM_ID, 1, PaymentAsset::Native, ENGLISH, 20),
+			Error::<Test>::TooManyEndings
+		);
+	});
+}
+
+#[test]
+fn bid_should_work() {
+	new_test_ext().execute_with(|| {
+		create(PaymentAsset::Native, ENGLISH);
+		assert_ok!(Auction::bid(Origin::signed(BOB), 0, 100));
+		assert_eq!(Balances::reserved_balance(BOB), 100);
+		assert_ok!(Auction::bid(Origin::signed(CHARLIE), 0, 110));
+		System::assert_last_event(Event::Auction(crate::Event::BidPlaced {
+			auction_id: 0,
+			bidder: CHARLIE,
+			amount: 110,
+			end: 21,
+		}));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 110);
+		assert_eq!(Auction::get_auction(0).unwrap().highest_bid, Some((CHARLIE, 110)));
+	});
+}
+
+#[test]
+fn bid_in_tokens_should_work() {
+	new_test_ext().execute_with(|| {
+		create(PaymentAsset::Token(PAYMENT_ID), ENGLISH);
+		assert_ok!(Auction::bid(Origin::signed(BOB), 0, 100));
+		assert_eq!(balance_of(BOB, PAYMENT_ID), 900);
+		assert_eq!(balance_of(Auction::account_id(), PAYMENT_ID), 100);
+		assert_ok!(Auction::bid(Origin::signed(CHARLIE), 0, 150));
+		assert_eq!(balance_of(BOB, PAYMENT_ID), 1_000);
+		assert_eq!(balance_of(CHARLIE, PAYMENT_ID), 850);
+		assert_eq!(balance_of(Auction::account_id(), PAYMENT_ID), 150);
+
+		run_to_block(21);
+		assert_eq!(balance_of(ALICE, PAYMENT_ID), 150);
+		assert_eq!(balance_of(CHARLIE, ITEM_ID), 5);
+		assert_eq!(balance_of(Auction::account_id(), PAYMENT_ID), 0);
+	});
+}
+
+#[test]
+fn bid_should_extend_auction() {
+	new_test_ext().execute_with(|| {
+		create(PaymentAsset::Native, ENGLISH);
+		run_to_block(18);
+		assert_ok!(Auction::bid(Origin::signed(BOB), 0, 100));
+		System::assert_last_event(Event::Auction(crate::Event::BidPlaced {
+			auction_id: 0,
+			bidder: BOB,
+			amount: 100,
+			end: 23,
+		}));
+		assert!(Auction::get_ending_at(21).is_empty());
+		assert_eq!(Auction::get_ending_at(23).into_inner(), vec![0]);
+		run_to_block(22);
+		assert!(Auction::get_auction(0).is_some());
+		run_to_block(23);
+		assert!(Auction::get_auction(0).is_none());
+		assert_eq!(balance_of(BOB, ITEM_ID), 5);
+	});
+}
+
+#[test]
+fn bid_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Auction::bid(Origin::signed(BOB), 0, 100), Error::<Test>::UnknownAuction);
+		create(PaymentAsset::Native, ENGLISH);
+		assert_noop!(Auction::bid(Origin::signed(ALICE), 0, 100), Error::<Test>::OwnAuction);
+		assert_noop!(Auction::bid(Origin::signed(BOB), 0, 99), Error::<Test>::BidTooLow);
+		assert_ok!(Auction::bid(Origin::signed(BOB), 0, 100));
+		assert_noop!(Auction::bid(Origin::signed(CHARLIE), 0, 109), Error::<Test>::BidTooLow);
+		assert_noop!(
+			Auction::bid(Origin::signed(CHARLIE), 0, 2_000),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn bid_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		create(PaymentAsset::Native, DUTCH);
+		assert_noop!(Auction::bid(Origin::signed(BOB), 0, 200), Error::<Test>::NotEnglish);
+	});
+}
+
+#[test]
+fn english_auction_should_settle() {
+	new_test_ext().execute_with(|| {
+		create(PaymentAsset::Native, ENGLISH);
+		assert_ok!(Auction::bid(Origin::signed(BOB), 0, 100));
+		assert_ok!(Auction::bid(Origin::signed(CHARLIE), 0, 110));
+		run_to_block(21);
+		System::assert_last_event(Event::Auction(crate::Event::Settled {
+			auction_id: 0,
+			winner: Some(CHARLIE),
+			price: Some(110),
+		}));
+		assert_eq!(Balances::free_balance(ALICE), 1_110);
+		assert_eq!(Balances::free_balance(CHARLIE), 890);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_eq!(balance_of(CHARLIE, ITEM_ID), 5);
+		assert_eq!(Auction::get_auction(0), None);
+		assert!(Auction::get_ending_at(21).is_empty());
+	});
+}
+
+#[test]
+fn unsold_auction_should_return_lot() {
+	new_test_ext().execute_with(|| {
+		create(PaymentAsset::Native, ENGLISH);
+		create(PaymentAsset::Native, DUTCH);
+		run_to_block(21);
+		System::assert_has_event(Event::Auction(crate::Event::Settled {
+			auction_id: 0,
+			winner: None,
+			price: None,
+		}));
+		System::assert_last_event(Event::Auction(crate::Event::Settled {
+			auction_id: 1,
+			winner: None,
+			price: None,
+		}));
+		assert_eq!(balance_of(ALICE, ITEM_ID), 10);
+		assert_eq!(balance_of(Auction::account_id(), ITEM_ID), 0);
+	});
+}
+
+#[test]
+fn buy_should_work() {
+	new_test_ext().execute_with(|| {
+		create(PaymentAsset::Native, DUTCH);
+		run_to_block(11);
+		assert_ok!(Auction::buy(Origin::signed(BOB), 0));
+		System::assert_last_event(Event::Auction(crate::Event::Settled {
+			auction_id: 0,
+			winner: Some(BOB),
+			price: Some(150),
+		}));
+		assert_eq!(Balances::free_balance(BOB), 850);
+		assert_eq!(Balances::free_balance(ALICE), 1_150);
+		assert_eq!(balance_of(BOB, ITEM_ID), 5);
+		assert_eq!(Auction::get_auction(0), None);
+		assert!(Auction::get_ending_at(21).is_empty());
+	});
+}
+
+#[test]
+fn buy_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Auction::buy(Origin::signed(BOB), 0), Error::<Test>::UnknownAuction);
+		create(PaymentAsset::Native, ENGLISH);
+		assert_noop!(Auction::buy(Origin::signed(BOB), 0), Error::<Test>::NotDutch);
+		create(PaymentAsset::Token(PAYMENT_ID), DUTCH);
+		assert_noop!(Auction::buy(Origin::signed(ALICE), 1), Error::<Test>::OwnAuction);
+		assert_ok!(Auction::buy(Origin::signed(BOB), 1));
+		assert_eq!(balance_of(ALICE, PAYMENT_ID), 200);
+	});
+}
+
+#[test]
+fn cancel_should_work() {
+	new_test_ext().execute_with(|| {
+		create(PaymentAsset::Native, ENGLISH);
+		assert_ok!(Auction::cancel(Origin::signed(ALICE), 0));
+		System::assert_last_event(Event::Auction(crate::Event::Cancelled { auction_id: 0 }));
+		assert_eq!(balance_of(ALICE, ITEM_ID), 10);
+		assert_eq!(Auction::get_auction(0), None);
+		assert!(Auction::get_ending_at(21).is_empty());
+	});
+}
+
+#[test]
+fn cancel_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Auction::cancel(Origin::signed(ALICE), 0), Error::<Test>::UnknownAuction);
+		create(PaymentAsset::Native, ENGLISH);
+		assert_noop!(Auction::cancel(Origin::signed(BOB), 0), Error::<Test>::NotSeller);
+		assert_ok!(Auction::bid(Origin::signed(BOB), 0, 100));
+		assert_noop!(Auction::cancel(Origin::signed(ALICE), 0), Error::<Test>::HasBids);
+	});
+}
+
+#[test]
+fn settle_should_retry_failed_settlement() {
+	new_test_ext().execute_with(|| {
+		create(PaymentAsset::Native, ENGLISH);
+		assert_ok!(Auction::bid(Origin::signed(BOB), 0, 100));
+		assert_noop!(Auction::settle(Origin::signed(BOB), 0), Error::<Test>::AuctionNotEnded);
+		assert_ok!(Erc1155::freeze(Origin::signed(ALICE), ITEM_ID));
+		run_to_block(21);
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			Event::Auction(crate::Event::SettlementFailed { auction_id: 0, .. })
+		)));
+		assert!(Auction::get_auction(0).is_some());
+		assert_eq!(Balances::reserved_balance(BOB), 100);
+
+		assert_ok!(Erc1155::thaw(Origin::signed(ALICE), ITEM_ID));
+		assert_ok!(Auction::settle(Origin::signed(CHARLIE), 0));
+		assert_eq!(balance_of(BOB, ITEM_ID), 5);
+		assert_eq!(Balances::free_balance(ALICE), 1_100);
+	});
+}
+
+#[test]
+fn settle_should_fail_without_reserved_bid() {
+	new_test_ext().execute_with(|| {
+		create(PaymentAsset::Native, ENGLISH);
+		assert_ok!(Auction::bid(Origin::signed(BOB), 0, 100));
+		Balances::unreserve_named(&AuctionPalletId::get().0, &BOB, 1);
+		assert_noop!(
+			Auction::bid(Origin::signed(CHARLIE), 0, 110),
+			Error::<Test>::InsufficientReserve
+		);
+		run_to_block(21);
+		System::assert_last_event(Event::Auction(crate::Event::SettlementFailed {
+			auction_id: 0,
+			error: Error::<Test>::InsufficientReserve.into(),
+		}));
+		assert!(Auction::get_auction(0).is_some());
+		assert_eq!(Balances::free_balance(ALICE), 1_000);
+		assert_eq!(Balances::reserved_balance(BOB), 99);
+	});
+}
@@ -0,0 +1,54 @@
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Saturating},
+	Perbill, RuntimeDebug,
+};
+
+/// What bids and prices of an auction are paid in.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum PaymentAsset<TokenId> {
+	Native,
+	Token(TokenId),
+}
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum AuctionKind<Balance> {
+	/// Ascending bids, the highest bid at or above `reserve_price` wins at the end.
+	English { reserve_price: Balance, min_increment: Balance },
+	/// The price falls linearly from `start_price` to `end_price`, the first buyer wins.
+	Dutch { start_price: Balance, end_price: Balance },
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Auction<AccountId, TokenId, Balance, BlockNumber> {
+	pub seller: AccountId,
+	pub token_id: TokenId,
+	pub amount: Balance,
+	pub asset: PaymentAsset<TokenId>,
+	pub kind: AuctionKind<Balance>,
+	pub start: BlockNumber,
+	/// Block the auction is settled in, English auctions are extended by late bids.
+	pub end: BlockNumber,
+	/// Escrowed highest bid of an English auction.
+	pub highest_bid: Option<(AccountId, Balance)>,
+}
+
+impl<AccountId, TokenId, Balance, BlockNumber> Auction<AccountId, TokenId, Balance, BlockNumber>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// Price of a Dutch auction at `now`, `None` for English auctions.
+	pub fn dutch_price_at(&self, now: BlockNumber) -> Option<Balance> {
+		match self.kind {
+			AuctionKind::Dutch { start_price, end_price } => {
+				let elapsed = now.saturating_sub(self.start).min(self.end - self.start);
+				let decay = Perbill::from_rational(elapsed, self.end - self.start)
+					.mul_floor(start_price.saturating_sub(end_price));
+				Some(start_price.saturating_sub(decay))
+			},
+			AuctionKind::English { .. } => None,
+		}
+	}
+}
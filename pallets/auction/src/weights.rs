@@ -0,0 +1,142 @@
+//! Weights for pallet_auction.
+//!
+//! These are hand-written estimates that follow the storage accesses of each call, not the output
+//! of a benchmark run. Replace them with measured weights by running:
+//! ./target/release/node-template benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_auction --extrinsic '*' --steps 50 --repeat 20
+//! --output ./pallets/auction/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_auction.
+pub trait WeightInfo {
+	fn create() -> Weight;
+	fn bid() -> Weight;
+	fn buy() -> Weight;
+	fn cancel() -> Weight;
+	fn settle() -> Weight;
+	fn on_initialize(n: u32) -> Weight;
+}
+
+/// Weights for pallet_auction using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Auction NextAuctionId (r:1 w:1)
+	// Storage: Auction EndingAt (r:1 w:1)
+	// Storage: Auction Auctions (r:0 w:1)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	fn create() -> Weight {
+		(68_410_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Auction Auctions (r:1 w:1)
+	// Storage: Auction EndingAt (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn bid() -> Weight {
+		(54_720_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Auction Auctions (r:1 w:1)
+	// Storage: Auction EndingAt (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	fn buy() -> Weight {
+		(97_860_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Auction Auctions (r:1 w:1)
+	// Storage: Auction EndingAt (r:1 w:1)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	fn cancel() -> Weight {
+		(59_180_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Auction Auctions (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	fn settle() -> Weight {
+		(88_290_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Auction EndingAt (r:1 w:1)
+	// Storage: Auction Auctions (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	fn on_initialize(n: u32) -> Weight {
+		(4_120_000 as Weight)
+			.saturating_add((86_540_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((10 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(n as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create() -> Weight {
+		(68_410_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn bid() -> Weight {
+		(54_720_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn buy() -> Weight {
+		(97_860_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn cancel() -> Weight {
+		(59_180_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn settle() -> Weight {
+		(88_290_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn on_initialize(n: u32) -> Weight {
+		(4_120_000 as Weight)
+			.saturating_add((86_540_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((10 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(n as Weight)))
+	}
+}
@@ -0,0 +1,43 @@
+;; Forwards its input to the chain extension and returns the status code followed by the output.
+;; The first four bytes of the input are the function id, the rest is passed to the extension.
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "seal_call_chain_extension"
+		(func $seal_call_chain_extension (param i32 i32 i32 i32 i32) (result i32))
+	)
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 16 16))
+
+	;; [0, 4) length of the input buffer
+	(data (i32.const 0) "\00\01")
+
+	;; [4, 8) length of the output buffer
+	(data (i32.const 4) "\00\01")
+
+	;; [8, 264) input buffer
+
+	;; [264, 268) status code
+
+	;; [268, 524) output buffer
+
+	(func (export "deploy"))
+
+	(func (export "call")
+		(call $seal_input (i32.const 8) (i32.const 0))
+		(i32.store
+			(i32.const 264)
+			(call $seal_call_chain_extension
+				(i32.load (i32.const 8)) ;; func_id
+				(i32.const 12) ;; input_ptr
+				(i32.sub (i32.load (i32.const 0)) (i32.const 4)) ;; input_len
+				(i32.const 268) ;; output_ptr
+				(i32.const 4) ;; output_len_ptr
+			)
+		)
+		(call $seal_return
+			(i32.const 0) ;; flags
+			(i32.const 264) ;; data_ptr
+			(i32.add (i32.load (i32.const 4)) (i32.const 4)) ;; data_len
+		)
+	)
+)
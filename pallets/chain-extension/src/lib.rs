@@ -0,0 +1,167 @@
+//! `pallet_contracts` chain extension giving contracts access to `pallet_erc1155` tokens and the
+//! `pallet_dex` pool.
+//!
+//! Arguments and return values are SCALE encoded. Calls that move tokens act as the account of the
+//! calling contract. Failures of the underlying pallets are reported through the status code, so
+//! the contract can handle them; an unknown function id traps the contract.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Encode, MaxEncodedLen};
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::Get,
+};
+use pallet_contracts::chain_extension::{
+	ChainExtension, Environment, Ext, InitState, RetVal, SysConfig, UncheckedFrom,
+};
+use pallet_erc1155::WeightInfo;
+use sp_runtime::{DispatchError, DispatchResult};
+use traits::Erc1155;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// `(owner: AccountId, token_id: TokenId) -> Balance`
+pub const BALANCE_OF: u32 = 0x0001;
+/// `(token_id: TokenId) -> Balance`
+pub const TOTAL_SUPPLY: u32 = 0x0002;
+/// `(owner: AccountId, operator: AccountId) -> bool`
+pub const IS_APPROVED_FOR_ALL: u32 = 0x0003;
+/// `(to: AccountId, token_id: TokenId, amount: Balance)`
+pub const TRANSFER: u32 = 0x0004;
+/// `(operator: AccountId, approved: bool)`
+pub const SET_APPROVAL_FOR_ALL: u32 = 0x0005;
+/// `(token_id: TokenId, amount: Balance) -> Balance`, the paired token amount a swap returns.
+pub const DEX_QUOTE: u32 = 0x0101;
+/// `(token_id: TokenId, amount: Balance) -> Balance`, sells `amount` to the pool and returns the
+/// paired token amount bought. The contract must have approved the pool.
+pub const DEX_BUY_TOKEN: u32 = 0x0102;
+
+/// Status code returned to the contract.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum StatusCode {
+	Success = 0,
+	/// `pallet_erc1155` rejected the call.
+	TokenError = 1,
+	/// `pallet_dex` rejected the call.
+	DexError = 2,
+}
+
+pub struct Erc1155Extension;
+
+impl<T> ChainExtension<T> for Erc1155Extension
+where
+	T: pallet_contracts::Config
+		+ pallet_erc1155::Config
+		+ pallet_dex::Config<Tokens = pallet_erc1155::Pallet<T>>,
+	T::TokenId: MaxEncodedLen,
+	T::Balance: MaxEncodedLen,
+{
+	fn call<E>(func_id: u32, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
+	where
+		E: Ext<T = T>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		let mut env = env.buf_in_buf_out();
+		let status = match func_id {
+			BALANCE_OF => {
+				env.charge_weight(T::DbWeight::get().reads(1))?;
+				let (owner, token_id): (T::AccountId, T::TokenId) = env.read_as()?;
+				let balance = pallet_erc1155::Pallet::<T>::get_balance(&owner, token_id);
+				env.write(&balance.encode(), false, None)?;
+				StatusCode::Success
+			},
+			TOTAL_SUPPLY => {
+				env.charge_weight(T::DbWeight::get().reads(1))?;
+				let token_id: T::TokenId = env.read_as()?;
+				let supply = pallet_erc1155::Pallet::<T>::get_total_supply(token_id);
+				env.write(&supply.encode(), false, None)?;
+				StatusCode::Success
+			},
+			IS_APPROVED_FOR_ALL => {
+				env.charge_weight(T::DbWeight::get().reads(2))?;
+				let (owner, operator): (T::AccountId, T::AccountId) = env.read_as()?;
+				let approved = pallet_erc1155::Pallet::<T>::get_approval(&owner, &operator);
+				env.write(&approved.encode(), false, None)?;
+				StatusCode::Success
+			},
+			TRANSFER => {
+				env.charge_weight(pallet_erc1155::Pallet::<T>::transfer_single_weight())?;
+				let (to, token_id, amount): (T::AccountId, T::TokenId, T::Balance) =
+					env.read_as()?;
+				let contract = env.ext().address().clone();
+				let result = transactional(|| {
+					pallet_erc1155::Pallet::<T>::do_transfer(
+						&contract,
+						&contract,
+						&to,
+						&token_id,
+						&amount,
+						&[],
+					)
+					.map(|_| ())
+				});
+				status(result, StatusCode::TokenError)
+			},
+			SET_APPROVAL_FOR_ALL => {
+				env.charge_weight(
+					<T as pallet_erc1155::Config>::WeightInfo::set_approval_for_all(),
+				)?;
+				let (operator, approved): (T::AccountId, bool) = env.read_as()?;
+				let contract = env.ext().address().clone();
+				<pallet_erc1155::Pallet<T> as Erc1155<T::AccountId>>::set_approval_for_all(
+					&contract, &operator, approved,
+				);
+				StatusCode::Success
+			},
+			DEX_QUOTE => {
+				env.charge_weight(T::DbWeight::get().reads(5))?;
+				let (token_id, amount): (T::TokenId, T::Balance) = env.read_as()?;
+				match pallet_dex::Pallet::<T>::quote(token_id, amount) {
+					Ok(bought) => {
+						env.write(&bought.encode(), false, None)?;
+						StatusCode::Success
+					},
+					Err(_) => StatusCode::DexError,
+				}
+			},
+			DEX_BUY_TOKEN => {
+				// a quote followed by two transfers
+				env.charge_weight(T::DbWeight::get().reads(5).saturating_add(
+					pallet_erc1155::Pallet::<T>::transfer_single_weight().saturating_mul(2),
+				))?;
+				let (token_id, amount): (T::TokenId, T::Balance) = env.read_as()?;
+				let contract = env.ext().address().clone();
+				match pallet_dex::Pallet::<T>::do_buy_token(&contract, token_id, amount) {
+					Ok(bought) => {
+						env.write(&bought.encode(), false, None)?;
+						StatusCode::Success
+					},
+					Err(_) => StatusCode::DexError,
+				}
+			},
+			_ => return Err(DispatchError::Other("unknown chain extension function id")),
+		};
+		Ok(RetVal::Converging(status as u32))
+	}
+}
+
+/// Roll back all storage changes of `f` if it fails.
+fn transactional(f: impl FnOnce() -> DispatchResult) -> DispatchResult {
+	with_transaction(|| match f() {
+		Ok(()) => TransactionOutcome::Commit(Ok(())),
+		Err(e) => TransactionOutcome::Rollback(Err(e)),
+	})
+}
+
+fn status(result: DispatchResult, error: StatusCode) -> StatusCode {
+	match result {
+		Ok(()) => StatusCode::Success,
+		Err(_) => error,
+	}
+}
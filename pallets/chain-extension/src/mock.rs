@@ -0,0 +1,201 @@
+use crate::Erc1155Extension;
+use frame_support::{
+	parameter_types,
+	traits::Nothing,
+	weights::{constants::WEIGHT_PER_SECOND, Weight},
+	PalletId,
+};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, Convert, IdentityLookup},
+	AccountId32, Perbill,
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the chain extension.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		Randomness: pallet_randomness_collective_flip::{Pallet, Storage},
+		Contracts: pallet_contracts::{Pallet, Call, Storage, Event<T>},
+		Erc1155: pallet_erc1155::{Pallet, Call, Storage, Event<T>},
+		Dex: pallet_dex::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+	pub BlockWeights: frame_system::limits::BlockWeights =
+		frame_system::limits::BlockWeights::simple_max(2 * WEIGHT_PER_SECOND);
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = BlockWeights;
+	type BlockLength = ();
+	type DbWeight = frame_support::weights::constants::RocksDbWeight;
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId32;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u128>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u128;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 1;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+impl pallet_randomness_collective_flip::Config for Test {}
+
+parameter_types! {
+	pub const ContractDeposit: u128 = 16;
+	pub const DeletionQueueDepth: u32 = 1024;
+	pub const DeletionWeightLimit: Weight = 500_000_000_000;
+	pub Schedule: pallet_contracts::Schedule<Test> = Default::default();
+}
+
+impl Convert<Weight, u128> for Test {
+	fn convert(weight: Weight) -> u128 {
+		weight.into()
+	}
+}
+
+impl pallet_contracts::Config for Test {
+	type Time = Timestamp;
+	type Randomness = Randomness;
+	type Currency = Balances;
+	type Event = Event;
+	type Call = Call;
+	type CallFilter = Nothing;
+	type ContractDeposit = ContractDeposit;
+	type CallStack = [pallet_contracts::Frame<Self>; 31];
+	type WeightPrice = Self;
+	type WeightInfo = ();
+	type ChainExtension = Erc1155Extension;
+	type DeletionQueueDepth = DeletionQueueDepth;
+	type DeletionWeightLimit = DeletionWeightLimit;
+	type Schedule = Schedule;
+}
+
+parameter_types! {
+	pub const Decimals: u32 = 0;
+	pub const MaxCheckpoints: u32 = 4;
+	pub const MigrationItemsPerBlock: u32 = 2;
+	pub const MaxBatchSize: u32 = 10;
+	pub const MaxDataLength: u32 = 32;
+	pub const StringLimit: u32 = 50;
+	pub const AttributeDepositBase: u128 = 10;
+	pub const DepositPerByte: u128 = 1;
+	pub const KeyLimit: u32 = 16;
+	pub const ValueLimit: u32 = 32;
+	pub const MaxEmissionSchedules: u32 = 4;
+	pub const MaxLocks: u32 = 4;
+	pub const Erc1155PalletId: PalletId = PalletId(*b"py/e1155");
+	pub const MaxNestingDepth: u32 = 3;
+}
+
+impl pallet_erc1155::Config for Test {
+	type Event = Event;
+	type TokenId = u32;
+	type Balance = u128;
+	type CollectionId = u32;
+	type Decimals = Decimals;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId32>;
+	type MaxCheckpoints = MaxCheckpoints;
+	type MigrationItemsPerBlock = MigrationItemsPerBlock;
+	type MaxBatchSize = MaxBatchSize;
+	type MaxDataLength = MaxDataLength;
+	type StringLimit = StringLimit;
+	type OnReceived = ();
+	type Currency = Balances;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type MaxEmissionSchedules = MaxEmissionSchedules;
+	type MaxLocks = MaxLocks;
+	type PalletId = Erc1155PalletId;
+	type MaxNestingDepth = MaxNestingDepth;
+	type OnDust = ();
+	type FeeExempt = Nothing;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const Fee: Perbill = Perbill::from_percent(99);
+}
+
+impl pallet_dex::Config for Test {
+	type Event = Event;
+	type Tokens = Erc1155;
+	type Fee = Fee;
+	type GovernanceOrigin = frame_system::EnsureRoot<AccountId32>;
+	type WeightInfo = ();
+}
+
+pub const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
+pub const BOB: AccountId32 = AccountId32::new([2u8; 32]);
+pub const POOL: AccountId32 = AccountId32::new([101u8; 32]);
+
+pub const GAS_LIMIT: Weight = 100_000_000_000;
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(ALICE, 1_000_000_000), (BOB, 1_000_000_000)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
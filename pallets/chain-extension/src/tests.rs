@@ -0,0 +1,168 @@
+use crate::{
+	mock::*, StatusCode, BALANCE_OF, DEX_BUY_TOKEN, DEX_QUOTE, IS_APPROVED_FOR_ALL,
+	SET_APPROVAL_FOR_ALL, TOTAL_SUPPLY, TRANSFER,
+};
+use codec::{Decode, Encode};
+use frame_support::assert_ok;
+use sp_runtime::{traits::Hash, AccountId32};
+use traits::Erc1155 as _;
+
+const TOKEN_1_ID: u32 = 1;
+const TOKEN_2_ID: u32 = 2;
+
+const FIXTURE: &str = include_str!("../fixtures/forward.wat");
+
+fn deploy() -> AccountId32 {
+	let wasm = wat::parse_str(FIXTURE).unwrap();
+	let code_hash = <Test as frame_system::Config>::Hashing::hash(&wasm);
+	assert_ok!(Contracts::instantiate_with_code(
+		Origin::signed(ALICE),
+		100_000,
+		GAS_LIMIT,
+		wasm,
+		vec![],
+		vec![]
+	));
+	Contracts::contract_address(&ALICE, &code_hash, &[])
+}
+
+/// Call the extension through the fixture, returns the status code and the output.
+fn call(contract: &AccountId32, func_id: u32, input: impl Encode) -> (u32, Vec<u8>) {
+	let mut data = func_id.to_le_bytes().to_vec();
+	data.extend(input.encode());
+	let result = Contracts::bare_call(ALICE, contract.clone(), 0, GAS_LIMIT, data, false);
+	let output = result.result.unwrap().data.0;
+	(u32::decode(&mut &output[..4]).unwrap(), output[4..].to_vec())
+}
+
+fn decode<T: Decode>(output: Vec<u8>) -> T {
+	T::decode(&mut &output[..]).unwrap()
+}
+
+/// Creates both tokens and moves part of the supply to `contract`.
+fn init_tokens(contract: &AccountId32) {
+	assert_ok!(<Erc1155 as traits::Erc1155<AccountId32>>::init(
+		&ALICE,
+		&vec![TOKEN_1_ID, TOKEN_2_ID],
+		&vec![1000, 1000]
+	));
+	assert_ok!(Erc1155::transfer(&ALICE, contract, TOKEN_1_ID, 300));
+}
+
+fn init_dex() {
+	<Erc1155 as traits::Erc1155<AccountId32>>::set_approval_for_all(&ALICE, &POOL, true);
+	assert_ok!(Dex::init(Origin::signed(ALICE), POOL, TOKEN_1_ID, 100, TOKEN_2_ID, 1000));
+}
+
+#[test]
+fn balance_of_should_work() {
+	new_test_ext().execute_with(|| {
+		let contract = deploy();
+		init_tokens(&contract);
+
+		let (status, output) = call(&contract, BALANCE_OF, (contract.clone(), TOKEN_1_ID));
+		assert_eq!(status, StatusCode::Success as u32);
+		assert_eq!(decode::<u128>(output), 300);
+
+		let (_, output) = call(&contract, TOTAL_SUPPLY, TOKEN_1_ID);
+		assert_eq!(decode::<u128>(output), 1000);
+	})
+}
+
+#[test]
+fn transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		let contract = deploy();
+		init_tokens(&contract);
+
+		let (status, _) = call(&contract, TRANSFER, (BOB, TOKEN_1_ID, 120u128));
+		assert_eq!(status, StatusCode::Success as u32);
+		assert_eq!(Erc1155::get_balance(&contract, TOKEN_1_ID), 180);
+		assert_eq!(Erc1155::get_balance(&BOB, TOKEN_1_ID), 120);
+	})
+}
+
+#[test]
+fn transfer_should_fail() {
+	new_test_ext().execute_with(|| {
+		let contract = deploy();
+		init_tokens(&contract);
+
+		// the contract can only move its own tokens
+		let (status, _) = call(&contract, TRANSFER, (BOB, TOKEN_2_ID, 1u128));
+		assert_eq!(status, StatusCode::TokenError as u32);
+		let (status, _) = call(&contract, TRANSFER, (BOB, TOKEN_1_ID, 301u128));
+		assert_eq!(status, StatusCode::TokenError as u32);
+		assert_eq!(Erc1155::get_balance(&contract, TOKEN_1_ID), 300);
+		assert_eq!(Erc1155::get_balance(&BOB, TOKEN_1_ID), 0);
+	})
+}
+
+#[test]
+fn set_approval_for_all_should_work() {
+	new_test_ext().execute_with(|| {
+		let contract = deploy();
+
+		let (status, _) = call(&contract, SET_APPROVAL_FOR_ALL, (BOB, true));
+		assert_eq!(status, StatusCode::Success as u32);
+		assert!(Erc1155::get_approval(&contract, &BOB));
+
+		let (_, output) = call(&contract, IS_APPROVED_FOR_ALL, (contract.clone(), BOB));
+		assert!(decode::<bool>(output));
+	})
+}
+
+#[test]
+fn dex_should_work() {
+	new_test_ext().execute_with(|| {
+		let contract = deploy();
+		init_tokens(&contract);
+		init_dex();
+
+		let (status, output) = call(&contract, DEX_QUOTE, (TOKEN_1_ID, 100u128));
+		assert_eq!(status, StatusCode::Success as u32);
+		let quote = decode::<u128>(output);
+		assert_eq!(quote, 497); // 0.99 * 100 * 1000 / (100 + 99)
+
+		assert_eq!(call(&contract, SET_APPROVAL_FOR_ALL, (POOL, true)).0, 0);
+		let (status, output) = call(&contract, DEX_BUY_TOKEN, (TOKEN_1_ID, 100u128));
+		assert_eq!(status, StatusCode::Success as u32);
+		assert_eq!(decode::<u128>(output), quote);
+		assert_eq!(Erc1155::get_balance(&contract, TOKEN_1_ID), 200);
+		assert_eq!(Erc1155::get_balance(&contract, TOKEN_2_ID), quote);
+	})
+}
+
+#[test]
+fn dex_should_fail() {
+	new_test_ext().execute_with(|| {
+		let contract = deploy();
+		init_tokens(&contract);
+
+		let (status, _) = call(&contract, DEX_QUOTE, (TOKEN_1_ID, 100u128));
+		assert_eq!(status, StatusCode::DexError as u32);
+
+		// the pool was not approved
+		init_dex();
+		let (status, _) = call(&contract, DEX_BUY_TOKEN, (TOKEN_1_ID, 100u128));
+		assert_eq!(status, StatusCode::DexError as u32);
+		assert_eq!(Erc1155::get_balance(&contract, TOKEN_1_ID), 300);
+	})
+}
+
+#[test]
+fn unknown_function_should_fail() {
+	new_test_ext().execute_with(|| {
+		let contract = deploy();
+
+		let result = Contracts::bare_call(
+			ALICE,
+			contract,
+			0,
+			GAS_LIMIT,
+			0x0fffu32.to_le_bytes().to_vec(),
+			false,
+		);
+		assert!(result.result.is_err());
+	})
+}
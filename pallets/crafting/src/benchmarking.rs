@@ -0,0 +1,87 @@
+//! Benchmarking setup for pallet-crafting
+
+use super::*;
+
+use crate::Pallet as Crafting;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_std::{convert::TryInto, vec::Vec};
+use traits::{Erc1155, Erc1155Mintable};
+
+/// One of each of the tokens `first..first + n`.
+fn items<T: Config>(first: u32, n: u32) -> ItemsOf<T>
+where
+	TokenIdOf<T>: From<u32>,
+{
+	(first..first + n)
+		.map(|token_id| (token_id.into(), 1u32.into()))
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+/// Create `i` input tokens held by the caller and `o` output tokens it issues.
+fn setup<T: Config>(i: u32, o: u32) -> T::AccountId
+where
+	T::Tokens: Erc1155Mintable<T::AccountId>,
+	TokenIdOf<T>: From<u32>,
+{
+	let caller: T::AccountId = whitelisted_caller();
+	for token_id in 0..i + o {
+		T::Tokens::create(&caller, token_id.into()).unwrap();
+	}
+	for token_id in 0..i {
+		T::Tokens::mint(&caller, token_id.into(), 1u32.into()).unwrap();
+	}
+	caller
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T::Tokens: Erc1155Mintable<T::AccountId>,
+			TokenIdOf<T>: From<u32>,
+	}
+
+	register {
+		let i in 1 .. T::MaxItems::get();
+		let o in 1 .. T::MaxItems::get();
+		let caller = setup::<T>(i, o);
+	}: _(RawOrigin::Signed(caller), items::<T>(0, i), items::<T>(i, o), 1u32.into(), Some(1))
+	verify {
+		assert!(Crafting::<T>::get_recipe(0).is_some());
+	}
+
+	remove {
+		let caller = setup::<T>(1, 1);
+		Crafting::<T>::register(
+			RawOrigin::Signed(caller.clone()).into(),
+			items::<T>(0, 1),
+			items::<T>(1, 1),
+			1u32.into(),
+			Some(1),
+		)?;
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert!(Crafting::<T>::get_recipe(0).is_none());
+	}
+
+	craft {
+		let i in 1 .. T::MaxItems::get();
+		let o in 1 .. T::MaxItems::get();
+		let caller = setup::<T>(i, o);
+		Crafting::<T>::register(
+			RawOrigin::Signed(caller.clone()).into(),
+			items::<T>(0, i),
+			items::<T>(i, o),
+			1u32.into(),
+			Some(1),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert_eq!(T::Tokens::balance_of(&caller, i.into()), Ok(1u32.into()));
+	}
+
+	impl_benchmark_test_suite!(Crafting, crate::mock::new_test_ext(), crate::mock::Test);
+}
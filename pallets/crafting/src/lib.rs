@@ -0,0 +1,186 @@
+//! Crafting recipes over `pallet_erc1155` tokens.
+//!
+//! A token issuer registers recipes that burn a set of input tokens from the crafter and mint
+//! tokens it issues in return. Recipes may make each account wait between crafts and may be
+//! limited to a number of crafts in total. Crafting is atomic, either every input is burned and
+//! every output minted or nothing happens, and fails once the owner of the recipe no longer issues
+//! one of its outputs.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{Recipe, WeightInfo};
+	use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*, transactional};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Saturating, Zero};
+	use traits::{Erc1155, Erc1155Mintable};
+
+	pub type RecipeId = u64;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type TokenIdOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::TokenId;
+
+	pub type ItemsOf<T> = BoundedVec<(TokenIdOf<T>, BalanceOf<T>), <T as Config>::MaxItems>;
+
+	pub type RecipeOf<T> = Recipe<
+		<T as frame_system::Config>::AccountId,
+		ItemsOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		type Tokens: Erc1155Mintable<Self::AccountId>;
+		/// Maximum number of inputs, and of outputs, of a recipe.
+		#[pallet::constant]
+		type MaxItems: Get<u32>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_recipe_id)]
+	pub(super) type NextRecipeId<T: Config> = StorageValue<_, RecipeId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_recipe)]
+	pub(super) type Recipes<T: Config> = StorageMap<_, Blake2_128Concat, RecipeId, RecipeOf<T>>;
+
+	/// Block an account last crafted a recipe with a cooldown.
+	#[pallet::storage]
+	#[pallet::getter(fn get_last_crafted)]
+	pub(super) type LastCrafted<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		RecipeId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::BlockNumber,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		Registered { recipe_id: RecipeId, owner: T::AccountId },
+		Removed { recipe_id: RecipeId },
+		Crafted { recipe_id: RecipeId, who: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Only the issuer of every output can register and craft a recipe.
+		NotIssuer,
+		NoInputs,
+		NoOutputs,
+		ZeroAmount,
+		/// A usage limit of zero.
+		ZeroUses,
+		UnknownRecipe,
+		NotOwner,
+		/// The cooldown since the last craft of the account has not passed yet.
+		CoolingDown,
+		/// The recipe has been crafted as many times as it allows.
+		UsesExhausted,
+		Overflow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a recipe burning `inputs` and minting `outputs`, crafted at most once every
+		/// `cooldown` blocks by an account and `max_uses` times in total.
+		#[pallet::weight(T::WeightInfo::register(inputs.len() as u32, outputs.len() as u32))]
+		pub fn register(
+			origin: OriginFor<T>,
+			inputs: ItemsOf<T>,
+			outputs: ItemsOf<T>,
+			cooldown: T::BlockNumber,
+			max_uses: Option<u32>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			ensure!(!inputs.is_empty(), Error::<T>::NoInputs);
+			ensure!(!outputs.is_empty(), Error::<T>::NoOutputs);
+			ensure!(
+				inputs.iter().chain(outputs.iter()).all(|(_, amount)| !amount.is_zero()),
+				Error::<T>::ZeroAmount
+			);
+			ensure!(max_uses != Some(0), Error::<T>::ZeroUses);
+			for (token_id, _) in outputs.iter() {
+				ensure!(T::Tokens::is_issuer(&owner, *token_id), Error::<T>::NotIssuer);
+			}
+			let recipe_id = Self::get_next_recipe_id();
+			NextRecipeId::<T>::put(recipe_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+			Recipes::<T>::insert(
+				recipe_id,
+				Recipe { owner: owner.clone(), inputs, outputs, cooldown, uses_left: max_uses },
+			);
+			Self::deposit_event(Event::Registered { recipe_id, owner });
+			Ok(())
+		}
+
+		/// Stop a recipe from being crafted.
+		#[pallet::weight(T::WeightInfo::remove())]
+		pub fn remove(origin: OriginFor<T>, recipe_id: RecipeId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let recipe = Self::get_recipe(recipe_id).ok_or(Error::<T>::UnknownRecipe)?;
+			ensure!(recipe.owner == who, Error::<T>::NotOwner);
+			// the cooldowns left in `LastCrafted` are never read again, recipe ids aren't reused
+			Recipes::<T>::remove(recipe_id);
+			Self::deposit_event(Event::Removed { recipe_id });
+			Ok(())
+		}
+
+		/// Burn the inputs of a recipe from the caller and mint its outputs to them, as long as the
+		/// owner of the recipe still issues every output.
+		#[pallet::weight(T::WeightInfo::craft(T::MaxItems::get(), T::MaxItems::get()))]
+		#[transactional]
+		pub fn craft(origin: OriginFor<T>, recipe_id: RecipeId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut recipe = Self::get_recipe(recipe_id).ok_or(Error::<T>::UnknownRecipe)?;
+			if let Some(uses_left) = recipe.uses_left.as_mut() {
+				ensure!(*uses_left > 0, Error::<T>::UsesExhausted);
+				*uses_left -= 1;
+				Recipes::<T>::insert(recipe_id, &recipe);
+			}
+			if !recipe.cooldown.is_zero() {
+				let now = frame_system::Pallet::<T>::block_number();
+				if let Some(last) = Self::get_last_crafted(recipe_id, &who) {
+					ensure!(now >= last.saturating_add(recipe.cooldown), Error::<T>::CoolingDown);
+				}
+				LastCrafted::<T>::insert(recipe_id, &who, now);
+			}
+			for (token_id, amount) in recipe.inputs {
+				T::Tokens::burn(&who, token_id, amount)?;
+			}
+			for (token_id, amount) in recipe.outputs {
+				ensure!(T::Tokens::is_issuer(&recipe.owner, token_id), Error::<T>::NotIssuer);
+				T::Tokens::mint(&who, token_id, amount)?;
+			}
+			Self::deposit_event(Event::Crafted { recipe_id, who });
+			Ok(())
+		}
+	}
+}
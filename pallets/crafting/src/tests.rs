@@ -0,0 +1,218 @@
+use crate::{mock::*, Error, Recipe};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::Perbill;
+use sp_std::convert::TryInto;
+
+/// Member of a collection of CHARLIE.
+const SHIELD: u32 = 13;
+
+fn balance_of(who: u64, token_id: u32) -> u128 {
+	Erc1155::get_balance(who, token_id)
+}
+
+fn items(items: Vec<(u32, u128)>) -> BoundedVec<(u32, u128), MaxItems> {
+	items.try_into().unwrap()
+}
+
+/// Two wood and an iron make a sword.
+fn register(cooldown: u64, max_uses: Option<u32>) {
+	assert_ok!(Crafting::register(
+		Origin::signed(ALICE),
+		items(vec![(WOOD, 2), (IRON, 1)]),
+		items(vec![(SWORD, 1)]),
+		cooldown,
+		max_uses
+	));
+}
+
+#[test]
+fn register_should_work() {
+	new_test_ext().execute_with(|| {
+		register(5, Some(3));
+		System::assert_last_event(Event::Crafting(crate::Event::Registered {
+			recipe_id: 0,
+			owner: ALICE,
+		}));
+		assert_eq!(
+			Crafting::get_recipe(0),
+			Some(Recipe {
+				owner: ALICE,
+				inputs: items(vec![(WOOD, 2), (IRON, 1)]),
+				outputs: items(vec![(SWORD, 1)]),
+				cooldown: 5,
+				uses_left: Some(3),
+			})
+		);
+		assert_eq!(Crafting::get_next_recipe_id(), 1);
+	});
+}
+
+#[test]
+fn register_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		// anyone's tokens can be inputs, only the owner's can be outputs
+		assert_noop!(
+			Crafting::register(
+				Origin::signed(BOB),
+				items(vec![(WOOD, 2)]),
+				items(vec![(SWORD, 1)]),
+				0,
+				None
+			),
+			Error::<Test>::NotIssuer
+		);
+		assert_noop!(
+			Crafting::register(
+				Origin::signed(ALICE),
+				items(vec![]),
+				items(vec![(SWORD, 1)]),
+				0,
+				None
+			),
+			Error::<Test>::NoInputs
+		);
+		assert_noop!(
+			Crafting::register(
+				Origin::signed(ALICE),
+				items(vec![(WOOD, 2)]),
+				items(vec![]),
+				0,
+				None
+			),
+			Error::<Test>::NoOutputs
+		);
+	});
+}
+
+#[test]
+fn register_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Crafting::register(
+				Origin::signed(ALICE),
+				items(vec![(WOOD, 0)]),
+				items(vec![(SWORD, 1)]),
+				0,
+				None
+			),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			Crafting::register(
+				Origin::signed(ALICE),
+				items(vec![(WOOD, 2)]),
+				items(vec![(SWORD, 0)]),
+				0,
+				None
+			),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			Crafting::register(
+				Origin::signed(ALICE),
+				items(vec![(WOOD, 2)]),
+				items(vec![(SWORD, 1)]),
+				0,
+				Some(0)
+			),
+			Error::<Test>::ZeroUses
+		);
+	});
+}
+
+#[test]
+fn remove_should_work() {
+	new_test_ext().execute_with(|| {
+		register(0, None);
+		assert_noop!(Crafting::remove(Origin::signed(BOB), 0), Error::<Test>::NotOwner);
+		assert_ok!(Crafting::remove(Origin::signed(ALICE), 0));
+		System::assert_last_event(Event::Crafting(crate::Event::Removed { recipe_id: 0 }));
+		assert_eq!(Crafting::get_recipe(0), None);
+		assert_noop!(Crafting::craft(Origin::signed(BOB), 0), Error::<Test>::UnknownRecipe);
+		assert_noop!(Crafting::remove(Origin::signed(ALICE), 0), Error::<Test>::UnknownRecipe);
+	});
+}
+
+#[test]
+fn craft_should_work() {
+	new_test_ext().execute_with(|| {
+		register(0, None);
+		assert_ok!(Crafting::craft(Origin::signed(BOB), 0));
+		assert_ok!(Crafting::craft(Origin::signed(BOB), 0));
+		System::assert_last_event(Event::Crafting(crate::Event::Crafted {
+			recipe_id: 0,
+			who: BOB,
+		}));
+		assert_eq!(balance_of(BOB, WOOD), 6);
+		assert_eq!(balance_of(BOB, IRON), 8);
+		assert_eq!(balance_of(BOB, SWORD), 2);
+		assert_eq!(Erc1155::get_token(WOOD).unwrap().supply, 6);
+		assert_eq!(Crafting::get_last_crafted(0, BOB), None);
+	});
+}
+
+#[test]
+fn craft_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		register(0, None);
+		// CHARLIE has no iron, the wood burned first is given back
+		<Erc1155 as traits::Erc1155Mintable<u64>>::mint(&CHARLIE, WOOD, 2).unwrap();
+		assert_noop!(
+			Crafting::craft(Origin::signed(CHARLIE), 0),
+			pallet_erc1155::Error::<Test>::InsufficientFunds
+		);
+		assert_eq!(balance_of(CHARLIE, WOOD), 2);
+		assert_eq!(balance_of(CHARLIE, SWORD), 0);
+	});
+}
+
+#[test]
+fn craft_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		register(5, None);
+		assert_ok!(Crafting::craft(Origin::signed(BOB), 0));
+		assert_eq!(Crafting::get_last_crafted(0, BOB), Some(1));
+		System::set_block_number(5);
+		assert_noop!(Crafting::craft(Origin::signed(BOB), 0), Error::<Test>::CoolingDown);
+		System::set_block_number(6);
+		assert_ok!(Crafting::craft(Origin::signed(BOB), 0));
+		assert_eq!(Crafting::get_last_crafted(0, BOB), Some(6));
+	});
+}
+
+#[test]
+fn craft_should_fail_3() {
+	new_test_ext().execute_with(|| {
+		register(0, Some(2));
+		<Erc1155 as traits::Erc1155Mintable<u64>>::mint(&CHARLIE, WOOD, 2).unwrap();
+		<Erc1155 as traits::Erc1155Mintable<u64>>::mint(&CHARLIE, IRON, 1).unwrap();
+		assert_ok!(Crafting::craft(Origin::signed(BOB), 0));
+		assert_ok!(Crafting::craft(Origin::signed(CHARLIE), 0));
+		assert_eq!(Crafting::get_recipe(0).unwrap().uses_left, Some(0));
+		assert_noop!(Crafting::craft(Origin::signed(BOB), 0), Error::<Test>::UsesExhausted);
+	});
+}
+
+#[test]
+fn craft_should_fail_4() {
+	new_test_ext().execute_with(|| {
+		// ALICE issues SHIELD as an admin of the collection of CHARLIE
+		assert_ok!(Erc1155::create_collection(Origin::signed(CHARLIE), 1, None, Perbill::zero()));
+		assert_ok!(Erc1155::mint(Origin::signed(CHARLIE), 1, SHIELD, CHARLIE, 1));
+		assert_ok!(Erc1155::set_collection_admin(Origin::signed(CHARLIE), 1, ALICE, true));
+		assert_ok!(Crafting::register(
+			Origin::signed(ALICE),
+			items(vec![(WOOD, 2)]),
+			items(vec![(SWORD, 1), (SHIELD, 1)]),
+			0,
+			None
+		));
+		assert_ok!(Crafting::craft(Origin::signed(BOB), 0));
+		assert_eq!(balance_of(BOB, SHIELD), 1);
+
+		assert_ok!(Erc1155::set_collection_admin(Origin::signed(CHARLIE), 1, ALICE, false));
+		assert_noop!(Crafting::craft(Origin::signed(BOB), 0), Error::<Test>::NotIssuer);
+		assert_eq!(balance_of(BOB, WOOD), 8);
+		assert_eq!(balance_of(BOB, SWORD), 1);
+	});
+}
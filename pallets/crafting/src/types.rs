@@ -0,0 +1,17 @@
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Recipe<AccountId, Items, BlockNumber> {
+	/// Issuer of every output, the pallet mints on its behalf.
+	pub owner: AccountId,
+	/// `(token_id, amount)` burned from the crafter.
+	pub inputs: Items,
+	/// `(token_id, amount)` minted to the crafter.
+	pub outputs: Items,
+	/// Blocks an account waits between two crafts of the recipe.
+	pub cooldown: BlockNumber,
+	/// Crafts left across all accounts, `None` for no limit.
+	pub uses_left: Option<u32>,
+}
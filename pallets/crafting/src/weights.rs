@@ -0,0 +1,91 @@
+//! Weights for pallet_crafting.
+//!
+//! These are hand-written estimates that follow the storage accesses of each call, not the output
+//! of a benchmark run. Replace them with measured weights by running:
+//! ./target/release/node-template benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_crafting --extrinsic '*' --steps 50 --repeat 20
+//! --output ./pallets/crafting/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_crafting.
+pub trait WeightInfo {
+	fn register(i: u32, o: u32) -> Weight;
+	fn remove() -> Weight;
+	fn craft(i: u32, o: u32) -> Weight;
+}
+
+/// Weights for pallet_crafting using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	// Storage: Crafting NextRecipeId (r:1 w:1)
+	// Storage: Crafting Recipes (r:0 w:1)
+	fn register(i: u32, o: u32) -> Weight {
+		(17_840_000 as Weight)
+			.saturating_add((260_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add((4_310_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Crafting Recipes (r:1 w:1)
+	fn remove() -> Weight {
+		(15_020_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Crafting Recipes (r:1 w:1)
+	// Storage: Crafting LastCrafted (r:1 w:1)
+	// Storage: Erc1155 Balances (r:1 w:1)
+	// Storage: Erc1155 TotalSupply (r:1 w:1)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	fn craft(i: u32, o: u32) -> Weight {
+		(21_470_000 as Weight)
+			.saturating_add((14_930_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add((18_920_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn register(i: u32, o: u32) -> Weight {
+		(17_840_000 as Weight)
+			.saturating_add((260_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add((4_310_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn remove() -> Weight {
+		(15_020_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn craft(i: u32, o: u32) -> Weight {
+		(21_470_000 as Weight)
+			.saturating_add((14_930_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add((18_920_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+	}
+}
@@ -2,18 +2,26 @@
 
 use super::*;
 
-#[allow(unused)]
 use crate::Pallet as Dex;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
-use frame_system::RawOrigin;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::{EnsureOrigin, UnfilteredDispatchable};
+use sp_runtime::Perbill;
 
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
-		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+	set_fee {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let call = Call::<T>::set_fee { fee: MAX_FEE };
+	}: { call.dispatch_bypass_filter(origin)? }
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert_eq!(Dex::<T>::fee(), Perbill::one() - MAX_FEE);
+	}
+
+	set_paused {
+		let origin = T::GovernanceOrigin::successful_origin();
+		let call = Call::<T>::set_paused { paused: true };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(Dex::<T>::is_paused());
 	}
 
 	impl_benchmark_test_suite!(Dex, crate::mock::new_test_ext(), crate::mock::Test);
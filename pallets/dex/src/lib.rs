@@ -4,6 +4,9 @@
 /// Learn more about FRAME and the core library of Substrate FRAME pallets:
 /// <https://docs.substrate.io/v3/runtime/frame>
 pub use pallet::*;
+pub use weights::WeightInfo;
+
+pub mod weights;
 
 #[cfg(test)]
 mod mock;
@@ -16,14 +19,18 @@ mod benchmarking;
 
 #[frame_support::pallet]
 pub mod pallet {
+	use crate::WeightInfo;
 	use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*, transactional};
 	use frame_system::pallet_prelude::*;
 	use sp_runtime::{
-		traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, IntegerSquareRoot, Zero},
+		traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, IntegerSquareRoot, One, Zero},
 		Perbill,
 	};
 	use traits::Erc1155;
 
+	/// Highest fee governance can set, as a share of the input left out of the swap.
+	pub const MAX_FEE: Perbill = Perbill::from_percent(10);
+
 	type BalanceOf<T> =
 		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::Balance;
 
@@ -34,8 +41,13 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		type Tokens: Erc1155<Self::AccountId>;
+		/// Share of the input amount that goes into a swap until governance sets another fee,
+		/// e.g. 99% for a 1% fee.
 		#[pallet::constant]
 		type Fee: Get<Perbill>;
+		/// Origin allowed to change the fee and pause the pool.
+		type GovernanceOrigin: EnsureOrigin<Self::Origin>;
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
@@ -59,6 +71,17 @@ pub mod pallet {
 	pub(super) type Liquidity<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
+	/// Share of the input that goes into a swap after governance set the fee, replaces
+	/// `Config::Fee`.
+	#[pallet::storage]
+	#[pallet::getter(fn get_fee_override)]
+	pub(super) type FeeOverride<T: Config> = StorageValue<_, Perbill>;
+
+	/// Swaps and deposits are rejected while the pool is paused, withdrawals stay open.
+	#[pallet::storage]
+	#[pallet::getter(fn is_paused)]
+	pub(super) type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -73,6 +96,9 @@ pub mod pallet {
 		TokenBought(T::AccountId, TokenIdOf<T>, BalanceOf<T>, TokenIdOf<T>, BalanceOf<T>),
 		Deposited(T::AccountId, TokenIdOf<T>, BalanceOf<T>, TokenIdOf<T>, BalanceOf<T>),
 		Withdrawed(T::AccountId, TokenIdOf<T>, BalanceOf<T>, TokenIdOf<T>, BalanceOf<T>),
+		/// Governance set the fee, the share of the input left out of the swap.
+		FeeChanged(Perbill),
+		PauseChanged(bool),
 	}
 
 	#[pallet::error]
@@ -85,6 +111,9 @@ pub mod pallet {
 		WrongShareValue,
 		NoLiquiudity,
 		NoLiquiudityToWithdraw,
+		PoolPaused,
+		/// The fee is above `MAX_FEE`.
+		FeeTooHigh,
 	}
 
 	#[pallet::call]
@@ -137,16 +166,7 @@ pub mod pallet {
 			amount: BalanceOf<T>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Self::initialized()?;
-			Self::has_liquidity()?;
-			let token_to_buy = Self::get_paired_token(token_id).unwrap();
-			let pool = Self::get_pool_address().unwrap();
-			let reserves =
-				T::Tokens::balance_of_batch(vec![&pool, &pool], vec![token_id, token_to_buy])?;
-			let bought = Self::price(amount, reserves[0], reserves[1]).unwrap();
-			T::Tokens::transfer_from_single(&sender, &pool, &token_id, &amount)?;
-			T::Tokens::transfer(&pool, &sender, token_to_buy, bought)?;
-			Self::deposit_event(Event::TokenBought(sender, token_id, amount, token_to_buy, bought));
+			Self::do_buy_token(&sender, token_id, amount)?;
 			Ok(())
 		}
 
@@ -159,6 +179,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			Self::initialized()?;
+			Self::not_paused()?;
 			let paired_token = Self::get_paired_token(token_id).unwrap();
 			let pool = Self::get_pool_address().unwrap();
 			let reserves =
@@ -193,6 +214,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			Self::initialized()?;
+			Self::not_paused()?;
 			let paired_token = Self::get_paired_token(token_id).unwrap();
 			let pool = Self::get_pool_address().unwrap();
 			let reserves =
@@ -308,19 +330,104 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Replace the fee of the pool, `fee` is the share of the input left out of the swap, e.g.
+		/// 1%. `Self::fee` returns the complement.
+		#[pallet::weight(T::WeightInfo::set_fee())]
+		pub fn set_fee(origin: OriginFor<T>, fee: Perbill) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(fee <= MAX_FEE, Error::<T>::FeeTooHigh);
+			FeeOverride::<T>::put(Perbill::one() - fee);
+			Self::deposit_event(Event::FeeChanged(fee));
+			Ok(())
+		}
+
+		/// Pause or resume swaps and deposits.
+		#[pallet::weight(T::WeightInfo::set_paused())]
+		pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			Paused::<T>::put(paused);
+			Self::deposit_event(Event::PauseChanged(paused));
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Share of the input amount that goes into a swap, the rest stays in the pool.
+		pub fn fee() -> Perbill {
+			Self::get_fee_override().unwrap_or_else(T::Fee::get)
+		}
+
+		/// Sell `amount` of `token_id` to the pool, returns the amount of the paired token bought.
+		#[transactional]
+		pub fn do_buy_token(
+			who: &T::AccountId,
+			token_id: TokenIdOf<T>,
+			amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			Self::not_paused()?;
+			let bought = Self::quote(token_id, amount)?;
+			let token_to_buy = Self::get_paired_token(token_id).ok_or(Error::<T>::WrongTokenId)?;
+			let pool = Self::get_pool_address().unwrap();
+			T::Tokens::transfer_from_single(who, &pool, &token_id, &amount)?;
+			T::Tokens::transfer(&pool, who, token_to_buy, bought)?;
+			Self::deposit_event(Event::TokenBought(
+				who.clone(),
+				token_id,
+				amount,
+				token_to_buy,
+				bought,
+			));
+			Ok(bought)
+		}
+
+		/// Amount of the paired token `buy_token` currently returns for `amount` of `token_id`.
+		pub fn quote(
+			token_id: TokenIdOf<T>,
+			amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			Self::initialized()?;
+			Self::has_liquidity()?;
+			let token_to_buy = Self::get_paired_token(token_id).ok_or(Error::<T>::WrongTokenId)?;
+			let pool = Self::get_pool_address().unwrap();
+			let reserves =
+				T::Tokens::balance_of_batch(vec![&pool, &pool], vec![token_id, token_to_buy])?;
+			Ok(Self::price(amount, reserves[0], reserves[1]).ok_or(Error::<T>::Overflow)?)
+		}
+
+		/// Amount of `token_id` `buy_token` currently needs to return at least `bought` of the
+		/// paired token.
+		pub fn quote_input(
+			token_id: TokenIdOf<T>,
+			bought: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			Self::initialized()?;
+			Self::has_liquidity()?;
+			let token_to_buy = Self::get_paired_token(token_id).ok_or(Error::<T>::WrongTokenId)?;
+			let pool = Self::get_pool_address().unwrap();
+			let reserves =
+				T::Tokens::balance_of_batch(vec![&pool, &pool], vec![token_id, token_to_buy])?;
+			let remaining = reserves[1].checked_sub(&bought).ok_or(Error::<T>::NoLiquiudity)?;
+			ensure!(!remaining.is_zero(), Error::<T>::NoLiquiudity);
+			// round up, the pool must not return less than `bought`
+			let input_amount_with_fee = bought
+				.checked_mul(&reserves[0])
+				.and_then(|amount| amount.checked_add(&remaining))
+				.and_then(|amount| amount.checked_sub(&One::one()))
+				.and_then(|amount| amount.checked_div(&remaining))
+				.ok_or(Error::<T>::Overflow)?;
+			Ok(Self::fee().saturating_reciprocal_mul_ceil(input_amount_with_fee))
+		}
+
 		fn price(
 			input_amount: BalanceOf<T>,
 			input_reserve: BalanceOf<T>,
 			output_reserve: BalanceOf<T>,
 		) -> Option<BalanceOf<T>> {
-			let input_amount_with_fee = T::Fee::get() * input_amount;
+			let input_amount_with_fee = Self::fee() * input_amount;
 			input_amount_with_fee
-				.checked_mul(&output_reserve)
-				.unwrap()
-				.checked_div(&input_reserve.checked_add(&input_amount_with_fee).unwrap())
+				.checked_mul(&output_reserve)?
+				.checked_div(&input_reserve.checked_add(&input_amount_with_fee)?)
 		}
 
 		/// Calculate the amount of input token we need to swap for second token to achieve correct ratio
@@ -330,7 +437,7 @@ pub mod pallet {
 			input_reserve: BalanceOf<T>,
 			output_reserve: BalanceOf<T>,
 		) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
-			let fee = T::Fee::get();
+			let fee = Self::fee();
 			let two: BalanceOf<T> = 2u32.try_into().ok().unwrap();
 			let discriminant_sqrt = input_reserve
 				.integer_sqrt_checked()
@@ -355,7 +462,7 @@ pub mod pallet {
 				.checked_div(&(fee * two))
 				.unwrap();
 
-			let bought = Self::price(tokens_to_swap, input_reserve, output_reserve).unwrap();
+			let bought = Self::price(tokens_to_swap, input_reserve, output_reserve)?;
 			Some((tokens_to_swap, bought))
 		}
 
@@ -397,6 +504,11 @@ pub mod pallet {
 			Ok(())
 		}
 
+		fn not_paused() -> Result<(), Error<T>> {
+			ensure!(!Self::is_paused(), <Error<T>>::PoolPaused);
+			Ok(())
+		}
+
 		fn has_liquidity() -> Result<(), Error<T>> {
 			ensure!(
 				Self::get_total_liquidity().unwrap() != BalanceOf::<T>::default(),
@@ -442,3 +554,12 @@ pub mod pallet {
 		}
 	}
 }
+
+/// Matches the pool account, e.g. to exempt it from the transfer fees of the pooled tokens.
+pub struct PoolAccount<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> frame_support::traits::Contains<T::AccountId> for PoolAccount<T> {
+	fn contains(who: &T::AccountId) -> bool {
+		Pallet::<T>::get_pool_address().as_ref() == Some(who)
+	}
+}
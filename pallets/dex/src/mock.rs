@@ -1,5 +1,5 @@
 use crate as pallet_dex;
-use frame_support::parameter_types;
+use frame_support::{parameter_types, BoundedVec, PalletId};
 use frame_system as system;
 use pallet_erc1155;
 use sp_core::H256;
@@ -77,13 +77,45 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub const Decimals: u32 = 6;
+	pub const MaxCheckpoints: u32 = 4;
+	pub const MigrationItemsPerBlock: u32 = 2;
+	pub const MaxBatchSize: u32 = 10;
+	pub const MaxDataLength: u32 = 32;
+	pub const StringLimit: u32 = 50;
+	pub const AttributeDepositBase: u128 = 10;
+	pub const DepositPerByte: u128 = 1;
+	pub const KeyLimit: u32 = 16;
+	pub const ValueLimit: u32 = 32;
+	pub const MaxEmissionSchedules: u32 = 4;
+	pub const Erc1155PalletId: PalletId = PalletId(*b"py/e1155");
+	pub const MaxNestingDepth: u32 = 3;
 }
 
 impl pallet_erc1155::Config for Test {
 	type Event = Event;
 	type TokenId = u32;
 	type Balance = u128;
+	type CollectionId = u32;
 	type Decimals = Decimals;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type MaxCheckpoints = MaxCheckpoints;
+	type MigrationItemsPerBlock = MigrationItemsPerBlock;
+	type MaxBatchSize = MaxBatchSize;
+	type MaxDataLength = MaxDataLength;
+	type StringLimit = StringLimit;
+	type OnReceived = ();
+	type Currency = Balances;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type MaxEmissionSchedules = MaxEmissionSchedules;
+	type MaxLocks = MaxLocks;
+	type PalletId = Erc1155PalletId;
+	type MaxNestingDepth = MaxNestingDepth;
+	type OnDust = ();
+	type FeeExempt = pallet_dex::PoolAccount<Test>;
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -94,6 +126,12 @@ impl pallet_dex::Config for Test {
 	type Event = Event;
 	type Tokens = PalletErc1155;
 	type Fee = Fee;
+	type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+	type WeightInfo = ();
+}
+
+pub fn bounded<T>(items: Vec<T>) -> BoundedVec<T, MaxBatchSize> {
+	items.try_into().unwrap()
 }
 
 // Build genesis storage according to the mock runtime.
@@ -17,8 +17,8 @@ const JOHN: u64 = 6;
 fn init_tokens(total_supply: u128) {
 	assert_ok!(PalletErc1155::init(
 		Origin::signed(ALICE),
-		vec![TOKEN_1_ID, TOKEN_2_ID],
-		vec![total_supply, total_supply]
+		bounded(vec![TOKEN_1_ID, TOKEN_2_ID]),
+		bounded(vec![total_supply, total_supply])
 	));
 }
 
@@ -32,8 +32,9 @@ fn approve_and_transfer(from: u64, to: u64, amount: u128) {
 		Origin::signed(ALICE),
 		from,
 		to,
-		vec![TOKEN_1_ID, TOKEN_2_ID],
-		vec![amount * MIL, amount * MIL]
+		bounded(vec![TOKEN_1_ID, TOKEN_2_ID]),
+		bounded(vec![amount * MIL, amount * MIL]),
+		Default::default()
 	));
 }
 
@@ -135,6 +136,29 @@ fn buy_token_should_work() {
 	});
 }
 
+#[test]
+fn buy_token_should_skip_transfer_fees() {
+	new_test_ext().execute_with(|| {
+		init_tokens(1000);
+		assert_ok!(PalletErc1155::set_transfer_fee(
+			Origin::signed(ALICE),
+			TOKEN_1_ID,
+			Perbill::from_percent(10),
+			CHARLIE
+		));
+		approve(ALICE, POOL);
+		init_dex(100, 1000);
+		assert_ok!(Dex::buy_token(Origin::signed(ALICE), TOKEN_1_ID, 100 * MIL));
+		assert_eq!(PalletErc1155::balance_of(&ALICE, TOKEN_1_ID), Ok(800_000_000));
+		assert_eq!(PalletErc1155::balance_of(&ALICE, TOKEN_2_ID), Ok(497_487_437));
+		assert_eq!(PalletErc1155::balance_of(&CHARLIE, TOKEN_1_ID), Ok(0));
+		// transfers outside the pool still pay the fee
+		assert_ok!(PalletErc1155::transfer(&ALICE, &BOB, TOKEN_1_ID, 10 * MIL));
+		assert_eq!(PalletErc1155::balance_of(&BOB, TOKEN_1_ID), Ok(9 * MIL));
+		assert_eq!(PalletErc1155::balance_of(&CHARLIE, TOKEN_1_ID), Ok(1 * MIL));
+	});
+}
+
 #[test]
 fn buy_token_should_fail() {
 	new_test_ext().execute_with(|| {
@@ -149,6 +173,98 @@ fn buy_token_should_fail() {
 	});
 }
 
+#[test]
+fn set_fee_should_work() {
+	new_test_ext().execute_with(|| {
+		init_tokens(1000);
+		approve(ALICE, POOL);
+		init_dex(100, 1000);
+		assert_eq!(Dex::fee(), Perbill::from_percent(99));
+		assert_noop!(
+			Dex::set_fee(Origin::signed(ALICE), Perbill::from_percent(2)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Dex::set_fee(Origin::root(), Perbill::from_percent(2)));
+		assert_eq!(Dex::fee(), Perbill::from_percent(98));
+		assert_eq!(Dex::quote(TOKEN_1_ID, 100 * MIL), Ok(494_949_494)); // 0.98 * 100 * 1000 / (100 + 98) = 494.949494
+	});
+}
+
+#[test]
+fn set_fee_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Dex::set_fee(Origin::root(), Perbill::from_percent(11)),
+			Error::<Test>::FeeTooHigh
+		);
+		assert_ok!(Dex::set_fee(Origin::root(), crate::MAX_FEE));
+		assert_eq!(Dex::fee(), Perbill::from_percent(90));
+	});
+}
+
+#[test]
+fn set_paused_should_work() {
+	new_test_ext().execute_with(|| {
+		init_tokens(1000);
+		approve(ALICE, POOL);
+		init_dex(100, 1000);
+		assert_noop!(
+			Dex::set_paused(Origin::signed(ALICE), true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Dex::set_paused(Origin::root(), true));
+		assert_noop!(
+			Dex::buy_token(Origin::signed(ALICE), TOKEN_1_ID, 1 * MIL),
+			Error::<Test>::PoolPaused
+		);
+		assert_noop!(
+			Dex::deposit(Origin::signed(ALICE), TOKEN_1_ID, 1 * MIL),
+			Error::<Test>::PoolPaused
+		);
+		// liquidity providers can still leave
+		assert_ok!(Dex::withdraw(Origin::signed(ALICE), 10));
+		assert_ok!(Dex::set_paused(Origin::root(), false));
+		assert_ok!(Dex::buy_token(Origin::signed(ALICE), TOKEN_1_ID, 1 * MIL));
+	});
+}
+
+#[test]
+fn quote_should_work() {
+	new_test_ext().execute_with(|| {
+		init_tokens(1000);
+		approve(ALICE, POOL);
+		init_dex(100, 1000);
+		assert_eq!(Dex::quote(TOKEN_1_ID, 100 * MIL), Ok(497_487_437));
+		assert_eq!(Dex::do_buy_token(&ALICE, TOKEN_1_ID, 100 * MIL), Ok(497_487_437));
+		assert_eq!(PalletErc1155::balance_of(&ALICE, TOKEN_2_ID), Ok(497_487_437));
+	});
+}
+
+#[test]
+fn quote_input_should_work() {
+	new_test_ext().execute_with(|| {
+		init_tokens(1000);
+		approve(ALICE, POOL);
+		init_dex(100, 1000);
+		assert_eq!(Dex::quote_input(TOKEN_1_ID, 10 * MIL), Ok(1_020_306));
+		assert_eq!(Dex::quote(TOKEN_1_ID, 1_020_306), Ok(10_000_009));
+		assert_eq!(Dex::quote(TOKEN_1_ID, 1_020_305), Ok(9_999_999));
+		assert_noop!(Dex::quote_input(TOKEN_1_ID, 1000 * MIL), Error::<Test>::NoLiquiudity);
+	});
+}
+
+#[test]
+fn quote_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Dex::quote(TOKEN_1_ID, 100 * MIL), Error::<Test>::Uninitilized);
+		init_tokens(1000);
+		approve(ALICE, POOL);
+		init_dex(100, 1000);
+		assert_noop!(Dex::quote(3, 100 * MIL), Error::<Test>::WrongTokenId);
+		assert_noop!(Dex::quote(TOKEN_1_ID, u128::MAX), Error::<Test>::Overflow);
+	});
+}
+
 #[test]
 fn deposit_should_work_1() {
 	new_test_ext().execute_with(|| {
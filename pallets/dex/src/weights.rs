@@ -0,0 +1,45 @@
+//! Weights for pallet_dex.
+//!
+//! These are hand-written estimates that follow the storage accesses of each call, not the output
+//! of a benchmark run. Replace them with measured weights by running:
+//! ./target/release/node-template benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_dex --extrinsic '*' --steps 50 --repeat 20
+//! --output ./pallets/dex/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_dex.
+pub trait WeightInfo {
+	fn set_fee() -> Weight;
+	fn set_paused() -> Weight;
+}
+
+/// Weights for pallet_dex using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Dex FeeOverride (r:0 w:1)
+	fn set_fee() -> Weight {
+		(11_730_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Dex Paused (r:0 w:1)
+	fn set_paused() -> Weight {
+		(11_250_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_fee() -> Weight {
+		(11_730_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_paused() -> Weight {
+		(11_250_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
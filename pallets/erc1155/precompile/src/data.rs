@@ -0,0 +1,189 @@
+//! Minimal Solidity ABI encoding for the types used by the IERC1155 interface.
+
+use sp_core::{H160, H256, U256};
+use sp_std::vec::Vec;
+
+/// Reads 32 byte words from call data, `input` starts right after the selector.
+pub struct EvmDataReader<'a> {
+	input: &'a [u8],
+	cursor: usize,
+}
+
+impl<'a> EvmDataReader<'a> {
+	/// Split `input` into the function selector and a reader over the arguments.
+	pub fn new_with_selector(input: &'a [u8]) -> Result<([u8; 4], Self), &'static str> {
+		if input.len() < 4 {
+			return Err("input is too short")
+		}
+		let mut selector = [0u8; 4];
+		selector.copy_from_slice(&input[..4]);
+		Ok((selector, Self { input: &input[4..], cursor: 0 }))
+	}
+
+	fn read_word(&mut self) -> Result<&'a [u8], &'static str> {
+		let end = self.cursor.checked_add(32).ok_or("offset overflow")?;
+		let word = self.input.get(self.cursor..end).ok_or("input is too short")?;
+		self.cursor = end;
+		Ok(word)
+	}
+
+	pub fn read_u256(&mut self) -> Result<U256, &'static str> {
+		self.read_word().map(U256::from_big_endian)
+	}
+
+	pub fn read_address(&mut self) -> Result<H160, &'static str> {
+		let word = self.read_word()?;
+		if word[..12].iter().any(|byte| *byte != 0) {
+			return Err("invalid address")
+		}
+		Ok(H160::from_slice(&word[12..]))
+	}
+
+	pub fn read_bool(&mut self) -> Result<bool, &'static str> {
+		match self.read_u256()? {
+			value if value.is_zero() => Ok(false),
+			value if value == U256::one() => Ok(true),
+			_ => Err("invalid bool"),
+		}
+	}
+
+	pub fn read_u256_array(&mut self) -> Result<Vec<U256>, &'static str> {
+		let mut array = self.read_dynamic()?;
+		let len = array.read_length()?;
+		(0..len).map(|_| array.read_u256()).collect()
+	}
+
+	pub fn read_address_array(&mut self) -> Result<Vec<H160>, &'static str> {
+		let mut array = self.read_dynamic()?;
+		let len = array.read_length()?;
+		(0..len).map(|_| array.read_address()).collect()
+	}
+
+	pub fn read_bytes(&mut self) -> Result<Vec<u8>, &'static str> {
+		let mut bytes = self.read_dynamic()?;
+		let len = bytes.read_length()?;
+		let start = bytes.cursor;
+		let end = start.checked_add(len).ok_or("offset overflow")?;
+		bytes
+			.input
+			.get(start..end)
+			.map(|data| data.to_vec())
+			.ok_or("input is too short")
+	}
+
+	/// Follow the offset in the head of a dynamic argument.
+	fn read_dynamic(&mut self) -> Result<Self, &'static str> {
+		let offset = Self::to_usize(self.read_u256()?)?;
+		let input = self.input.get(offset..).ok_or("input is too short")?;
+		Ok(Self { input, cursor: 0 })
+	}
+
+	fn read_length(&mut self) -> Result<usize, &'static str> {
+		let len = Self::to_usize(self.read_u256()?)?;
+		// every element takes at least one byte, which bounds allocations by the input size
+		if len > self.input.len() {
+			return Err("input is too short")
+		}
+		Ok(len)
+	}
+
+	fn to_usize(value: U256) -> Result<usize, &'static str> {
+		if value > U256::from(u32::MAX) {
+			return Err("offset overflow")
+		}
+		Ok(value.low_u32() as usize)
+	}
+}
+
+enum Argument {
+	Static([u8; 32]),
+	Dynamic(Vec<u8>),
+}
+
+/// Encodes function arguments or return values.
+#[derive(Default)]
+pub struct EvmDataWriter {
+	selector: Option<[u8; 4]>,
+	arguments: Vec<Argument>,
+}
+
+impl EvmDataWriter {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn new_with_selector(selector: [u8; 4]) -> Self {
+		Self { selector: Some(selector), arguments: Vec::new() }
+	}
+
+	pub fn write_u256(mut self, value: U256) -> Self {
+		self.arguments.push(Argument::Static(u256_word(value)));
+		self
+	}
+
+	pub fn write_address(mut self, value: H160) -> Self {
+		self.arguments.push(Argument::Static(address_word(value)));
+		self
+	}
+
+	pub fn write_bool(self, value: bool) -> Self {
+		self.write_u256(if value { U256::one() } else { U256::zero() })
+	}
+
+	pub fn write_u256_array(self, values: &[U256]) -> Self {
+		let words = values.iter().map(|value| u256_word(*value)).collect();
+		self.write_array(words)
+	}
+
+	pub fn write_address_array(self, values: &[H160]) -> Self {
+		let words = values.iter().map(|value| address_word(*value)).collect();
+		self.write_array(words)
+	}
+
+	pub fn write_bytes(mut self, value: &[u8]) -> Self {
+		let mut encoded = u256_word(value.len().into()).to_vec();
+		encoded.extend_from_slice(value);
+		encoded.resize(32 + (value.len() + 31) / 32 * 32, 0);
+		self.arguments.push(Argument::Dynamic(encoded));
+		self
+	}
+
+	fn write_array(mut self, words: Vec<[u8; 32]>) -> Self {
+		let mut encoded = u256_word(words.len().into()).to_vec();
+		words.iter().for_each(|word| encoded.extend_from_slice(word));
+		self.arguments.push(Argument::Dynamic(encoded));
+		self
+	}
+
+	pub fn build(self) -> Vec<u8> {
+		let mut head = self.selector.map(|selector| selector.to_vec()).unwrap_or_default();
+		let mut tail = Vec::new();
+		let head_size = self.arguments.len() * 32;
+		for argument in self.arguments {
+			match argument {
+				Argument::Static(word) => head.extend_from_slice(&word),
+				Argument::Dynamic(encoded) => {
+					head.extend_from_slice(&u256_word((head_size + tail.len()).into()));
+					tail.extend(encoded);
+				},
+			}
+		}
+		head.extend(tail);
+		head
+	}
+}
+
+fn u256_word(value: U256) -> [u8; 32] {
+	let mut word = [0u8; 32];
+	value.to_big_endian(&mut word);
+	word
+}
+
+fn address_word(value: H160) -> [u8; 32] {
+	H256::from(value).to_fixed_bytes()
+}
+
+/// Log topic of an indexed address.
+pub fn address_topic(value: H160) -> H256 {
+	H256::from(value)
+}
@@ -0,0 +1,358 @@
+//! EVM precompile exposing `pallet_erc1155` tokens through the IERC1155 interface.
+//!
+//! EVM addresses are mapped to substrate accounts with the runtime's `AddressMapping`, so a
+//! contract sees the same balances and operator approvals as the pallet. Token ids and amounts
+//! must fit into `u128`. The `onERC1155Received` hook of contract recipients is not called,
+//! receivers are notified through the pallet's `OnReceived` hook instead.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::{
+	Context, ExitError, ExitRevert, ExitSucceed, Log, Precompile, PrecompileFailure,
+	PrecompileOutput, PrecompileResult,
+};
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::Get,
+	weights::Weight,
+};
+use pallet_erc1155::WeightInfo;
+use pallet_evm::{AddressMapping, GasWeightMapping};
+use sp_core::{H160, H256, U256};
+use sp_runtime::{traits::UniqueSaturatedInto, DispatchError};
+use sp_std::{convert::TryFrom, marker::PhantomData, vec, vec::Vec};
+use traits::Erc1155;
+
+mod data;
+pub use data::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub const SELECTOR_BALANCE_OF: [u8; 4] = hex_literal::hex!("00fdd58e");
+pub const SELECTOR_BALANCE_OF_BATCH: [u8; 4] = hex_literal::hex!("4e1273f4");
+pub const SELECTOR_SAFE_TRANSFER_FROM: [u8; 4] = hex_literal::hex!("f242432a");
+pub const SELECTOR_SAFE_BATCH_TRANSFER_FROM: [u8; 4] = hex_literal::hex!("2eb2c2d6");
+pub const SELECTOR_SET_APPROVAL_FOR_ALL: [u8; 4] = hex_literal::hex!("a22cb465");
+pub const SELECTOR_IS_APPROVED_FOR_ALL: [u8; 4] = hex_literal::hex!("e985e9c5");
+
+/// `TransferSingle(address,address,address,uint256,uint256)`
+pub const SELECTOR_LOG_TRANSFER_SINGLE: [u8; 32] =
+	hex_literal::hex!("c3d58168c5ae7397731d063d5bbf3d657854427343f4c083240f7aacaa2d0f62");
+/// `TransferBatch(address,address,address,uint256[],uint256[])`
+pub const SELECTOR_LOG_TRANSFER_BATCH: [u8; 32] =
+	hex_literal::hex!("4a39dc06d4c0dbc64b70af90fd698a233a518aa5d07e595d983b8c0526c8f7fb");
+/// `ApprovalForAll(address,address,bool)`
+pub const SELECTOR_LOG_APPROVAL_FOR_ALL: [u8; 32] =
+	hex_literal::hex!("17307eab39ab6107e8899845ad3d59bd9653f200f220920489ca2b5937696c31");
+
+/// `Error(string)`, used to encode revert reasons.
+const SELECTOR_ERROR: [u8; 4] = hex_literal::hex!("08c379a0");
+
+type Erc1155Of<Runtime> = pallet_erc1155::Pallet<Runtime>;
+
+pub struct Erc1155Precompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for Erc1155Precompile<Runtime>
+where
+	Runtime: pallet_erc1155::Config + pallet_evm::Config,
+	Runtime::TokenId: TryFrom<u128>,
+{
+	fn execute(
+		input: &[u8],
+		target_gas: Option<u64>,
+		context: &Context,
+		is_static: bool,
+	) -> PrecompileResult {
+		let (selector, input) = EvmDataReader::new_with_selector(input).map_err(error)?;
+		match selector {
+			SELECTOR_BALANCE_OF => Self::balance_of(input, target_gas),
+			SELECTOR_BALANCE_OF_BATCH => Self::balance_of_batch(input, target_gas),
+			SELECTOR_IS_APPROVED_FOR_ALL => Self::is_approved_for_all(input, target_gas),
+			SELECTOR_SAFE_TRANSFER_FROM |
+			SELECTOR_SAFE_BATCH_TRANSFER_FROM |
+			SELECTOR_SET_APPROVAL_FOR_ALL
+				if is_static =>
+				Err(error("cannot modify state in static call")),
+			SELECTOR_SAFE_TRANSFER_FROM => Self::safe_transfer_from(input, target_gas, context),
+			SELECTOR_SAFE_BATCH_TRANSFER_FROM =>
+				Self::safe_batch_transfer_from(input, target_gas, context),
+			SELECTOR_SET_APPROVAL_FOR_ALL => Self::set_approval_for_all(input, target_gas, context),
+			_ => Err(error("unknown selector")),
+		}
+	}
+}
+
+impl<Runtime> Erc1155Precompile<Runtime>
+where
+	Runtime: pallet_erc1155::Config + pallet_evm::Config,
+	Runtime::TokenId: TryFrom<u128>,
+{
+	fn balance_of(mut input: EvmDataReader, target_gas: Option<u64>) -> PrecompileResult {
+		let cost = Self::cost(<Runtime as frame_system::Config>::DbWeight::get().reads(1));
+		Self::ensure_gas(cost, target_gas)?;
+
+		let owner = input.read_address().map_err(error)?;
+		let token_id = Self::token_id(input.read_u256().map_err(error)?)?;
+
+		let balance = Erc1155Of::<Runtime>::get_balance(Self::account_id(owner), token_id);
+		Ok(Self::output(cost, EvmDataWriter::new().write_u256(Self::u256(balance)).build(), vec![]))
+	}
+
+	fn balance_of_batch(mut input: EvmDataReader, target_gas: Option<u64>) -> PrecompileResult {
+		let owners = input.read_address_array().map_err(error)?;
+		let token_ids = input.read_u256_array().map_err(error)?;
+		if owners.len() != token_ids.len() {
+			return Err(error("accounts and ids length mismatch"))
+		}
+
+		let reads = owners.len() as Weight;
+		let cost = Self::cost(<Runtime as frame_system::Config>::DbWeight::get().reads(reads));
+		Self::ensure_gas(cost, target_gas)?;
+
+		let balances = owners
+			.into_iter()
+			.zip(token_ids)
+			.map(|(owner, token_id)| {
+				let balance = Erc1155Of::<Runtime>::get_balance(
+					Self::account_id(owner),
+					Self::token_id(token_id)?,
+				);
+				Ok(Self::u256(balance))
+			})
+			.collect::<Result<Vec<_>, PrecompileFailure>>()?;
+		Ok(Self::output(cost, EvmDataWriter::new().write_u256_array(&balances).build(), vec![]))
+	}
+
+	fn is_approved_for_all(mut input: EvmDataReader, target_gas: Option<u64>) -> PrecompileResult {
+		let cost = Self::cost(<Runtime as frame_system::Config>::DbWeight::get().reads(2));
+		Self::ensure_gas(cost, target_gas)?;
+
+		let owner = Self::account_id(input.read_address().map_err(error)?);
+		let operator = Self::account_id(input.read_address().map_err(error)?);
+
+		let approved = Erc1155Of::<Runtime>::get_approval(&owner, &operator);
+		Ok(Self::output(cost, EvmDataWriter::new().write_bool(approved).build(), vec![]))
+	}
+
+	fn safe_transfer_from(
+		mut input: EvmDataReader,
+		target_gas: Option<u64>,
+		context: &Context,
+	) -> PrecompileResult {
+		let cost = Self::cost(Erc1155Of::<Runtime>::transfer_single_weight());
+		Self::ensure_gas(cost, target_gas)?;
+
+		let from = input.read_address().map_err(error)?;
+		let to = input.read_address().map_err(error)?;
+		let id = input.read_u256().map_err(error)?;
+		let value = input.read_u256().map_err(error)?;
+		let data = input.read_bytes().map_err(error)?;
+		Self::ensure_data_length(&data, cost)?;
+
+		let operator = Self::account_id(context.caller);
+		let from_account = Self::account_id(from);
+		Self::ensure_owner_or_approved(&operator, &from_account, cost)?;
+
+		let token_id = Self::token_id(id)?;
+		let amount = Self::balance(value)?;
+		let received = with_transaction(|| {
+			match Erc1155Of::<Runtime>::do_transfer(
+				&operator,
+				&from_account,
+				&Self::account_id(to),
+				&token_id,
+				&amount,
+				&data,
+			) {
+				Ok(received) => TransactionOutcome::Commit(Ok(received)),
+				Err(e) => TransactionOutcome::Rollback(Err(e)),
+			}
+		})
+		.map_err(|e| revert(e, cost))?;
+
+		let log = Log {
+			address: context.address,
+			topics: vec![
+				H256::from(SELECTOR_LOG_TRANSFER_SINGLE),
+				address_topic(context.caller),
+				address_topic(from),
+				address_topic(to),
+			],
+			// the amount received after the transfer fee, like the pallet's `TransferSingle`
+			data: EvmDataWriter::new().write_u256(id).write_u256(Self::u256(received)).build(),
+		};
+		Ok(Self::output(cost, Vec::new(), vec![log]))
+	}
+
+	fn safe_batch_transfer_from(
+		mut input: EvmDataReader,
+		target_gas: Option<u64>,
+		context: &Context,
+	) -> PrecompileResult {
+		let from = input.read_address().map_err(error)?;
+		let to = input.read_address().map_err(error)?;
+		let ids = input.read_u256_array().map_err(error)?;
+		let values = input.read_u256_array().map_err(error)?;
+		let data = input.read_bytes().map_err(error)?;
+
+		let max_batch_size = <Runtime as pallet_erc1155::Config>::MaxBatchSize::get() as usize;
+		if ids.len() > max_batch_size {
+			return Err(error("too many ids"))
+		}
+		let cost = Self::cost(Erc1155Of::<Runtime>::transfer_batch_weight(ids.len() as u32));
+		Self::ensure_gas(cost, target_gas)?;
+		Self::ensure_data_length(&data, cost)?;
+
+		let operator = Self::account_id(context.caller);
+		let from_account = Self::account_id(from);
+		Self::ensure_owner_or_approved(&operator, &from_account, cost)?;
+
+		let token_ids = ids.iter().map(|id| Self::token_id(*id)).collect::<Result<Vec<_>, _>>()?;
+		let amounts = values
+			.iter()
+			.map(|value| Self::balance(*value))
+			.collect::<Result<Vec<_>, _>>()?;
+		let received = Erc1155Of::<Runtime>::do_transfer_batch(
+			&operator,
+			&from_account,
+			&Self::account_id(to),
+			&token_ids,
+			&amounts,
+			&data,
+		)
+		.map_err(|e| revert(e, cost))?;
+
+		let log = Log {
+			address: context.address,
+			topics: vec![
+				H256::from(SELECTOR_LOG_TRANSFER_BATCH),
+				address_topic(context.caller),
+				address_topic(from),
+				address_topic(to),
+			],
+			data: EvmDataWriter::new()
+				.write_u256_array(&ids)
+				.write_u256_array(&received.into_iter().map(Self::u256).collect::<Vec<_>>())
+				.build(),
+		};
+		Ok(Self::output(cost, Vec::new(), vec![log]))
+	}
+
+	fn set_approval_for_all(
+		mut input: EvmDataReader,
+		target_gas: Option<u64>,
+		context: &Context,
+	) -> PrecompileResult {
+		let cost =
+			Self::cost(<Runtime as pallet_erc1155::Config>::WeightInfo::set_approval_for_all());
+		Self::ensure_gas(cost, target_gas)?;
+
+		let operator = input.read_address().map_err(error)?;
+		let approved = input.read_bool().map_err(error)?;
+		if operator == context.caller {
+			return Err(revert_with("setting approval status for self", cost))
+		}
+
+		<Erc1155Of<Runtime> as Erc1155<Runtime::AccountId>>::set_approval_for_all(
+			&Self::account_id(context.caller),
+			&Self::account_id(operator),
+			approved,
+		);
+
+		let log = Log {
+			address: context.address,
+			topics: vec![
+				H256::from(SELECTOR_LOG_APPROVAL_FOR_ALL),
+				address_topic(context.caller),
+				address_topic(operator),
+			],
+			data: EvmDataWriter::new().write_bool(approved).build(),
+		};
+		Ok(Self::output(cost, Vec::new(), vec![log]))
+	}
+
+	fn ensure_owner_or_approved(
+		operator: &Runtime::AccountId,
+		from: &Runtime::AccountId,
+		cost: u64,
+	) -> Result<(), PrecompileFailure> {
+		if operator != from && !Erc1155Of::<Runtime>::get_approval(from, operator) {
+			return Err(revert_with("caller is not owner nor approved", cost))
+		}
+		Ok(())
+	}
+
+	fn ensure_data_length(data: &[u8], cost: u64) -> Result<(), PrecompileFailure> {
+		if data.len() > <Runtime as pallet_erc1155::Config>::MaxDataLength::get() as usize {
+			return Err(revert_with("data is too long", cost))
+		}
+		Ok(())
+	}
+
+	fn ensure_gas(cost: u64, target_gas: Option<u64>) -> Result<(), PrecompileFailure> {
+		match target_gas {
+			Some(gas_limit) if cost > gas_limit =>
+				Err(PrecompileFailure::Error { exit_status: ExitError::OutOfGas }),
+			_ => Ok(()),
+		}
+	}
+
+	fn cost(weight: Weight) -> u64 {
+		<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight)
+	}
+
+	fn output(cost: u64, output: Vec<u8>, logs: Vec<Log>) -> PrecompileOutput {
+		PrecompileOutput { exit_status: ExitSucceed::Returned, cost, output, logs }
+	}
+
+	fn account_id(address: H160) -> Runtime::AccountId {
+		<Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address)
+	}
+
+	fn token_id(value: U256) -> Result<Runtime::TokenId, PrecompileFailure> {
+		to_u128(value)
+			.and_then(|value| Runtime::TokenId::try_from(value).ok())
+			.ok_or_else(|| error("token id out of bounds"))
+	}
+
+	fn balance(value: U256) -> Result<Runtime::Balance, PrecompileFailure> {
+		to_u128(value)
+			.and_then(|value| Runtime::Balance::try_from(value).ok())
+			.ok_or_else(|| error("amount out of bounds"))
+	}
+
+	fn u256(balance: Runtime::Balance) -> U256 {
+		let balance: u128 = balance.unique_saturated_into();
+		balance.into()
+	}
+}
+
+fn to_u128(value: U256) -> Option<u128> {
+	if value > U256::from(u128::MAX) {
+		return None
+	}
+	Some(value.low_u128())
+}
+
+fn error(message: &'static str) -> PrecompileFailure {
+	PrecompileFailure::Error { exit_status: ExitError::Other(message.into()) }
+}
+
+fn revert(error: DispatchError, cost: u64) -> PrecompileFailure {
+	revert_with(error.into(), cost)
+}
+
+/// Revert with a Solidity `Error(string)` reason.
+fn revert_with(reason: &'static str, cost: u64) -> PrecompileFailure {
+	PrecompileFailure::Revert {
+		exit_status: ExitRevert::Reverted,
+		output: EvmDataWriter::new_with_selector(SELECTOR_ERROR)
+			.write_bytes(reason.as_bytes())
+			.build(),
+		cost,
+	}
+}
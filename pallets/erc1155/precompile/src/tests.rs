@@ -0,0 +1,334 @@
+use crate::{
+	mock::*, EvmDataReader, EvmDataWriter, SELECTOR_BALANCE_OF, SELECTOR_BALANCE_OF_BATCH,
+	SELECTOR_IS_APPROVED_FOR_ALL, SELECTOR_LOG_APPROVAL_FOR_ALL, SELECTOR_LOG_TRANSFER_BATCH,
+	SELECTOR_LOG_TRANSFER_SINGLE, SELECTOR_SAFE_BATCH_TRANSFER_FROM, SELECTOR_SAFE_TRANSFER_FROM,
+	SELECTOR_SET_APPROVAL_FOR_ALL,
+};
+use fp_evm::{ExitError, ExitRevert, PrecompileFailure, PrecompileOutput};
+use frame_support::assert_ok;
+use sp_core::{H256, U256};
+use sp_runtime::Perbill;
+use traits::Erc1155 as _;
+
+const TOKEN_0_ID: u32 = 1;
+const TOKEN_1_ID: u32 = 2;
+
+const ALICE: u64 = 1;
+const BOB: u64 = 2;
+const CHARLIE: u64 = 3;
+
+fn init_tokens() {
+	Erc1155::init(&(ALICE as u128), &vec![TOKEN_0_ID, TOKEN_1_ID], &vec![100, 50]).unwrap();
+}
+
+fn output(result: fp_evm::PrecompileResult) -> PrecompileOutput {
+	match result {
+		Ok(output) => output,
+		Err(_) => panic!("precompile call failed"),
+	}
+}
+
+fn revert_reason(result: fp_evm::PrecompileResult) -> Vec<u8> {
+	match result {
+		Err(PrecompileFailure::Revert { exit_status: ExitRevert::Reverted, output, .. }) => {
+			let (_, mut reader) = EvmDataReader::new_with_selector(&output).unwrap();
+			reader.read_bytes().unwrap()
+		},
+		_ => panic!("precompile call did not revert"),
+	}
+}
+
+fn balance_of(owner: u64, token_id: u32) -> Vec<u8> {
+	EvmDataWriter::new_with_selector(SELECTOR_BALANCE_OF)
+		.write_address(address(owner))
+		.write_u256(token_id.into())
+		.build()
+}
+
+fn safe_transfer_from(from: u64, to: u64, token_id: u32, amount: u128) -> Vec<u8> {
+	EvmDataWriter::new_with_selector(SELECTOR_SAFE_TRANSFER_FROM)
+		.write_address(address(from))
+		.write_address(address(to))
+		.write_u256(token_id.into())
+		.write_u256(amount.into())
+		.write_bytes(&[])
+		.build()
+}
+
+fn set_approval_for_all(operator: u64, approved: bool) -> Vec<u8> {
+	EvmDataWriter::new_with_selector(SELECTOR_SET_APPROVAL_FOR_ALL)
+		.write_address(address(operator))
+		.write_bool(approved)
+		.build()
+}
+
+#[test]
+fn balance_of_should_work() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+
+		let result = output(call(BOB, balance_of(ALICE, TOKEN_0_ID), true));
+		assert_eq!(result.output, EvmDataWriter::new().write_u256(100.into()).build());
+		assert!(result.logs.is_empty());
+
+		// unknown tokens have a zero balance
+		let result = output(call(BOB, balance_of(ALICE, 9), true));
+		assert_eq!(result.output, EvmDataWriter::new().write_u256(U256::zero()).build());
+	})
+}
+
+#[test]
+fn balance_of_batch_should_work() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+
+		let input = EvmDataWriter::new_with_selector(SELECTOR_BALANCE_OF_BATCH)
+			.write_address_array(&[address(ALICE), address(ALICE), address(BOB)])
+			.write_u256_array(&[TOKEN_0_ID.into(), TOKEN_1_ID.into(), TOKEN_0_ID.into()])
+			.build();
+		let result = output(call(BOB, input, true));
+		assert_eq!(
+			result.output,
+			EvmDataWriter::new()
+				.write_u256_array(&[100.into(), 50.into(), U256::zero()])
+				.build()
+		);
+	})
+}
+
+#[test]
+fn balance_of_batch_should_fail() {
+	new_test_ext().execute_with(|| {
+		let input = EvmDataWriter::new_with_selector(SELECTOR_BALANCE_OF_BATCH)
+			.write_address_array(&[address(ALICE), address(BOB)])
+			.write_u256_array(&[TOKEN_0_ID.into()])
+			.build();
+		assert!(matches!(call(BOB, input, true), Err(PrecompileFailure::Error { .. })));
+	})
+}
+
+#[test]
+fn safe_transfer_from_should_work() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+
+		let result = output(call(ALICE, safe_transfer_from(ALICE, BOB, TOKEN_0_ID, 30), false));
+		assert_eq!(Erc1155::get_balance(ALICE as u128, TOKEN_0_ID), 70);
+		assert_eq!(Erc1155::get_balance(BOB as u128, TOKEN_0_ID), 30);
+
+		assert_eq!(result.logs.len(), 1);
+		let log = &result.logs[0];
+		assert_eq!(log.address, address(PRECOMPILE));
+		assert_eq!(
+			log.topics,
+			vec![
+				H256::from(SELECTOR_LOG_TRANSFER_SINGLE),
+				H256::from(address(ALICE)),
+				H256::from(address(ALICE)),
+				H256::from(address(BOB)),
+			]
+		);
+		assert_eq!(
+			log.data,
+			EvmDataWriter::new().write_u256(1.into()).write_u256(30.into()).build()
+		);
+	})
+}
+
+#[test]
+fn safe_transfer_from_should_log_received_amount() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+		assert_ok!(Erc1155::set_transfer_fee(
+			Origin::signed(ALICE as u128),
+			TOKEN_0_ID,
+			Perbill::from_percent(10),
+			CHARLIE as u128
+		));
+
+		let result = output(call(ALICE, safe_transfer_from(ALICE, BOB, TOKEN_0_ID, 30), false));
+		assert_eq!(Erc1155::get_balance(BOB as u128, TOKEN_0_ID), 27);
+		assert_eq!(
+			result.logs[0].data,
+			EvmDataWriter::new().write_u256(1.into()).write_u256(27.into()).build()
+		);
+
+		let input = EvmDataWriter::new_with_selector(SELECTOR_SAFE_BATCH_TRANSFER_FROM)
+			.write_address(address(ALICE))
+			.write_address(address(BOB))
+			.write_u256_array(&[TOKEN_0_ID.into(), TOKEN_1_ID.into()])
+			.write_u256_array(&[10.into(), 20.into()])
+			.write_bytes(&[])
+			.build();
+		let result = output(call(ALICE, input, false));
+		assert_eq!(
+			result.logs[0].data,
+			EvmDataWriter::new()
+				.write_u256_array(&[TOKEN_0_ID.into(), TOKEN_1_ID.into()])
+				.write_u256_array(&[9.into(), 20.into()])
+				.build()
+		);
+	})
+}
+
+#[test]
+fn safe_transfer_from_should_work_for_operator() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+		output(call(ALICE, set_approval_for_all(CHARLIE, true), false));
+
+		let result = output(call(CHARLIE, safe_transfer_from(ALICE, BOB, TOKEN_1_ID, 50), false));
+		assert_eq!(Erc1155::get_balance(ALICE as u128, TOKEN_1_ID), 0);
+		assert_eq!(Erc1155::get_balance(BOB as u128, TOKEN_1_ID), 50);
+		assert_eq!(result.logs[0].topics[1], H256::from(address(CHARLIE)));
+	})
+}
+
+#[test]
+fn safe_transfer_from_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+
+		let result = call(CHARLIE, safe_transfer_from(ALICE, BOB, TOKEN_0_ID, 30), false);
+		assert_eq!(revert_reason(result), b"caller is not owner nor approved".to_vec());
+		assert_eq!(Erc1155::get_balance(ALICE as u128, TOKEN_0_ID), 100);
+	})
+}
+
+#[test]
+fn safe_transfer_from_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+
+		let result = call(ALICE, safe_transfer_from(ALICE, BOB, TOKEN_0_ID, 101), false);
+		assert_eq!(revert_reason(result), b"InsufficientFunds".to_vec());
+		assert_eq!(Erc1155::get_balance(ALICE as u128, TOKEN_0_ID), 100);
+	})
+}
+
+#[test]
+fn safe_transfer_from_should_fail_3() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+
+		// state changing calls are rejected in a static context
+		let result = call(ALICE, safe_transfer_from(ALICE, BOB, TOKEN_0_ID, 30), true);
+		assert!(matches!(result, Err(PrecompileFailure::Error { .. })));
+		assert_eq!(Erc1155::get_balance(ALICE as u128, TOKEN_0_ID), 100);
+	})
+}
+
+#[test]
+fn safe_transfer_from_should_fail_4() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+
+		let context = fp_evm::Context {
+			address: address(PRECOMPILE),
+			caller: address(ALICE),
+			apparent_value: Default::default(),
+		};
+		let result = <crate::Erc1155Precompile<Test> as fp_evm::Precompile>::execute(
+			&safe_transfer_from(ALICE, BOB, TOKEN_0_ID, 30),
+			Some(1),
+			&context,
+			false,
+		);
+		assert!(matches!(
+			result,
+			Err(PrecompileFailure::Error { exit_status: ExitError::OutOfGas })
+		));
+	})
+}
+
+#[test]
+fn safe_batch_transfer_from_should_work() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+
+		let ids = [TOKEN_0_ID.into(), TOKEN_1_ID.into()];
+		let values = [10.into(), 20.into()];
+		let input = EvmDataWriter::new_with_selector(SELECTOR_SAFE_BATCH_TRANSFER_FROM)
+			.write_address(address(ALICE))
+			.write_address(address(BOB))
+			.write_u256_array(&ids)
+			.write_u256_array(&values)
+			.write_bytes(b"data")
+			.build();
+		let result = output(call(ALICE, input, false));
+		assert_eq!(Erc1155::get_balance(BOB as u128, TOKEN_0_ID), 10);
+		assert_eq!(Erc1155::get_balance(BOB as u128, TOKEN_1_ID), 20);
+
+		let log = &result.logs[0];
+		assert_eq!(log.topics[0], H256::from(SELECTOR_LOG_TRANSFER_BATCH));
+		assert_eq!(
+			log.data,
+			EvmDataWriter::new().write_u256_array(&ids).write_u256_array(&values).build()
+		);
+	})
+}
+
+#[test]
+fn safe_batch_transfer_from_should_fail() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+
+		// the second transfer fails, the first one is rolled back
+		let input = EvmDataWriter::new_with_selector(SELECTOR_SAFE_BATCH_TRANSFER_FROM)
+			.write_address(address(ALICE))
+			.write_address(address(BOB))
+			.write_u256_array(&[TOKEN_0_ID.into(), TOKEN_1_ID.into()])
+			.write_u256_array(&[10.into(), 51.into()])
+			.write_bytes(&[])
+			.build();
+		assert_eq!(revert_reason(call(ALICE, input, false)), b"InsufficientFunds".to_vec());
+		assert_eq!(Erc1155::get_balance(ALICE as u128, TOKEN_0_ID), 100);
+		assert_eq!(Erc1155::get_balance(BOB as u128, TOKEN_0_ID), 0);
+	})
+}
+
+#[test]
+fn set_approval_for_all_should_work() {
+	new_test_ext().execute_with(|| {
+		let is_approved = EvmDataWriter::new_with_selector(SELECTOR_IS_APPROVED_FOR_ALL)
+			.write_address(address(ALICE))
+			.write_address(address(CHARLIE))
+			.build();
+
+		let result = output(call(ALICE, set_approval_for_all(CHARLIE, true), false));
+		assert_eq!(
+			result.logs[0].topics,
+			vec![
+				H256::from(SELECTOR_LOG_APPROVAL_FOR_ALL),
+				H256::from(address(ALICE)),
+				H256::from(address(CHARLIE)),
+			]
+		);
+		assert_eq!(result.logs[0].data, EvmDataWriter::new().write_bool(true).build());
+		let result = output(call(BOB, is_approved.clone(), true));
+		assert_eq!(result.output, EvmDataWriter::new().write_bool(true).build());
+
+		output(call(ALICE, set_approval_for_all(CHARLIE, false), false));
+		let result = output(call(BOB, is_approved, true));
+		assert_eq!(result.output, EvmDataWriter::new().write_bool(false).build());
+	})
+}
+
+#[test]
+fn set_approval_for_all_should_fail() {
+	new_test_ext().execute_with(|| {
+		let result = call(ALICE, set_approval_for_all(ALICE, true), false);
+		assert_eq!(revert_reason(result), b"setting approval status for self".to_vec());
+	})
+}
+
+#[test]
+fn unknown_selector_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert!(matches!(
+			call(ALICE, vec![0x12, 0x34, 0x56, 0x78], false),
+			Err(PrecompileFailure::Error { .. })
+		));
+		assert!(matches!(call(ALICE, vec![0x12], false), Err(PrecompileFailure::Error { .. })));
+	})
+}
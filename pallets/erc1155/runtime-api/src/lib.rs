@@ -0,0 +1,23 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+use traits::AttributeNamespace;
+
+sp_api::decl_runtime_apis! {
+	pub trait Erc1155Api<AccountId, TokenId, Balance, BlockNumber> where
+		AccountId: Codec,
+		TokenId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Balance of `account` at the end of `block`, if checkpoints cover it.
+		fn balance_of_at(account: AccountId, token_id: TokenId, block: BlockNumber) -> Option<Balance>;
+
+		/// Total supply of `token_id` at the end of `block`, if checkpoints cover it.
+		fn total_supply_at(token_id: TokenId, block: BlockNumber) -> Option<Balance>;
+
+		/// All attributes of `token_id` as `(namespace, key, value)`.
+		fn attributes(token_id: TokenId) -> Vec<(AttributeNamespace<AccountId>, Vec<u8>, Vec<u8>)>;
+	}
+}
@@ -0,0 +1,402 @@
+//! Benchmarking setup for pallet-erc1155
+
+use super::*;
+
+use crate::Pallet as Erc1155;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	traits::{Currency, EnsureOrigin, Get, UnfilteredDispatchable},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::{
+	traits::{Bounded, Zero},
+	Perbill,
+};
+use sp_std::{convert::TryInto, vec, vec::Vec};
+use traits::{AttributeNamespace, Erc1155 as Erc1155Trait, Erc1155Attributes, Erc1155Mintable};
+
+const SEED: u32 = 0;
+
+fn bounded<T: Config, I>(items: Vec<I>) -> BoundedVec<I, T::MaxBatchSize> {
+	items.try_into().ok().expect("benchmark batches respect MaxBatchSize; qed")
+}
+
+fn max_data<T: Config>() -> BoundedVec<u8, T::MaxDataLength> {
+	vec![0u8; T::MaxDataLength::get() as usize]
+		.try_into()
+		.ok()
+		.expect("data is exactly MaxDataLength long; qed")
+}
+
+/// Initialize `b` checkpointed tokens owned by the caller and approve the recipient, so transfers
+/// take the most expensive path. The checkpoint pages are full, so transfers archive them.
+fn setup<T: Config>(b: u32) -> (T::AccountId, T::AccountId, Vec<T::TokenId>)
+where
+	T::TokenId: From<u32>,
+{
+	let caller: T::AccountId = whitelisted_caller();
+	let recipient: T::AccountId = account("recipient", 0, SEED);
+	let token_ids: Vec<T::TokenId> = (0..b).map(Into::into).collect();
+	let supplies: Vec<T::Balance> = token_ids.iter().map(|_| 1_000u32.into()).collect();
+	<Erc1155<T> as Erc1155Trait<_>>::init(&caller, &token_ids, &supplies).unwrap();
+	let full: Vec<_> = (0..T::MaxCheckpoints::get())
+		.map(|block| (T::BlockNumber::from(block), T::Balance::zero()))
+		.collect();
+	let full: BoundedVec<_, T::MaxCheckpoints> =
+		full.try_into().ok().expect("exactly MaxCheckpoints checkpoints; qed");
+	for token_id in token_ids.iter() {
+		CheckpointedTokens::<T>::insert(token_id, T::BlockNumber::zero());
+		BalanceCheckpoints::<T>::insert(&caller, token_id, full.clone());
+		BalanceCheckpoints::<T>::insert(&recipient, token_id, full.clone());
+		SupplyCheckpoints::<T>::insert(token_id, full.clone());
+	}
+	frame_system::Pallet::<T>::set_block_number(T::MaxCheckpoints::get().into());
+	<Erc1155<T> as Erc1155Trait<_>>::set_approval_for_all(&caller, &recipient, true);
+	(caller, recipient, token_ids)
+}
+
+/// Create a collection owned by the caller with a separate admin, so permission checks have to
+/// fall through to `CollectionAdmins`.
+fn setup_collection<T: Config>() -> (T::AccountId, T::AccountId, T::CollectionId)
+where
+	T::CollectionId: From<u32>,
+{
+	let owner: T::AccountId = whitelisted_caller();
+	let admin: T::AccountId = account("admin", 0, SEED);
+	let collection_id: T::CollectionId = 0u32.into();
+	Erc1155::<T>::create_collection(
+		RawOrigin::Signed(owner.clone()).into(),
+		collection_id,
+		None,
+		Perbill::from_percent(5),
+	)
+	.unwrap();
+	Erc1155::<T>::set_collection_admin(
+		RawOrigin::Signed(owner.clone()).into(),
+		collection_id,
+		admin.clone(),
+		true,
+	)
+	.unwrap();
+	(owner, admin, collection_id)
+}
+
+/// Mint a member token of a fresh collection and return it with its admin.
+fn setup_member<T: Config>() -> (T::AccountId, T::TokenId)
+where
+	T::TokenId: From<u32>,
+	T::CollectionId: From<u32>,
+{
+	let (owner, admin, collection_id) = setup_collection::<T>();
+	let token_id: T::TokenId = 0u32.into();
+	Erc1155::<T>::mint(
+		RawOrigin::Signed(admin.clone()).into(),
+		collection_id,
+		token_id,
+		owner,
+		1_000u32.into(),
+	)
+	.unwrap();
+	(admin, token_id)
+}
+
+/// Mint a chain of `d + 1` non-fungible tokens starting at `first`, each nested in the previous
+/// one, and return the sovereign account of the innermost, so crediting it walks `d` parents.
+fn nested_recipient<T: Config>(owner: &T::AccountId, first: u32, d: u32) -> T::AccountId
+where
+	T::TokenId: From<u32>,
+{
+	for i in first..=first + d {
+		let holder = match i == first {
+			true => owner.clone(),
+			false => Erc1155::<T>::token_account_id((i - 1).into()),
+		};
+		<Erc1155<T> as Erc1155Mintable<_>>::create(owner, i.into()).unwrap();
+		<Erc1155<T> as Erc1155Mintable<_>>::mint(&holder, i.into(), 1u32.into()).unwrap();
+	}
+	Erc1155::<T>::token_account_id((first + d).into())
+}
+
+/// Charge a transfer fee on `token_id`, so transfers credit a third account.
+fn charge_fee<T: Config>(token_id: T::TokenId) {
+	let recipient: T::AccountId = account("fee_recipient", 0, SEED);
+	TransferFees::<T>::insert(token_id, TransferFee { rate: Perbill::from_percent(1), recipient });
+}
+
+fn max_string<T: Config>() -> BoundedVec<u8, T::StringLimit> {
+	vec![0u8; T::StringLimit::get() as usize]
+		.try_into()
+		.ok()
+		.expect("data is exactly StringLimit long; qed")
+}
+
+fn max_key<T: Config>() -> AttributeKeyOf<T> {
+	vec![0u8; T::KeyLimit::get() as usize]
+		.try_into()
+		.ok()
+		.expect("key is exactly KeyLimit long; qed")
+}
+
+fn max_value<T: Config>() -> AttributeValueOf<T> {
+	vec![0u8; T::ValueLimit::get() as usize]
+		.try_into()
+		.ok()
+		.expect("value is exactly ValueLimit long; qed")
+}
+
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, DepositBalanceOf::<T>::max_value() / 2u32.into());
+}
+
+benchmarks! {
+	where_clause { where T::TokenId: From<u32>, T::CollectionId: From<u32> }
+
+	init {
+		let b in 1 .. T::MaxBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let token_ids: Vec<T::TokenId> = (0..b).map(Into::into).collect();
+		let supplies: Vec<T::Balance> = token_ids.iter().map(|_| 1_000u32.into()).collect();
+	}: _(RawOrigin::Signed(caller.clone()), bounded::<T, _>(token_ids), bounded::<T, _>(supplies))
+	verify {
+		assert!(!Erc1155::<T>::get_total_supply((b - 1).into()).is_zero());
+	}
+
+	transfer_from_single {
+		let d in 0 .. T::MaxNestingDepth::get() - 1;
+		let (caller, _, token_ids) = setup::<T>(1);
+		let recipient = nested_recipient::<T>(&caller, T::MaxBatchSize::get(), d);
+		let operator: T::AccountId = account("operator", 0, SEED);
+		<Erc1155<T> as Erc1155Trait<_>>::set_approval_for_all(&caller, &operator, true);
+		charge_fee::<T>(token_ids[0]);
+		let amount: T::Balance = 100u32.into();
+	}: _(
+		RawOrigin::Signed(operator),
+		caller.clone(),
+		recipient.clone(),
+		token_ids[0],
+		amount,
+		max_data::<T>()
+	)
+	verify {
+		assert_eq!(Erc1155::<T>::get_balance(&recipient, token_ids[0]), 99u32.into());
+	}
+
+	transfer_from_batch {
+		let b in 1 .. T::MaxBatchSize::get();
+		let d in 0 .. T::MaxNestingDepth::get() - 1;
+		let (caller, _, token_ids) = setup::<T>(b);
+		let recipient = nested_recipient::<T>(&caller, T::MaxBatchSize::get(), d);
+		let operator: T::AccountId = account("operator", 0, SEED);
+		<Erc1155<T> as Erc1155Trait<_>>::set_approval_for_all(&caller, &operator, true);
+		for token_id in token_ids.iter() {
+			charge_fee::<T>(*token_id);
+		}
+		let amounts: Vec<T::Balance> = token_ids.iter().map(|_| 100u32.into()).collect();
+	}: _(
+		RawOrigin::Signed(operator),
+		caller.clone(),
+		recipient.clone(),
+		bounded::<T, _>(token_ids.clone()),
+		bounded::<T, _>(amounts),
+		max_data::<T>()
+	)
+	verify {
+		assert_eq!(Erc1155::<T>::get_balance(&recipient, token_ids[0]), 99u32.into());
+	}
+
+	set_approval_for_all {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), operator.clone(), true)
+	verify {
+		assert!(Erc1155::<T>::get_approval(&caller, &operator));
+	}
+
+	enable_checkpoints {
+		let caller: T::AccountId = whitelisted_caller();
+		let token_id: T::TokenId = 0u32.into();
+		<Erc1155<T> as Erc1155Trait<_>>::init(&caller, &vec![token_id], &vec![1_000u32.into()])?;
+		let origin = T::ForceOrigin::successful_origin();
+		let call = Call::<T>::enable_checkpoints { token_id };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(Erc1155::<T>::get_checkpointed_since(token_id).is_some());
+	}
+
+	create_collection {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id: T::CollectionId = 0u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), collection_id, Some(100), Perbill::from_percent(5))
+	verify {
+		assert!(Erc1155::<T>::get_collection(collection_id).is_some());
+	}
+
+	set_collection_metadata {
+		let (_, admin, collection_id) = setup_collection::<T>();
+	}: _(RawOrigin::Signed(admin), collection_id, max_string::<T>())
+	verify {
+		assert_eq!(Erc1155::<T>::get_collection_metadata(collection_id), max_string::<T>());
+	}
+
+	set_collection_admin {
+		let (owner, _, collection_id) = setup_collection::<T>();
+		let admin: T::AccountId = account("admin", 1, SEED);
+	}: _(RawOrigin::Signed(owner), collection_id, admin.clone(), true)
+	verify {
+		assert!(<Erc1155<T> as Erc1155Trait<_>>::is_collection_admin(collection_id, &admin));
+	}
+
+	assign_to_collection {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id: T::CollectionId = 0u32.into();
+		Erc1155::<T>::create_collection(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection_id,
+			Some(100),
+			Perbill::from_percent(5),
+		)?;
+		let token_id: T::TokenId = 0u32.into();
+		<Erc1155<T> as Erc1155Trait<_>>::init(&caller, &vec![token_id], &vec![1_000u32.into()])?;
+	}: _(RawOrigin::Signed(caller), token_id, collection_id)
+	verify {
+		assert_eq!(Erc1155::<T>::get_token_collection(token_id), Some(collection_id));
+	}
+
+	mint {
+		let (owner, admin, collection_id) = setup_collection::<T>();
+		let token_id: T::TokenId = 0u32.into();
+		let amount: T::Balance = 1_000u32.into();
+	}: _(RawOrigin::Signed(admin), collection_id, token_id, owner.clone(), amount)
+	verify {
+		assert_eq!(Erc1155::<T>::get_balance(&owner, token_id), amount);
+	}
+
+	set_token_metadata {
+		let (admin, token_id) = setup_member::<T>();
+	}: _(RawOrigin::Signed(admin), token_id, max_string::<T>())
+	verify {
+		assert_eq!(Erc1155::<T>::get_token_metadata(token_id), max_string::<T>());
+	}
+
+	freeze {
+		let (admin, token_id) = setup_member::<T>();
+	}: _(RawOrigin::Signed(admin), token_id)
+	verify {
+		assert!(FrozenTokens::<T>::contains_key(token_id));
+	}
+
+	thaw {
+		let (admin, token_id) = setup_member::<T>();
+		FrozenTokens::<T>::insert(token_id, ());
+	}: _(RawOrigin::Signed(admin), token_id)
+	verify {
+		assert!(!FrozenTokens::<T>::contains_key(token_id));
+	}
+
+	set_attribute {
+		let (admin, token_id) = setup_member::<T>();
+		let owner: T::AccountId = whitelisted_caller();
+		fund::<T>(&owner);
+		fund::<T>(&admin);
+		// overwrite a value deposited by another account so both deposits are touched
+		Erc1155::<T>::set_attribute(
+			RawOrigin::Signed(owner).into(),
+			token_id,
+			AttributeNamespace::Issuer,
+			max_key::<T>(),
+			max_value::<T>(),
+		)?;
+	}: _(RawOrigin::Signed(admin), token_id, AttributeNamespace::Issuer, max_key::<T>(), max_value::<T>())
+	verify {
+		assert_eq!(
+			<Erc1155<T> as Erc1155Attributes<_>>::attribute(token_id, &AttributeNamespace::Issuer, &max_key::<T>()),
+			Some(max_value::<T>().into_inner())
+		);
+	}
+
+	clear_attribute {
+		let (admin, token_id) = setup_member::<T>();
+		fund::<T>(&admin);
+		Erc1155::<T>::set_attribute(
+			RawOrigin::Signed(admin.clone()).into(),
+			token_id,
+			AttributeNamespace::Issuer,
+			max_key::<T>(),
+			max_value::<T>(),
+		)?;
+	}: _(RawOrigin::Signed(admin), token_id, AttributeNamespace::Issuer, max_key::<T>())
+	verify {
+		assert!(
+			<Erc1155<T> as Erc1155Attributes<_>>::attribute(token_id, &AttributeNamespace::Issuer, &max_key::<T>())
+				.is_none()
+		);
+	}
+
+	set_max_supply {
+		let s in 0 .. T::MaxEmissionSchedules::get();
+		let (admin, token_id) = setup_member::<T>();
+		let schedules: Vec<_> = (0..s)
+			.map(|i| EmissionSchedule {
+				start: i.into(),
+				end: (i + 10).into(),
+				amount: 1_000u32.into(),
+			})
+			.collect();
+		let schedules: EmissionSchedulesOf<T> = schedules.try_into().ok().unwrap();
+	}: _(RawOrigin::Signed(admin), token_id, 1_000_000u32.into(), schedules)
+	verify {
+		assert!(Erc1155::<T>::get_supply_cap(token_id).is_some());
+	}
+
+	transfer_from_token {
+		let d in 0 .. T::MaxNestingDepth::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		// token `i` is nested in token `i - 1`, token `d` holds the transferred token `d + 1`
+		for i in 0..=d {
+			let holder = match i {
+				0 => caller.clone(),
+				_ => Erc1155::<T>::token_account_id((i - 1).into()),
+			};
+			<Erc1155<T> as Erc1155Mintable<_>>::create(&caller, i.into())?;
+			<Erc1155<T> as Erc1155Mintable<_>>::mint(&holder, i.into(), 1u32.into())?;
+		}
+		let parent_id: T::TokenId = d.into();
+		let token_id: T::TokenId = (d + 1).into();
+		<Erc1155<T> as Erc1155Mintable<_>>::create(&caller, token_id)?;
+		<Erc1155<T> as Erc1155Mintable<_>>::mint(
+			&Erc1155::<T>::token_account_id(parent_id),
+			token_id,
+			1_000u32.into(),
+		)?;
+	}: _(RawOrigin::Signed(caller), parent_id, recipient.clone(), token_id, 1_000u32.into(), max_data::<T>())
+	verify {
+		assert_eq!(Erc1155::<T>::get_balance(&recipient, token_id), 1_000u32.into());
+	}
+
+	set_min_balance {
+		let (admin, token_id) = setup_member::<T>();
+	}: _(RawOrigin::Signed(admin), token_id, 10u32.into(), DustPolicy::Burn)
+	verify {
+		assert!(Erc1155::<T>::get_min_balance(token_id).is_some());
+	}
+
+	set_transfer_fee {
+		let (admin, token_id) = setup_member::<T>();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+	}: _(RawOrigin::Signed(admin), token_id, Perbill::from_percent(1), recipient)
+	verify {
+		assert!(Erc1155::<T>::get_transfer_fee(token_id).is_some());
+	}
+
+	set_fee_exemption {
+		let (admin, token_id) = setup_member::<T>();
+		let who: T::AccountId = account("exempt", 0, SEED);
+	}: _(RawOrigin::Signed(admin), token_id, who.clone(), true)
+	verify {
+		assert!(Erc1155::<T>::is_fee_exempt(&token_id, &who));
+	}
+
+	impl_benchmark_test_suite!(Erc1155, crate::mock::new_test_ext(), crate::mock::Test);
+}
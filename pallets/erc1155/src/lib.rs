@@ -1,6 +1,15 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+pub mod migrations;
+mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 #[cfg(test)]
 mod mock;
@@ -10,16 +19,48 @@ mod tests;
 
 #[frame_support::pallet]
 pub mod pallet {
+	use crate::{
+		migrations, CollectionDetails, DustPolicy, EmissionSchedule, MinBalance, SupplyCap,
+		TokenDetails, TokenLock, TransferFee, WeightInfo,
+	};
 	use codec::FullCodec;
-	use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*, transactional};
+	use frame_support::{
+		dispatch::DispatchResult,
+		ensure,
+		pallet_prelude::*,
+		traits::{Contains, Currency, ReservableCurrency},
+		transactional, PalletId,
+	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedMul, CheckedSub, Zero};
-	use sp_std::vec::Vec;
+	use sp_runtime::{
+		traits::{
+			AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedMul, CheckedSub, One,
+			Saturating, Zero,
+		},
+		Perbill,
+	};
 	use sp_std::{
 		cmp::{Eq, PartialEq},
 		fmt::Debug,
 	};
-	use traits::Erc1155;
+	use sp_std::{vec, vec::Vec};
+	use traits::{
+		AttributeNamespace, Erc1155, Erc1155Attributes, Erc1155Checkpoints, Erc1155Lockable,
+		Erc1155Mintable, Erc1155Royalties, LockIdentifier, OnErc1155Dust, OnErc1155Received,
+	};
+
+	type CheckpointsOf<T> = BoundedVec<
+		(<T as frame_system::Config>::BlockNumber, <T as Config>::Balance),
+		<T as Config>::MaxCheckpoints,
+	>;
+	pub type DepositBalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type AttributeKeyOf<T> = BoundedVec<u8, <T as Config>::KeyLimit>;
+	pub type AttributeValueOf<T> = BoundedVec<u8, <T as Config>::ValueLimit>;
+	pub type EmissionSchedulesOf<T> = BoundedVec<
+		EmissionSchedule<<T as Config>::Balance, <T as frame_system::Config>::BlockNumber>,
+		<T as Config>::MaxEmissionSchedules,
+	>;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -38,16 +79,78 @@ pub mod pallet {
 			+ Debug
 			+ Default
 			+ scale_info::TypeInfo;
+		type CollectionId: FullCodec
+			+ Eq
+			+ PartialEq
+			+ Copy
+			+ MaybeSerializeDeserialize
+			+ Debug
+			+ scale_info::TypeInfo;
 		#[pallet::constant]
 		type Decimals: Get<u32>;
+		type ForceOrigin: EnsureOrigin<Self::Origin>;
+		/// Checkpoints kept in the current page of an account and token, full pages are archived
+		/// rather than pruned so the history can't be pushed out by transfers to the account.
+		#[pallet::constant]
+		type MaxCheckpoints: Get<u32>;
+		/// Number of legacy entries migrated per block while a storage migration is running.
+		#[pallet::constant]
+		type MigrationItemsPerBlock: Get<u32>;
+		/// Maximum number of token ids in a single `init` or batch transfer.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+		/// Maximum length of the `data` payload attached to transfers.
+		#[pallet::constant]
+		type MaxDataLength: Get<u32>;
+		/// Maximum length of collection and token metadata.
+		#[pallet::constant]
+		type StringLimit: Get<u32>;
+		/// Receiver hook called after every transfer, an error rejects the transfer.
+		type OnReceived: OnErc1155Received<Self::AccountId, Self::TokenId, Self::Balance>;
+		/// Currency used for attribute deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Deposit reserved for every stored attribute.
+		#[pallet::constant]
+		type AttributeDepositBase: Get<DepositBalanceOf<Self>>;
+		/// Additional deposit reserved per byte of attribute key and value.
+		#[pallet::constant]
+		type DepositPerByte: Get<DepositBalanceOf<Self>>;
+		#[pallet::constant]
+		type KeyLimit: Get<u32>;
+		#[pallet::constant]
+		type ValueLimit: Get<u32>;
+		/// Maximum number of emission schedules per token.
+		#[pallet::constant]
+		type MaxEmissionSchedules: Get<u32>;
+		/// Maximum number of locks on the balance of a single account and token.
+		#[pallet::constant]
+		type MaxLocks: Get<u32>;
+		/// Used to derive the sovereign account of every token id.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// Maximum number of ancestors of a nested token.
+		#[pallet::constant]
+		type MaxNestingDepth: Get<u32>;
+		/// Called with dust burned under `DustPolicy::Burn`.
+		type OnDust: OnErc1155Dust<Self::AccountId, Self::TokenId, Self::Balance>;
+		/// Accounts exempt from the transfer fees of every token, such as dex pools.
+		type FeeExempt: Contains<Self::AccountId>;
+		type WeightInfo: WeightInfo;
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
-	#[pallet::getter(fn get_total_supply)]
+	pub(super) type Tokens<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::TokenId, TokenDetails<T::AccountId, T::Balance>>;
+
+	/// Legacy token supplies, drained into `Tokens` by the v1 migration.
+	#[pallet::storage]
 	pub(super) type TotalSupply<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::TokenId, T::Balance, ValueQuery>;
 
@@ -64,7 +167,11 @@ pub mod pallet {
 	>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn get_approval)]
+	pub(super) type OperatorApprovals<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Legacy approvals, drained into `OperatorApprovals` by the v1 migration.
+	#[pallet::storage]
 	pub(super) type Approval<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
@@ -75,13 +182,265 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn get_collection)]
+	pub(super) type Collections<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, CollectionDetails<T::AccountId>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_collection_metadata)]
+	pub(super) type CollectionMetadata<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		BoundedVec<u8, T::StringLimit>,
+		ValueQuery,
+	>;
+
+	/// Accounts allowed to mint, freeze and edit metadata on behalf of the collection owner.
+	#[pallet::storage]
+	pub(super) type CollectionAdmins<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::CollectionId, Blake2_128Concat, T::AccountId, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_token_collection)]
+	pub(super) type TokenCollection<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::TokenId, T::CollectionId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_token_metadata)]
+	pub(super) type TokenMetadata<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::TokenId, BoundedVec<u8, T::StringLimit>, ValueQuery>;
+
+	#[pallet::storage]
+	pub(super) type FrozenTokens<T: Config> = StorageMap<_, Blake2_128Concat, T::TokenId, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_supply_cap)]
+	pub(super) type SupplyCaps<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::TokenId, SupplyCap<T::Balance>>;
+
+	/// Schedules limiting mints after a cap was declared, no schedules means no limit other
+	/// than the cap.
+	#[pallet::storage]
+	#[pallet::getter(fn get_emission_schedules)]
+	pub(super) type EmissionSchedules<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::TokenId, EmissionSchedulesOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_locks)]
+	pub(super) type Locks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::TokenId,
+		BoundedVec<TokenLock<T::Balance>, T::MaxLocks>,
+		ValueQuery,
+	>;
+
+	/// Attribute values with the account and amount of the deposit reserved for them, `Pallet`
+	/// namespace entries carry no deposit.
+	#[pallet::storage]
+	pub(super) type Attributes<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::TokenId>,
+			NMapKey<Blake2_128Concat, AttributeNamespace<T::AccountId>>,
+			NMapKey<Blake2_128Concat, AttributeKeyOf<T>>,
+		),
+		(AttributeValueOf<T>, Option<(T::AccountId, DepositBalanceOf<T>)>),
+	>;
+
+	/// Minimum balances of tokens, accounts may only hold zero or at least this amount.
+	#[pallet::storage]
+	#[pallet::getter(fn get_min_balance)]
+	pub(super) type MinBalances<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::TokenId, MinBalance<T::Balance>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_transfer_fee)]
+	pub(super) type TransferFees<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::TokenId, TransferFee<T::AccountId>>;
+
+	/// Accounts exempt from the transfer fee of a single token.
+	#[pallet::storage]
+	pub(super) type FeeExemptions<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::TokenId, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Non-fungible tokens held by the sovereign account of another token, mapped to that token.
+	#[pallet::storage]
+	#[pallet::getter(fn get_parent)]
+	pub(super) type Parents<T: Config> = StorageMap<_, Blake2_128Concat, T::TokenId, T::TokenId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_migration_in_progress)]
+	pub(super) type MigrationInProgress<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Tokens with balance checkpointing enabled, mapped to the block it was enabled at.
+	#[pallet::storage]
+	#[pallet::getter(fn get_checkpointed_since)]
+	pub(super) type CheckpointedTokens<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::TokenId, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_balance_checkpoints)]
+	pub(super) type BalanceCheckpoints<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::TokenId,
+		CheckpointsOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_supply_checkpoints)]
+	pub(super) type SupplyCheckpoints<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::TokenId, CheckpointsOf<T>, ValueQuery>;
+
+	/// Number of full pages of balance checkpoints moved to `ArchivedBalanceCheckpoints`.
+	#[pallet::storage]
+	#[pallet::getter(fn get_balance_checkpoint_pages)]
+	pub(super) type BalanceCheckpointPages<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::TokenId,
+		u32,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	pub(super) type ArchivedBalanceCheckpoints<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::TokenId>,
+			NMapKey<Twox64Concat, u32>,
+		),
+		CheckpointsOf<T>,
+		ValueQuery,
+	>;
+
+	/// Number of full pages of supply checkpoints moved to `ArchivedSupplyCheckpoints`.
+	#[pallet::storage]
+	#[pallet::getter(fn get_supply_checkpoint_pages)]
+	pub(super) type SupplyCheckpointPages<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::TokenId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	pub(super) type ArchivedSupplyCheckpoints<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::TokenId,
+		Twox64Concat,
+		u32,
+		CheckpointsOf<T>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		Initialized(T::AccountId, Vec<T::TokenId>, Vec<T::Balance>),
-		TransferSingle(T::AccountId, T::AccountId, T::TokenId, T::Balance),
-		TransferBatch(T::AccountId, T::AccountId, Vec<T::TokenId>, Vec<T::Balance>),
+		/// `value` is the amount `to` received, net of transfer fees and including swept dust.
+		TransferSingle {
+			operator: T::AccountId,
+			from: T::AccountId,
+			to: T::AccountId,
+			id: T::TokenId,
+			value: T::Balance,
+		},
+		/// `values` are the amounts `to` received, as for `TransferSingle`.
+		TransferBatch {
+			operator: T::AccountId,
+			from: T::AccountId,
+			to: T::AccountId,
+			ids: Vec<T::TokenId>,
+			values: Vec<T::Balance>,
+		},
 		ApprovalForAll(T::AccountId, T::AccountId, bool),
+		CheckpointsEnabled(T::TokenId, T::BlockNumber),
+		CollectionCreated {
+			collection_id: T::CollectionId,
+			owner: T::AccountId,
+		},
+		CollectionMetadataSet {
+			collection_id: T::CollectionId,
+		},
+		CollectionAdminSet {
+			collection_id: T::CollectionId,
+			admin: T::AccountId,
+			is_admin: bool,
+		},
+		TokenAssigned {
+			token_id: T::TokenId,
+			collection_id: T::CollectionId,
+		},
+		TokenMetadataSet {
+			token_id: T::TokenId,
+		},
+		TokenFrozen {
+			token_id: T::TokenId,
+		},
+		TokenThawed {
+			token_id: T::TokenId,
+		},
+		AttributeSet {
+			token_id: T::TokenId,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: AttributeKeyOf<T>,
+			value: AttributeValueOf<T>,
+		},
+		AttributeCleared {
+			token_id: T::TokenId,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: AttributeKeyOf<T>,
+		},
+		MaxSupplySet {
+			token_id: T::TokenId,
+			max_supply: T::Balance,
+			schedules: u32,
+		},
+		MinBalanceSet {
+			token_id: T::TokenId,
+			min_balance: T::Balance,
+			dust: DustPolicy,
+		},
+		/// Remainder of `from` below the minimum balance was added to the transfer to `to`.
+		DustSwept {
+			from: T::AccountId,
+			to: T::AccountId,
+			token_id: T::TokenId,
+			amount: T::Balance,
+		},
+		TransferFeeSet {
+			token_id: T::TokenId,
+			rate: Perbill,
+			recipient: T::AccountId,
+		},
+		FeeExemptionSet {
+			token_id: T::TokenId,
+			who: T::AccountId,
+			exempt: bool,
+		},
+		/// A transfer from `from` paid `fee` to the fee recipient, `to` received `received`.
+		TransferFeePaid {
+			from: T::AccountId,
+			to: T::AccountId,
+			token_id: T::TokenId,
+			fee: T::Balance,
+			received: T::Balance,
+		},
+		/// Remainder of `who` below the minimum balance was burned.
+		DustBurned {
+			who: T::AccountId,
+			token_id: T::TokenId,
+			amount: T::Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -96,24 +455,67 @@ pub mod pallet {
 		SelfTransfer,
 		ZeroAmountTransfer,
 		ZeroAdressTransfer,
+		CheckpointsAlreadyEnabled,
+		CheckpointsDisabled,
+		CheckpointUnavailable,
+		CollectionAlreadyExists,
+		UnknownCollection,
+		NoPermission,
+		CollectionFull,
+		WrongCollection,
+		AlreadyInCollection,
+		TokenFrozen,
+		AttributeTooLong,
+		UnknownAttribute,
+		MaxSupplyAlreadySet,
+		MaxSupplyBelowSupply,
+		InvalidEmissionSchedule,
+		MaxSupplyExceeded,
+		EmissionScheduleExceeded,
+		LiquidityRestrictions,
+		TooManyLocks,
+		InvalidParent,
+		NestingCycle,
+		NestingTooDeep,
+		BelowMinBalance,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			migrations::on_runtime_upgrade::<T>()
+		}
+
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			if Self::get_migration_in_progress() {
+				migrations::step::<T>(T::MigrationItemsPerBlock::get())
+					.saturating_add(T::DbWeight::get().reads(1))
+			} else {
+				T::DbWeight::get().reads(1)
+			}
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		#[pallet::weight(100)]
+		#[pallet::weight(T::WeightInfo::init(token_ids.len() as u32))]
 		#[transactional]
 		pub fn init(
 			origin: OriginFor<T>,
-			token_ids: Vec<T::TokenId>,
-			initial_supplies: Vec<T::Balance>,
+			token_ids: BoundedVec<T::TokenId, T::MaxBatchSize>,
+			initial_supplies: BoundedVec<T::Balance, T::MaxBatchSize>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			<Self as Erc1155<_>>::init(&sender, &token_ids, &initial_supplies)?;
-			Self::deposit_event(Event::Initialized(sender, token_ids, initial_supplies));
+			Self::deposit_event(Event::Initialized(
+				sender,
+				token_ids.into_inner(),
+				initial_supplies.into_inner(),
+			));
 			Ok(())
 		}
 
-		#[pallet::weight(1000)]
+		#[pallet::weight(Pallet::<T>::transfer_single_weight())]
 		#[transactional]
 		pub fn transfer_from_single(
 			origin: OriginFor<T>,
@@ -121,29 +523,27 @@ pub mod pallet {
 			to: T::AccountId,
 			token_id: T::TokenId,
 			amount: T::Balance,
+			data: BoundedVec<u8, T::MaxDataLength>,
 		) -> DispatchResult {
-			let _sender = ensure_signed(origin)?;
-			<Self as Erc1155<_>>::transfer_from_single(&from, &to, &token_id, &amount)?;
-			Self::deposit_event(Event::TransferSingle(from, to, token_id, amount));
-			Ok(())
+			let sender = ensure_signed(origin)?;
+			Self::do_transfer_from_single(&sender, &from, &to, &token_id, &amount, &data)
 		}
 
-		#[pallet::weight(1000)]
+		#[pallet::weight(Pallet::<T>::transfer_batch_weight(token_ids.len() as u32))]
 		#[transactional]
 		pub fn transfer_from_batch(
 			origin: OriginFor<T>,
 			from: T::AccountId,
 			to: T::AccountId,
-			token_ids: Vec<T::TokenId>,
-			amounts: Vec<T::Balance>,
+			token_ids: BoundedVec<T::TokenId, T::MaxBatchSize>,
+			amounts: BoundedVec<T::Balance, T::MaxBatchSize>,
+			data: BoundedVec<u8, T::MaxDataLength>,
 		) -> DispatchResult {
-			let _sender = ensure_signed(origin)?;
-			<Self as Erc1155<_>>::transfer_from_batch(&from, &to, &token_ids, &amounts)?;
-			Self::deposit_event(Event::TransferBatch(from, to, token_ids, amounts));
-			Ok(())
+			let sender = ensure_signed(origin)?;
+			Self::do_transfer_from_batch(&sender, &from, &to, &token_ids, &amounts, &data)
 		}
 
-		#[pallet::weight(1000)]
+		#[pallet::weight(T::WeightInfo::set_approval_for_all())]
 		#[transactional]
 		pub fn set_approval_for_all(
 			origin: OriginFor<T>,
@@ -155,139 +555,1357 @@ pub mod pallet {
 			Self::deposit_event(Event::ApprovalForAll(sender, operator, approved));
 			Ok(())
 		}
-	}
-
-	impl<T: Config> Erc1155<T::AccountId> for Pallet<T> {
-		type TokenId = T::TokenId;
-		type Balance = T::Balance;
 
-		fn init(
-			who: &T::AccountId,
-			token_ids: &Vec<Self::TokenId>,
-			initial_supplies: &Vec<Self::Balance>,
-		) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::enable_checkpoints())]
+		pub fn enable_checkpoints(origin: OriginFor<T>, token_id: T::TokenId) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			Self::token_initialized(&token_id)?;
 			ensure!(
-				token_ids.len() == initial_supplies.len(),
-				Error::<T>::InsufficientDataProvided
+				!CheckpointedTokens::<T>::contains_key(&token_id),
+				Error::<T>::CheckpointsAlreadyEnabled
 			);
-			for (token_id, supply) in token_ids.iter().zip(initial_supplies) {
-				let real_supply = supply
-					.checked_mul(
-						&(10 as u128)
-							.checked_pow(T::Decimals::get())
-							.unwrap()
-							.try_into()
-							.ok()
-							.unwrap(),
-					)
-					.ok_or(Error::<T>::Overflow)?;
-				Self::token_uninitialized(token_id)?;
-				ensure!(!real_supply.is_zero(), Error::<T>::ZeroSupplyProvided);
-				Balances::<T>::insert(who, token_id, real_supply);
-				TotalSupply::<T>::insert(token_id, real_supply);
-			}
+			let now = frame_system::Pallet::<T>::block_number();
+			CheckpointedTokens::<T>::insert(&token_id, now);
+			Self::deposit_event(Event::CheckpointsEnabled(token_id, now));
 			Ok(())
 		}
 
-		fn total_supply(
-			token_id: Self::TokenId,
-		) -> Result<Self::Balance, sp_runtime::DispatchError> {
-			Self::token_initialized(&token_id)?;
-			Ok(Self::get_total_supply(token_id))
+		#[pallet::weight(T::WeightInfo::create_collection())]
+		pub fn create_collection(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			max_items: Option<u32>,
+			default_royalty: Perbill,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				!Collections::<T>::contains_key(&collection_id),
+				Error::<T>::CollectionAlreadyExists
+			);
+			Collections::<T>::insert(
+				&collection_id,
+				CollectionDetails { owner: sender.clone(), max_items, items: 0, default_royalty },
+			);
+			Self::deposit_event(Event::CollectionCreated { collection_id, owner: sender });
+			Ok(())
 		}
 
-		fn balance_of(
-			account: &T::AccountId,
-			token_id: Self::TokenId,
-		) -> Result<Self::Balance, sp_runtime::DispatchError> {
-			Self::token_initialized(&token_id)?;
-			Ok(Self::get_balance(account, token_id))
+		#[pallet::weight(T::WeightInfo::set_collection_metadata())]
+		pub fn set_collection_metadata(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			data: BoundedVec<u8, T::StringLimit>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_collection_admin(&collection_id, &sender)?;
+			CollectionMetadata::<T>::insert(&collection_id, data);
+			Self::deposit_event(Event::CollectionMetadataSet { collection_id });
+			Ok(())
 		}
 
-		fn balance_of_batch(
-			accounts: Vec<&T::AccountId>,
-			token_ids: Vec<Self::TokenId>,
-		) -> Result<Vec<Self::Balance>, sp_runtime::DispatchError> {
-			ensure!(accounts.len() == token_ids.len(), Error::<T>::InsufficientDataProvided);
-			let mut balance = Vec::new();
-			for (account, id) in accounts.iter().zip(token_ids) {
-				balance.push(Self::balance_of(account, id).ok().unwrap());
+		#[pallet::weight(T::WeightInfo::set_collection_admin())]
+		pub fn set_collection_admin(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			admin: T::AccountId,
+			is_admin: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection =
+				Self::get_collection(&collection_id).ok_or(Error::<T>::UnknownCollection)?;
+			ensure!(collection.owner == sender, Error::<T>::NoPermission);
+			if is_admin {
+				CollectionAdmins::<T>::insert(&collection_id, &admin, ());
+			} else {
+				CollectionAdmins::<T>::remove(&collection_id, &admin);
 			}
-			Ok(balance)
+			Self::deposit_event(Event::CollectionAdminSet { collection_id, admin, is_admin });
+			Ok(())
 		}
 
-		fn transfer_from_single(
-			from: &T::AccountId,
-			to: &T::AccountId,
-			token_id: &Self::TokenId,
-			amount: &Self::Balance,
+		/// Move a token created with `init` into a collection. Requires owning the token and
+		/// administering the collection.
+		#[pallet::weight(T::WeightInfo::assign_to_collection())]
+		pub fn assign_to_collection(
+			origin: OriginFor<T>,
+			token_id: T::TokenId,
+			collection_id: T::CollectionId,
 		) -> DispatchResult {
-			Self::token_initialized(&token_id)?;
-			ensure!(!amount.is_zero(), Error::<T>::ZeroAmountTransfer);
-			ensure!(to != &T::AccountId::default(), Error::<T>::ZeroAdressTransfer);
-			ensure!(from != to, Error::<T>::SelfTransfer);
-			ensure!(Self::get_approval(from, to), Error::<T>::TransferNotApproved);
-			Self::_transfer(from, to, token_id, amount)
+			let sender = ensure_signed(origin)?;
+			let token = Self::get_token(token_id).ok_or(Error::<T>::Uninitilized)?;
+			ensure!(token.owner.as_ref() == Some(&sender), Error::<T>::NoPermission);
+			ensure!(
+				!TokenCollection::<T>::contains_key(&token_id),
+				Error::<T>::AlreadyInCollection
+			);
+			Self::ensure_collection_admin(&collection_id, &sender)?;
+			Self::add_collection_item(&collection_id)?;
+			TokenCollection::<T>::insert(&token_id, collection_id);
+			Self::deposit_event(Event::TokenAssigned { token_id, collection_id });
+			Ok(())
 		}
 
-		fn transfer_from_batch(
-			from: &T::AccountId,
-			to: &T::AccountId,
-			token_ids: &Vec<Self::TokenId>,
-			amounts: &Vec<Self::Balance>,
+		/// Mint `amount` base units of `token_id` to `to`. Unknown token ids are created as
+		/// members of the collection.
+		#[pallet::weight(T::WeightInfo::mint())]
+		#[transactional]
+		pub fn mint(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			token_id: T::TokenId,
+			to: T::AccountId,
+			amount: T::Balance,
 		) -> DispatchResult {
-			ensure!(token_ids.len() == amounts.len(), Error::<T>::InsufficientDataProvided);
-			ensure!(to != &T::AccountId::default(), Error::<T>::ZeroAdressTransfer);
-			ensure!(from != to, <Error<T>>::SelfTransfer);
-			ensure!(Self::get_approval(from, to), Error::<T>::TransferNotApproved);
-			for (token_id, amount) in token_ids.iter().zip(amounts) {
-				Self::token_initialized(&token_id)?;
-				ensure!(!amount.is_zero(), Error::<T>::ZeroAmountTransfer);
-				Self::_transfer(from, to, token_id, amount)?;
+			let sender = ensure_signed(origin)?;
+			let collection = Self::ensure_collection_admin(&collection_id, &sender)?;
+			match Self::get_token_collection(&token_id) {
+				Some(member_of) => ensure!(member_of == collection_id, Error::<T>::WrongCollection),
+				None => {
+					Self::token_uninitialized(&token_id)?;
+					Self::add_collection_item(&collection_id)?;
+					TokenCollection::<T>::insert(&token_id, collection_id);
+					Tokens::<T>::insert(
+						&token_id,
+						TokenDetails { owner: Some(collection.owner), supply: Zero::zero() },
+					);
+				},
 			}
-			Ok(())
+			Self::do_mint(&sender, &to, &token_id, &amount)
 		}
 
-		fn transfer(
-			from: &T::AccountId,
-			to: &T::AccountId,
-			token_id: Self::TokenId,
-			amount: Self::Balance,
+		#[pallet::weight(T::WeightInfo::set_token_metadata())]
+		pub fn set_token_metadata(
+			origin: OriginFor<T>,
+			token_id: T::TokenId,
+			data: BoundedVec<u8, T::StringLimit>,
 		) -> DispatchResult {
-			Self::token_initialized(&token_id)?;
-			ensure!(!amount.is_zero(), Error::<T>::ZeroAmountTransfer);
-			ensure!(to != &T::AccountId::default(), Error::<T>::ZeroAdressTransfer);
-			ensure!(from != to, Error::<T>::SelfTransfer);
-			Self::_transfer(from, to, &token_id, &amount)
+			let sender = ensure_signed(origin)?;
+			Self::ensure_token_admin(&token_id, &sender)?;
+			TokenMetadata::<T>::insert(&token_id, data);
+			Self::deposit_event(Event::TokenMetadataSet { token_id });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn freeze(origin: OriginFor<T>, token_id: T::TokenId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_token_admin(&token_id, &sender)?;
+			FrozenTokens::<T>::insert(&token_id, ());
+			Self::deposit_event(Event::TokenFrozen { token_id });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::thaw())]
+		pub fn thaw(origin: OriginFor<T>, token_id: T::TokenId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_token_admin(&token_id, &sender)?;
+			FrozenTokens::<T>::remove(&token_id);
+			Self::deposit_event(Event::TokenThawed { token_id });
+			Ok(())
+		}
+
+		/// Set an attribute in the `Issuer` namespace or the sender's own `Owner` namespace,
+		/// reserving a deposit for the stored bytes.
+		#[pallet::weight(T::WeightInfo::set_attribute())]
+		pub fn set_attribute(
+			origin: OriginFor<T>,
+			token_id: T::TokenId,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: AttributeKeyOf<T>,
+			value: AttributeValueOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_attribute_writer(&token_id, &namespace, &sender, true)?;
+			Self::do_set_attribute(token_id, namespace, key, value, Some(&sender))
+		}
+
+		#[pallet::weight(T::WeightInfo::clear_attribute())]
+		pub fn clear_attribute(
+			origin: OriginFor<T>,
+			token_id: T::TokenId,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: AttributeKeyOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_attribute_writer(&token_id, &namespace, &sender, false)?;
+			Self::do_clear_attribute(token_id, namespace, key)
+		}
+
+		/// Commit to a maximum supply and optional emission schedules for future mints. Can
+		/// only be done once per token.
+		#[pallet::weight(T::WeightInfo::set_max_supply(schedules.len() as u32))]
+		pub fn set_max_supply(
+			origin: OriginFor<T>,
+			token_id: T::TokenId,
+			max_supply: T::Balance,
+			schedules: EmissionSchedulesOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_token_issuer(&token_id, &sender)?;
+			ensure!(!SupplyCaps::<T>::contains_key(&token_id), Error::<T>::MaxSupplyAlreadySet);
+			let supply = Self::get_total_supply(token_id);
+			ensure!(max_supply >= supply, Error::<T>::MaxSupplyBelowSupply);
+			ensure!(
+				schedules.iter().all(|schedule| schedule.start <= schedule.end),
+				Error::<T>::InvalidEmissionSchedule
+			);
+			SupplyCaps::<T>::insert(
+				&token_id,
+				SupplyCap { max_supply, issued_before: supply, minted: Zero::zero() },
+			);
+			let count = schedules.len() as u32;
+			if !schedules.is_empty() {
+				EmissionSchedules::<T>::insert(&token_id, schedules);
+			}
+			Self::deposit_event(Event::MaxSupplySet { token_id, max_supply, schedules: count });
+			Ok(())
+		}
+
+		/// Set the minimum balance of `token_id` and what happens to remainders below it, zero
+		/// removes the minimum. Existing balances below the minimum are collected on their next
+		/// transfer.
+		#[pallet::weight(T::WeightInfo::set_min_balance())]
+		pub fn set_min_balance(
+			origin: OriginFor<T>,
+			token_id: T::TokenId,
+			min_balance: T::Balance,
+			dust: DustPolicy,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_token_issuer(&token_id, &sender)?;
+			if min_balance.is_zero() {
+				MinBalances::<T>::remove(&token_id);
+			} else {
+				MinBalances::<T>::insert(&token_id, MinBalance { amount: min_balance, dust });
+			}
+			Self::deposit_event(Event::MinBalanceSet { token_id, min_balance, dust });
+			Ok(())
+		}
+
+		/// Charge `rate` of every transfer of `token_id` and pay it to `recipient`, a zero rate
+		/// removes the fee. Transfers from or to the recipient and exempt accounts are free.
+		#[pallet::weight(T::WeightInfo::set_transfer_fee())]
+		pub fn set_transfer_fee(
+			origin: OriginFor<T>,
+			token_id: T::TokenId,
+			rate: Perbill,
+			recipient: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_token_issuer(&token_id, &sender)?;
+			if rate.is_zero() {
+				TransferFees::<T>::remove(&token_id);
+			} else {
+				TransferFees::<T>::insert(
+					&token_id,
+					TransferFee { rate, recipient: recipient.clone() },
+				);
+			}
+			Self::deposit_event(Event::TransferFeeSet { token_id, rate, recipient });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_fee_exemption())]
+		pub fn set_fee_exemption(
+			origin: OriginFor<T>,
+			token_id: T::TokenId,
+			who: T::AccountId,
+			exempt: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_token_issuer(&token_id, &sender)?;
+			if exempt {
+				FeeExemptions::<T>::insert(&token_id, &who, ());
+			} else {
+				FeeExemptions::<T>::remove(&token_id, &who);
+			}
+			Self::deposit_event(Event::FeeExemptionSet { token_id, who, exempt });
+			Ok(())
+		}
+
+		/// Transfer tokens held by the sovereign account of `parent_id`, the sender must own the
+		/// outermost token `parent_id` is nested in.
+		#[pallet::weight(
+			T::WeightInfo::transfer_from_token(T::MaxNestingDepth::get())
+				.saturating_add(T::OnReceived::on_received_weight(1))
+		)]
+		#[transactional]
+		pub fn transfer_from_token(
+			origin: OriginFor<T>,
+			parent_id: T::TokenId,
+			to: T::AccountId,
+			token_id: T::TokenId,
+			amount: T::Balance,
+			data: BoundedVec<u8, T::MaxDataLength>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_token_owner(&sender, parent_id)?;
+			Self::do_transfer(
+				&sender,
+				&Self::token_account_id(parent_id),
+				&to,
+				&token_id,
+				&amount,
+				&data,
+			)
+			.map(|_| ())
+		}
+	}
+
+	impl<T: Config> Erc1155<T::AccountId> for Pallet<T> {
+		type TokenId = T::TokenId;
+		type Balance = T::Balance;
+		type CollectionId = T::CollectionId;
+
+		fn init(
+			who: &T::AccountId,
+			token_ids: &Vec<Self::TokenId>,
+			initial_supplies: &Vec<Self::Balance>,
+		) -> DispatchResult {
+			ensure!(
+				token_ids.len() == initial_supplies.len(),
+				Error::<T>::InsufficientDataProvided
+			);
+			for (token_id, supply) in token_ids.iter().zip(initial_supplies) {
+				let real_supply = supply
+					.checked_mul(
+						&(10 as u128)
+							.checked_pow(T::Decimals::get())
+							.unwrap()
+							.try_into()
+							.ok()
+							.unwrap(),
+					)
+					.ok_or(Error::<T>::Overflow)?;
+				Self::token_uninitialized(token_id)?;
+				ensure!(!real_supply.is_zero(), Error::<T>::ZeroSupplyProvided);
+				Balances::<T>::insert(who, token_id, real_supply);
+				Tokens::<T>::insert(
+					token_id,
+					TokenDetails { owner: Some(who.clone()), supply: real_supply },
+				);
+				Self::checkpoint_supply(token_id, Zero::zero(), real_supply);
+			}
+			Ok(())
+		}
+
+		fn total_supply(
+			token_id: Self::TokenId,
+		) -> Result<Self::Balance, sp_runtime::DispatchError> {
+			Self::token_initialized(&token_id)?;
+			Ok(Self::get_total_supply(token_id))
+		}
+
+		fn balance_of(
+			account: &T::AccountId,
+			token_id: Self::TokenId,
+		) -> Result<Self::Balance, sp_runtime::DispatchError> {
+			Self::token_initialized(&token_id)?;
+			Ok(Self::get_balance(account, token_id))
+		}
+
+		fn balance_of_batch(
+			accounts: Vec<&T::AccountId>,
+			token_ids: Vec<Self::TokenId>,
+		) -> Result<Vec<Self::Balance>, sp_runtime::DispatchError> {
+			ensure!(accounts.len() == token_ids.len(), Error::<T>::InsufficientDataProvided);
+			let mut balance = Vec::new();
+			for (account, id) in accounts.iter().zip(token_ids) {
+				balance.push(Self::balance_of(account, id).ok().unwrap());
+			}
+			Ok(balance)
+		}
+
+		fn transfer_from_single(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			token_id: &Self::TokenId,
+			amount: &Self::Balance,
+		) -> DispatchResult {
+			Self::do_transfer_from_single(to, from, to, token_id, amount, &[])
+		}
+
+		fn transfer_from_batch(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			token_ids: &Vec<Self::TokenId>,
+			amounts: &Vec<Self::Balance>,
+		) -> DispatchResult {
+			Self::do_transfer_from_batch(to, from, to, token_ids, amounts, &[])
+		}
+
+		fn transfer(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			token_id: Self::TokenId,
+			amount: Self::Balance,
+		) -> DispatchResult {
+			Self::do_transfer(from, from, to, &token_id, &amount, &[]).map(|_| ())
+		}
+
+		fn collection_of(token_id: Self::TokenId) -> Option<Self::CollectionId> {
+			Self::get_token_collection(token_id)
+		}
+
+		fn is_collection_admin(collection_id: Self::CollectionId, who: &T::AccountId) -> bool {
+			Self::ensure_collection_admin(&collection_id, who).is_ok()
 		}
 
 		fn set_approval_for_all(owner: &T::AccountId, operator: &T::AccountId, approved: bool) {
-			Approval::<T>::insert(owner, operator, approved);
+			Approval::<T>::remove(owner, operator);
+			if approved {
+				OperatorApprovals::<T>::insert(owner, operator, ());
+			} else {
+				OperatorApprovals::<T>::remove(owner, operator);
+			}
+		}
+	}
+
+	impl<T: Config> Erc1155Mintable<T::AccountId> for Pallet<T> {
+		fn create(owner: &T::AccountId, token_id: Self::TokenId) -> DispatchResult {
+			Self::token_uninitialized(&token_id)?;
+			Tokens::<T>::insert(
+				&token_id,
+				TokenDetails { owner: Some(owner.clone()), supply: Zero::zero() },
+			);
+			Self::deposit_event(Event::Initialized(
+				owner.clone(),
+				vec![token_id],
+				vec![Zero::zero()],
+			));
+			Ok(())
+		}
+
+		fn mint(
+			to: &T::AccountId,
+			token_id: Self::TokenId,
+			amount: Self::Balance,
+		) -> DispatchResult {
+			Self::do_mint(to, to, &token_id, &amount)
+		}
+
+		fn burn(
+			from: &T::AccountId,
+			token_id: Self::TokenId,
+			amount: Self::Balance,
+		) -> DispatchResult {
+			Self::do_burn(from, from, &token_id, &amount)
+		}
+
+		fn deposit(
+			to: &T::AccountId,
+			token_id: Self::TokenId,
+			amount: Self::Balance,
+		) -> DispatchResult {
+			Self::do_deposit(to, to, &token_id, &amount)
+		}
+
+		fn withdraw(
+			from: &T::AccountId,
+			token_id: Self::TokenId,
+			amount: Self::Balance,
+		) -> DispatchResult {
+			Self::do_burn(from, from, &token_id, &amount)
+		}
+
+		fn is_issuer(who: &T::AccountId, token_id: Self::TokenId) -> bool {
+			Self::ensure_token_issuer(&token_id, who).is_ok()
+		}
+	}
+
+	impl<T: Config> Erc1155Lockable<T::AccountId> for Pallet<T> {
+		fn set_lock(
+			id: LockIdentifier,
+			who: &T::AccountId,
+			token_id: Self::TokenId,
+			amount: Self::Balance,
+		) -> DispatchResult {
+			Locks::<T>::try_mutate_exists(who, token_id, |maybe_locks| -> DispatchResult {
+				let mut locks = maybe_locks.take().unwrap_or_default();
+				match locks.iter_mut().find(|lock| lock.id == id) {
+					Some(lock) => lock.amount = amount,
+					None => locks
+						.try_push(TokenLock { id, amount })
+						.map_err(|_| Error::<T>::TooManyLocks)?,
+				}
+				*maybe_locks = Some(locks);
+				Ok(())
+			})
+		}
+
+		fn remove_lock(id: LockIdentifier, who: &T::AccountId, token_id: Self::TokenId) {
+			Locks::<T>::mutate_exists(who, token_id, |maybe_locks| {
+				if let Some(locks) = maybe_locks {
+					locks.retain(|lock| lock.id != id);
+					if locks.is_empty() {
+						*maybe_locks = None;
+					}
+				}
+			});
+		}
+
+		fn locked_balance(who: &T::AccountId, token_id: Self::TokenId) -> Self::Balance {
+			Self::get_locks(who, token_id)
+				.iter()
+				.map(|lock| lock.amount)
+				.max()
+				.unwrap_or_else(Zero::zero)
+		}
+	}
+
+	impl<T: Config> Erc1155Attributes<T::AccountId> for Pallet<T> {
+		fn attribute(
+			token_id: Self::TokenId,
+			namespace: &AttributeNamespace<T::AccountId>,
+			key: &[u8],
+		) -> Option<Vec<u8>> {
+			let key: AttributeKeyOf<T> = key.to_vec().try_into().ok()?;
+			Attributes::<T>::get((token_id, namespace, key)).map(|(value, _)| value.into_inner())
+		}
+
+		fn set_attribute(token_id: Self::TokenId, key: &[u8], value: &[u8]) -> DispatchResult {
+			let key = key.to_vec().try_into().map_err(|_| Error::<T>::AttributeTooLong)?;
+			let value = value.to_vec().try_into().map_err(|_| Error::<T>::AttributeTooLong)?;
+			Self::do_set_attribute(token_id, AttributeNamespace::Pallet, key, value, None)
+		}
+
+		fn clear_attribute(token_id: Self::TokenId, key: &[u8]) -> DispatchResult {
+			let key = key.to_vec().try_into().map_err(|_| Error::<T>::AttributeTooLong)?;
+			Self::do_clear_attribute(token_id, AttributeNamespace::Pallet, key)
+		}
+	}
+
+	impl<T: Config> Erc1155Royalties<T::AccountId> for Pallet<T> {
+		fn royalty_of(token_id: Self::TokenId) -> Option<(T::AccountId, Perbill)> {
+			let collection = Self::get_collection(Self::get_token_collection(token_id)?)?;
+			(!collection.default_royalty.is_zero())
+				.then(|| (collection.owner, collection.default_royalty))
+		}
+	}
+
+	impl<T: Config> Erc1155Checkpoints<T::AccountId, T::BlockNumber> for Pallet<T> {
+		fn balance_of_at(
+			account: &T::AccountId,
+			token_id: Self::TokenId,
+			block: T::BlockNumber,
+		) -> Result<Self::Balance, sp_runtime::DispatchError> {
+			Self::token_initialized(&token_id)?;
+			let checkpoints = Self::get_balance_checkpoints(account, token_id);
+			let pages = Self::get_balance_checkpoint_pages(account, token_id);
+			let archived =
+				|page: u32| ArchivedBalanceCheckpoints::<T>::get((account, token_id, page));
+			Self::value_at(&token_id, checkpoints, pages, archived, block)
+				.map(|value| value.unwrap_or_else(|| Self::get_balance(account, token_id)))
+		}
+
+		fn total_supply_at(
+			token_id: Self::TokenId,
+			block: T::BlockNumber,
+		) -> Result<Self::Balance, sp_runtime::DispatchError> {
+			Self::token_initialized(&token_id)?;
+			let checkpoints = Self::get_supply_checkpoints(token_id);
+			let pages = Self::get_supply_checkpoint_pages(token_id);
+			let archived = |page: u32| ArchivedSupplyCheckpoints::<T>::get(token_id, page);
+			Self::value_at(&token_id, checkpoints, pages, archived, block)
+				.map(|value| value.unwrap_or_else(|| Self::get_total_supply(token_id)))
 		}
 	}
 
 	impl<T: Config> Pallet<T> {
-		fn _transfer(
+		/// Token details, falling back to legacy storage for tokens not migrated yet.
+		pub fn get_token(token_id: T::TokenId) -> Option<TokenDetails<T::AccountId, T::Balance>> {
+			Tokens::<T>::get(token_id).or_else(|| {
+				let supply = TotalSupply::<T>::get(token_id);
+				(!supply.is_zero()).then(|| TokenDetails { owner: None, supply })
+			})
+		}
+
+		pub fn get_total_supply(token_id: T::TokenId) -> T::Balance {
+			Self::get_token(token_id).map(|token| token.supply).unwrap_or_default()
+		}
+
+		/// Weight of a single transfer, including the nesting walk and the receiver hook.
+		pub fn transfer_single_weight() -> Weight {
+			T::WeightInfo::transfer_from_single(T::MaxNestingDepth::get())
+				.saturating_add(T::OnReceived::on_received_weight(1))
+		}
+
+		/// Weight of a batch transfer of `b` token ids, including the nesting walk and the
+		/// receiver hook.
+		pub fn transfer_batch_weight(b: u32) -> Weight {
+			T::WeightInfo::transfer_from_batch(b, T::MaxNestingDepth::get())
+				.saturating_add(T::OnReceived::on_received_weight(b))
+		}
+
+		pub fn get_approval(owner: &T::AccountId, operator: &T::AccountId) -> bool {
+			OperatorApprovals::<T>::contains_key(owner, operator) ||
+				Approval::<T>::get(owner, operator)
+		}
+
+		/// Transfer by `operator`, who must be `from` or an operator approved by `from`.
+		pub fn do_transfer_from_single(
+			operator: &T::AccountId,
 			from: &T::AccountId,
 			to: &T::AccountId,
 			token_id: &T::TokenId,
 			amount: &T::Balance,
+			data: &[u8],
 		) -> DispatchResult {
-			Balances::<T>::try_mutate(&from, &token_id, |balance| -> Result<(), Error<T>> {
-				let updated_sender_balance =
-					balance.checked_sub(&amount).ok_or(Error::<T>::InsufficientFunds)?;
-				*balance = updated_sender_balance;
+			Self::ensure_can_transfer(from, to, token_id, amount)?;
+			Self::ensure_operator(operator, from)?;
+			Self::transfer_and_notify(operator, from, to, token_id, amount, data).map(|_| ())
+		}
+
+		/// Batch transfer by `operator`, who must be `from` or an operator approved by `from`.
+		pub fn do_transfer_from_batch(
+			operator: &T::AccountId,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			token_ids: &[T::TokenId],
+			amounts: &[T::Balance],
+			data: &[u8],
+		) -> DispatchResult {
+			ensure!(token_ids.len() == amounts.len(), Error::<T>::InsufficientDataProvided);
+			ensure!(to != &T::AccountId::default(), Error::<T>::ZeroAdressTransfer);
+			ensure!(from != to, <Error<T>>::SelfTransfer);
+			Self::ensure_operator(operator, from)?;
+			Self::batch_transfer_and_notify(operator, from, to, token_ids, amounts, data)
+				.map(|_| ())
+		}
+
+		/// Batch transfer without an approval check, for callers that already authorized `from`.
+		/// Returns the amounts received after the transfer fee.
+		#[transactional]
+		pub fn do_transfer_batch(
+			operator: &T::AccountId,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			token_ids: &[T::TokenId],
+			amounts: &[T::Balance],
+			data: &[u8],
+		) -> Result<Vec<T::Balance>, DispatchError> {
+			ensure!(token_ids.len() == amounts.len(), Error::<T>::InsufficientDataProvided);
+			ensure!(to != &T::AccountId::default(), Error::<T>::ZeroAdressTransfer);
+			ensure!(from != to, <Error<T>>::SelfTransfer);
+			Self::batch_transfer_and_notify(operator, from, to, token_ids, amounts, data)
+		}
+
+		fn batch_transfer_and_notify(
+			operator: &T::AccountId,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			token_ids: &[T::TokenId],
+			amounts: &[T::Balance],
+			data: &[u8],
+		) -> Result<Vec<T::Balance>, DispatchError> {
+			let mut received = Vec::with_capacity(amounts.len());
+			for (token_id, amount) in token_ids.iter().zip(amounts) {
+				Self::token_initialized(&token_id)?;
+				ensure!(!amount.is_zero(), Error::<T>::ZeroAmountTransfer);
+				ensure!(!FrozenTokens::<T>::contains_key(token_id), Error::<T>::TokenFrozen);
+				received.push(Self::_transfer(from, to, token_id, amount)?);
+			}
+			T::OnReceived::on_batch_received(operator, from, to, token_ids, &received, data)?;
+			Self::deposit_event(Event::TransferBatch {
+				operator: operator.clone(),
+				from: from.clone(),
+				to: to.clone(),
+				ids: token_ids.to_vec(),
+				values: received.clone(),
+			});
+			Ok(received)
+		}
+
+		/// Transfer without an approval check, for callers that already authorized `from`.
+		/// Returns the amount received after the transfer fee.
+		pub fn do_transfer(
+			operator: &T::AccountId,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			token_id: &T::TokenId,
+			amount: &T::Balance,
+			data: &[u8],
+		) -> Result<T::Balance, DispatchError> {
+			Self::ensure_can_transfer(from, to, token_id, amount)?;
+			Self::transfer_and_notify(operator, from, to, token_id, amount, data)
+		}
+
+		/// Move `amount` between a payer and the holding account of another pallet, e.g. to
+		/// escrow transaction fees. Neither side pays the transfer fee, has dust collected or is
+		/// held to the minimum balance, balances left below it are collected on their next
+		/// regular transfer. Locks and freezes are not checked either, callers debiting a user
+		/// check `transferable_balance` first.
+		#[transactional]
+		pub fn do_transfer_held(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			token_id: &T::TokenId,
+			amount: &T::Balance,
+		) -> DispatchResult {
+			Self::token_initialized(token_id)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmountTransfer);
+			ensure!(from != to, Error::<T>::SelfTransfer);
+			Balances::<T>::try_mutate_exists(from, token_id, |balance| -> DispatchResult {
+				let current_balance = balance.unwrap_or_default();
+				let updated_balance =
+					current_balance.checked_sub(amount).ok_or(Error::<T>::InsufficientFunds)?;
+				Self::checkpoint_balance(from, *token_id, current_balance, updated_balance);
+				*balance = Some(updated_balance).filter(|balance| !balance.is_zero());
+				Ok(())
+			})?;
+			Balances::<T>::try_mutate_exists(to, token_id, |balance| -> DispatchResult {
+				let current_balance = balance.unwrap_or_default();
+				let updated_balance =
+					current_balance.checked_add(amount).ok_or(Error::<T>::Overflow)?;
+				Self::checkpoint_balance(to, *token_id, current_balance, updated_balance);
+				*balance = Some(updated_balance);
+				Ok(())
+			})?;
+			Self::note_holder(to, token_id)?;
+			Self::deposit_event(Event::TransferSingle {
+				operator: from.clone(),
+				from: from.clone(),
+				to: to.clone(),
+				id: *token_id,
+				value: *amount,
+			});
+			Ok(())
+		}
+
+		/// Balance of `token_id` that `who` may move, nothing while the token is frozen.
+		pub fn transferable_balance(who: &T::AccountId, token_id: &T::TokenId) -> T::Balance {
+			if FrozenTokens::<T>::contains_key(token_id) {
+				return Zero::zero()
+			}
+			Self::get_balance(who, token_id).saturating_sub(Self::locked_balance(who, *token_id))
+		}
+
+		fn ensure_operator(operator: &T::AccountId, from: &T::AccountId) -> DispatchResult {
+			ensure!(
+				operator == from || Self::get_approval(from, operator),
+				Error::<T>::TransferNotApproved
+			);
+			Ok(())
+		}
+
+		fn ensure_can_transfer(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			token_id: &T::TokenId,
+			amount: &T::Balance,
+		) -> DispatchResult {
+			Self::token_initialized(token_id)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmountTransfer);
+			ensure!(to != &T::AccountId::default(), Error::<T>::ZeroAdressTransfer);
+			ensure!(from != to, Error::<T>::SelfTransfer);
+			ensure!(!FrozenTokens::<T>::contains_key(token_id), Error::<T>::TokenFrozen);
+			Ok(())
+		}
+
+		/// Credit newly minted tokens to `to`, reported as a transfer from the zero account.
+		#[transactional]
+		pub fn do_mint(
+			operator: &T::AccountId,
+			to: &T::AccountId,
+			token_id: &T::TokenId,
+			amount: &T::Balance,
+		) -> DispatchResult {
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmountTransfer);
+			ensure!(to != &T::AccountId::default(), Error::<T>::ZeroAdressTransfer);
+			Self::note_minted(token_id, amount)?;
+			Self::issue(operator, to, token_id, amount)
+		}
+
+		/// Credit tokens returning from another chain to `to`. They were counted against the cap
+		/// and emission schedules when first minted, so only the total supply is checked against
+		/// the cap.
+		#[transactional]
+		pub fn do_deposit(
+			operator: &T::AccountId,
+			to: &T::AccountId,
+			token_id: &T::TokenId,
+			amount: &T::Balance,
+		) -> DispatchResult {
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmountTransfer);
+			ensure!(to != &T::AccountId::default(), Error::<T>::ZeroAdressTransfer);
+			if let Some(cap) = Self::get_supply_cap(token_id) {
+				let supply = Self::get_total_supply(*token_id)
+					.checked_add(amount)
+					.ok_or(Error::<T>::Overflow)?;
+				ensure!(supply <= cap.max_supply, Error::<T>::MaxSupplyExceeded);
+			}
+			Self::issue(operator, to, token_id, amount)
+		}
+
+		fn issue(
+			operator: &T::AccountId,
+			to: &T::AccountId,
+			token_id: &T::TokenId,
+			amount: &T::Balance,
+		) -> DispatchResult {
+			Tokens::<T>::try_mutate(token_id, |token| -> DispatchResult {
+				let token = token.as_mut().ok_or(Error::<T>::Uninitilized)?;
+				let updated_supply =
+					token.supply.checked_add(amount).ok_or(Error::<T>::Overflow)?;
+				Self::checkpoint_supply(*token_id, token.supply, updated_supply);
+				token.supply = updated_supply;
+				Ok(())
+			})?;
+			Balances::<T>::try_mutate(to, token_id, |balance| -> DispatchResult {
+				let updated_balance = balance.checked_add(amount).ok_or(Error::<T>::Overflow)?;
+				Self::ensure_min_balance(token_id, updated_balance)?;
+				Self::checkpoint_balance(to, *token_id, *balance, updated_balance);
+				*balance = updated_balance;
+				Ok(())
+			})?;
+			Self::note_holder(to, token_id)?;
+			if !Self::get_total_supply(*token_id).is_one() {
+				Parents::<T>::remove(token_id);
+			}
+			Self::deposit_event(Event::TransferSingle {
+				operator: operator.clone(),
+				from: T::AccountId::default(),
+				to: to.clone(),
+				id: *token_id,
+				value: *amount,
+			});
+			Ok(())
+		}
+
+		/// Destroy tokens held by `from`, reported as a transfer to the zero account.
+		#[transactional]
+		pub fn do_burn(
+			operator: &T::AccountId,
+			from: &T::AccountId,
+			token_id: &T::TokenId,
+			amount: &T::Balance,
+		) -> DispatchResult {
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmountTransfer);
+			Balances::<T>::try_mutate_exists(from, token_id, |balance| -> DispatchResult {
+				let current_balance = balance.unwrap_or_default();
+				let updated_balance =
+					current_balance.checked_sub(amount).ok_or(Error::<T>::InsufficientFunds)?;
+				ensure!(
+					updated_balance >= Self::locked_balance(from, *token_id),
+					Error::<T>::LiquidityRestrictions
+				);
+				Self::ensure_min_balance(token_id, updated_balance)?;
+				Self::checkpoint_balance(from, *token_id, current_balance, updated_balance);
+				*balance = Some(updated_balance).filter(|balance| !balance.is_zero());
 				Ok(())
 			})?;
-			Balances::<T>::try_mutate(&to, &token_id, |balance| -> Result<(), Error<T>> {
-				let updated_to_balance =
-					balance.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
-				*balance = updated_to_balance;
+			Tokens::<T>::try_mutate(token_id, |token| -> DispatchResult {
+				let token = token.as_mut().ok_or(Error::<T>::Uninitilized)?;
+				let updated_supply =
+					token.supply.checked_sub(amount).ok_or(Error::<T>::InsufficientFunds)?;
+				Self::checkpoint_supply(*token_id, token.supply, updated_supply);
+				token.supply = updated_supply;
+				Ok(())
+			})?;
+			if Self::get_total_supply(*token_id).is_one() &&
+				Self::get_balance(from, *token_id).is_one()
+			{
+				Self::note_holder(from, token_id)?;
+			} else {
+				Parents::<T>::remove(token_id);
+			}
+			Self::deposit_event(Event::TransferSingle {
+				operator: operator.clone(),
+				from: from.clone(),
+				to: T::AccountId::default(),
+				id: *token_id,
+				value: *amount,
+			});
+			Ok(())
+		}
+
+		fn ensure_collection_admin(
+			collection_id: &T::CollectionId,
+			who: &T::AccountId,
+		) -> Result<CollectionDetails<T::AccountId>, DispatchError> {
+			let collection =
+				Self::get_collection(collection_id).ok_or(Error::<T>::UnknownCollection)?;
+			ensure!(
+				&collection.owner == who || CollectionAdmins::<T>::contains_key(collection_id, who),
+				Error::<T>::NoPermission
+			);
+			Ok(collection)
+		}
+
+		fn ensure_token_admin(token_id: &T::TokenId, who: &T::AccountId) -> DispatchResult {
+			let collection_id =
+				Self::get_token_collection(token_id).ok_or(Error::<T>::NoPermission)?;
+			Self::ensure_collection_admin(&collection_id, who).map(|_| ())
+		}
+
+		/// All attributes of `token_id` as `(namespace, key, value)`.
+		pub fn get_attributes(
+			token_id: T::TokenId,
+		) -> Vec<(AttributeNamespace<T::AccountId>, Vec<u8>, Vec<u8>)> {
+			Attributes::<T>::iter_prefix((token_id,))
+				.map(|((namespace, key), (value, _))| {
+					(namespace, key.into_inner(), value.into_inner())
+				})
+				.collect()
+		}
+
+		/// Store an attribute, moving any deposit of the previous value to `depositor`.
+		#[transactional]
+		fn do_set_attribute(
+			token_id: T::TokenId,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: AttributeKeyOf<T>,
+			value: AttributeValueOf<T>,
+			depositor: Option<&T::AccountId>,
+		) -> DispatchResult {
+			Self::token_initialized(&token_id)?;
+			if let Some((_, Some((old_depositor, old_deposit)))) =
+				Attributes::<T>::get((token_id, &namespace, &key))
+			{
+				T::Currency::unreserve(&old_depositor, old_deposit);
+			}
+			let deposit = match depositor {
+				Some(who) => {
+					let bytes = (key.len() + value.len()) as u32;
+					let amount = T::AttributeDepositBase::get()
+						.saturating_add(T::DepositPerByte::get().saturating_mul(bytes.into()));
+					T::Currency::reserve(who, amount)?;
+					Some((who.clone(), amount))
+				},
+				None => None,
+			};
+			Attributes::<T>::insert((token_id, &namespace, &key), (&value, deposit));
+			Self::deposit_event(Event::AttributeSet { token_id, namespace, key, value });
+			Ok(())
+		}
+
+		fn do_clear_attribute(
+			token_id: T::TokenId,
+			namespace: AttributeNamespace<T::AccountId>,
+			key: AttributeKeyOf<T>,
+		) -> DispatchResult {
+			let (_, deposit) = Attributes::<T>::take((token_id, &namespace, &key))
+				.ok_or(Error::<T>::UnknownAttribute)?;
+			if let Some((depositor, amount)) = deposit {
+				T::Currency::unreserve(&depositor, amount);
+			}
+			Self::deposit_event(Event::AttributeCleared { token_id, namespace, key });
+			Ok(())
+		}
+
+		/// Count `amount` against the cap and emission schedules of `token_id`, if it has any.
+		fn note_minted(token_id: &T::TokenId, amount: &T::Balance) -> DispatchResult {
+			let mut cap = match Self::get_supply_cap(token_id) {
+				Some(cap) => cap,
+				None => return Ok(()),
+			};
+			let minted = cap.minted.checked_add(amount).ok_or(Error::<T>::Overflow)?;
+			let total = cap.issued_before.checked_add(&minted).ok_or(Error::<T>::Overflow)?;
+			ensure!(total <= cap.max_supply, Error::<T>::MaxSupplyExceeded);
+			let schedules = Self::get_emission_schedules(token_id);
+			if !schedules.is_empty() {
+				let now = frame_system::Pallet::<T>::block_number();
+				let unlocked = schedules.iter().fold(T::Balance::zero(), |unlocked, schedule| {
+					unlocked.saturating_add(schedule.unlocked_at(now))
+				});
+				ensure!(minted <= unlocked, Error::<T>::EmissionScheduleExceeded);
+			}
+			cap.minted = minted;
+			SupplyCaps::<T>::insert(token_id, cap);
+			Ok(())
+		}
+
+		/// The token owner and admins of its collection may configure the token.
+		pub fn ensure_token_issuer(token_id: &T::TokenId, who: &T::AccountId) -> DispatchResult {
+			let token = Self::get_token(*token_id).ok_or(Error::<T>::Uninitilized)?;
+			if token.owner.as_ref() == Some(who) {
+				return Ok(())
+			}
+			Self::ensure_token_admin(token_id, who)
+		}
+
+		/// Issuer attributes belong to the token owner and collection admins, owner attributes to
+		/// the named holder, who must hold the token to write new values.
+		fn ensure_attribute_writer(
+			token_id: &T::TokenId,
+			namespace: &AttributeNamespace<T::AccountId>,
+			who: &T::AccountId,
+			is_write: bool,
+		) -> DispatchResult {
+			match namespace {
+				AttributeNamespace::Issuer => Self::ensure_token_issuer(token_id, who),
+				AttributeNamespace::Owner(owner) => {
+					ensure!(owner == who, Error::<T>::NoPermission);
+					ensure!(
+						!is_write || !Self::get_balance(who, token_id).is_zero(),
+						Error::<T>::NoPermission
+					);
+					Ok(())
+				},
+				AttributeNamespace::Pallet => Err(Error::<T>::NoPermission.into()),
+			}
+		}
+
+		fn add_collection_item(collection_id: &T::CollectionId) -> DispatchResult {
+			Collections::<T>::try_mutate(collection_id, |collection| -> DispatchResult {
+				let collection = collection.as_mut().ok_or(Error::<T>::UnknownCollection)?;
+				let items = collection.items.checked_add(1).ok_or(Error::<T>::Overflow)?;
+				ensure!(
+					collection.max_items.map_or(true, |max_items| items <= max_items),
+					Error::<T>::CollectionFull
+				);
+				collection.items = items;
+				Ok(())
+			})
+		}
+
+		fn transfer_and_notify(
+			operator: &T::AccountId,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			token_id: &T::TokenId,
+			amount: &T::Balance,
+			data: &[u8],
+		) -> Result<T::Balance, DispatchError> {
+			let received = Self::_transfer(from, to, token_id, amount)?;
+			T::OnReceived::on_received(operator, from, to, *token_id, received, data)?;
+			Self::deposit_event(Event::TransferSingle {
+				operator: operator.clone(),
+				from: from.clone(),
+				to: to.clone(),
+				id: *token_id,
+				value: received,
+			});
+			Ok(received)
+		}
+
+		#[transactional]
+		fn _transfer(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			token_id: &T::TokenId,
+			amount: &T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			let min_balance = Self::get_min_balance(token_id);
+			let mut dust = T::Balance::zero();
+			Balances::<T>::try_mutate_exists(
+				&from,
+				&token_id,
+				|balance| -> Result<(), Error<T>> {
+					let current_balance = balance.unwrap_or_default();
+					let mut updated_sender_balance = current_balance
+						.checked_sub(&amount)
+						.ok_or(Error::<T>::InsufficientFunds)?;
+					let locked = Self::locked_balance(from, *token_id);
+					ensure!(updated_sender_balance >= locked, Error::<T>::LiquidityRestrictions);
+					if let Some(min_balance) = &min_balance {
+						if !updated_sender_balance.is_zero() &&
+							updated_sender_balance < min_balance.amount
+						{
+							ensure!(locked.is_zero(), Error::<T>::LiquidityRestrictions);
+							dust = updated_sender_balance;
+							updated_sender_balance = Zero::zero();
+						}
+					}
+					Self::checkpoint_balance(
+						from,
+						*token_id,
+						current_balance,
+						updated_sender_balance,
+					);
+					*balance = Some(updated_sender_balance).filter(|balance| !balance.is_zero());
+					Ok(())
+				},
+			)?;
+			let swept = match &min_balance {
+				Some(MinBalance { dust: DustPolicy::SweepToRecipient, .. }) => dust,
+				_ => Zero::zero(),
+			};
+			let fee = Self::transfer_fee(from, to, token_id, *amount);
+			let fee_amount = fee.as_ref().map(|(_, fee)| *fee).unwrap_or_default();
+			let received = amount
+				.saturating_sub(fee_amount)
+				.checked_add(&swept)
+				.ok_or(Error::<T>::Overflow)?;
+			Self::credit(to, token_id, received)?;
+			if let Some((recipient, fee)) = fee {
+				Self::credit(&recipient, token_id, fee)?;
+				Self::note_holder(&recipient, token_id)?;
+				Self::deposit_event(Event::TransferFeePaid {
+					from: from.clone(),
+					to: to.clone(),
+					token_id: *token_id,
+					fee,
+					received,
+				});
+			}
+			if !swept.is_zero() {
+				Self::deposit_event(Event::DustSwept {
+					from: from.clone(),
+					to: to.clone(),
+					token_id: *token_id,
+					amount: swept,
+				});
+			} else if !dust.is_zero() {
+				Self::burn_dust(from, token_id, dust)?;
+			}
+			Self::note_holder(to, token_id)?;
+			Ok(received)
+		}
+
+		fn credit(
+			who: &T::AccountId,
+			token_id: &T::TokenId,
+			amount: T::Balance,
+		) -> Result<(), Error<T>> {
+			Balances::<T>::try_mutate_exists(who, token_id, |balance| -> Result<(), Error<T>> {
+				let current_balance = balance.unwrap_or_default();
+				let updated_balance =
+					current_balance.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+				Self::ensure_min_balance(token_id, updated_balance)?;
+				Self::checkpoint_balance(who, *token_id, current_balance, updated_balance);
+				*balance = Some(updated_balance).filter(|balance| !balance.is_zero());
+				Ok(())
+			})
+		}
+
+		/// Whether transfers of `token_id` from or to `who` are free of the transfer fee.
+		pub fn is_fee_exempt(token_id: &T::TokenId, who: &T::AccountId) -> bool {
+			T::FeeExempt::contains(who) || FeeExemptions::<T>::contains_key(token_id, who)
+		}
+
+		/// Fee charged on a transfer of `amount` and the account it is paid to, `None` if the
+		/// transfer is free. A fee that would leave the fee recipient below the minimum balance
+		/// of the token is waived.
+		fn transfer_fee(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			token_id: &T::TokenId,
+			amount: T::Balance,
+		) -> Option<(T::AccountId, T::Balance)> {
+			let fee = Self::get_transfer_fee(token_id)?;
+			if [from, to]
+				.iter()
+				.any(|who| *who == &fee.recipient || Self::is_fee_exempt(token_id, who))
+			{
+				return None
+			}
+			let amount = fee.rate.mul_floor(amount);
+			let credited = Self::get_balance(&fee.recipient, token_id).saturating_add(amount);
+			if amount.is_zero() || Self::ensure_min_balance(token_id, credited).is_err() {
+				return None
+			}
+			Some((fee.recipient, amount))
+		}
+
+		/// Nonzero balances must reach the minimum balance of the token.
+		fn ensure_min_balance(token_id: &T::TokenId, balance: T::Balance) -> Result<(), Error<T>> {
+			if let Some(min_balance) = Self::get_min_balance(token_id) {
+				ensure!(
+					balance.is_zero() || balance >= min_balance.amount,
+					Error::<T>::BelowMinBalance
+				);
+			}
+			Ok(())
+		}
+
+		/// Remove `amount` of dust already taken from the balance of `who` from the supply.
+		fn burn_dust(
+			who: &T::AccountId,
+			token_id: &T::TokenId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			Tokens::<T>::try_mutate(token_id, |token| -> DispatchResult {
+				let token = token.as_mut().ok_or(Error::<T>::Uninitilized)?;
+				let updated_supply =
+					token.supply.checked_sub(&amount).ok_or(Error::<T>::InsufficientFunds)?;
+				Self::checkpoint_supply(*token_id, token.supply, updated_supply);
+				token.supply = updated_supply;
 				Ok(())
 			})?;
+			T::OnDust::on_dust(who, *token_id, amount);
+			Self::deposit_event(Event::DustBurned {
+				who: who.clone(),
+				token_id: *token_id,
+				amount,
+			});
 			Ok(())
 		}
 
+		/// Sovereign account of `token_id`, whoever owns the token controls its holdings.
+		pub fn token_account_id(token_id: T::TokenId) -> T::AccountId {
+			T::PalletId::get().into_sub_account(token_id)
+		}
+
+		/// Token `who` is the sovereign account of, if any.
+		pub fn token_of_account(who: &T::AccountId) -> Option<T::TokenId> {
+			PalletId::try_from_sub_account::<T::TokenId>(who)
+				.filter(|(pallet_id, token_id)| {
+					*pallet_id == T::PalletId::get() && &Self::token_account_id(*token_id) == who
+				})
+				.map(|(_, token_id)| token_id)
+		}
+
+		/// Outermost token `token_id` is nested in, `token_id` itself if it is not nested.
+		pub fn root_token(token_id: T::TokenId) -> Result<T::TokenId, DispatchError> {
+			let mut current = token_id;
+			for _ in 0..T::MaxNestingDepth::get() {
+				match Self::get_parent(current) {
+					Some(parent_id) => current = parent_id,
+					None => return Ok(current),
+				}
+			}
+			ensure!(!Parents::<T>::contains_key(current), Error::<T>::NestingTooDeep);
+			Ok(current)
+		}
+
+		fn ensure_token_owner(who: &T::AccountId, token_id: T::TokenId) -> DispatchResult {
+			let root_id = Self::root_token(token_id)?;
+			ensure!(
+				Self::get_total_supply(root_id).is_one() &&
+					Self::get_balance(who, root_id).is_one(),
+				Error::<T>::NoPermission
+			);
+			Ok(())
+		}
+
+		/// Track non-fungible tokens moved in or out of a sovereign account. Only non-fungible
+		/// tokens can hold other tokens and no token may end up inside itself.
+		fn note_holder(to: &T::AccountId, token_id: &T::TokenId) -> DispatchResult {
+			let parent_id = match Self::token_of_account(to) {
+				Some(parent_id) => parent_id,
+				None => {
+					if Self::get_total_supply(*token_id).is_one() {
+						Parents::<T>::remove(token_id);
+					}
+					return Ok(())
+				},
+			};
+			ensure!(Self::get_total_supply(parent_id).is_one(), Error::<T>::InvalidParent);
+			ensure!(&parent_id != token_id, Error::<T>::NestingCycle);
+			let mut depth = 0;
+			let mut ancestor = parent_id;
+			while let Some(next) = Self::get_parent(ancestor) {
+				ensure!(&next != token_id, Error::<T>::NestingCycle);
+				depth += 1;
+				ensure!(depth < T::MaxNestingDepth::get(), Error::<T>::NestingTooDeep);
+				ancestor = next;
+			}
+			if Self::get_total_supply(*token_id).is_one() {
+				Parents::<T>::insert(token_id, parent_id);
+			}
+			Ok(())
+		}
+
+		fn checkpoint_balance(
+			who: &T::AccountId,
+			token_id: T::TokenId,
+			previous: T::Balance,
+			updated: T::Balance,
+		) {
+			if let Some(since) = Self::get_checkpointed_since(token_id) {
+				BalanceCheckpoints::<T>::mutate(who, token_id, |checkpoints| {
+					Self::write_checkpoint(checkpoints, since, previous, updated, |full| {
+						let page = BalanceCheckpointPages::<T>::mutate(who, token_id, |pages| {
+							*pages = pages.saturating_add(1);
+							*pages - 1
+						});
+						ArchivedBalanceCheckpoints::<T>::insert((who, token_id, page), full);
+					})
+				});
+			}
+		}
+
+		fn checkpoint_supply(token_id: T::TokenId, previous: T::Balance, updated: T::Balance) {
+			if let Some(since) = Self::get_checkpointed_since(token_id) {
+				SupplyCheckpoints::<T>::mutate(token_id, |checkpoints| {
+					Self::write_checkpoint(checkpoints, since, previous, updated, |full| {
+						let page = SupplyCheckpointPages::<T>::mutate(token_id, |pages| {
+							*pages = pages.saturating_add(1);
+							*pages - 1
+						});
+						ArchivedSupplyCheckpoints::<T>::insert(token_id, page, full);
+					})
+				});
+			}
+		}
+
+		/// Record `updated` as the value at the end of the current block. The first write after
+		/// checkpointing was enabled also records `previous` as the value at the enabling block.
+		/// Full pages are handed to `archive` before a new page is started.
+		fn write_checkpoint(
+			checkpoints: &mut CheckpointsOf<T>,
+			since: T::BlockNumber,
+			previous: T::Balance,
+			updated: T::Balance,
+			mut archive: impl FnMut(CheckpointsOf<T>),
+		) {
+			let now = frame_system::Pallet::<T>::block_number();
+			match checkpoints.iter_mut().last() {
+				Some((block, value)) if *block == now => {
+					*value = updated;
+					return
+				},
+				None if since < now =>
+					Self::push_checkpoint(checkpoints, (since, previous), &mut archive),
+				_ => {},
+			}
+			Self::push_checkpoint(checkpoints, (now, updated), &mut archive);
+		}
+
+		fn push_checkpoint(
+			checkpoints: &mut CheckpointsOf<T>,
+			checkpoint: (T::BlockNumber, T::Balance),
+			archive: &mut impl FnMut(CheckpointsOf<T>),
+		) {
+			if !checkpoints.is_empty() && checkpoints.len() >= T::MaxCheckpoints::get() as usize {
+				archive(sp_std::mem::take(checkpoints));
+			}
+			let _ = checkpoints.try_push(checkpoint);
+		}
+
+		/// Look up the value at the end of `block`. `Ok(None)` means the value has not changed
+		/// since checkpointing was enabled, so the current value applies. Blocks before the current
+		/// page are looked up in the `pages` archived pages read with `archived`.
+		fn value_at(
+			token_id: &T::TokenId,
+			checkpoints: CheckpointsOf<T>,
+			pages: u32,
+			archived: impl Fn(u32) -> CheckpointsOf<T>,
+			block: T::BlockNumber,
+		) -> Result<Option<T::Balance>, sp_runtime::DispatchError> {
+			let since =
+				Self::get_checkpointed_since(token_id).ok_or(Error::<T>::CheckpointsDisabled)?;
+			ensure!(
+				since <= block && block <= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::CheckpointUnavailable
+			);
+			let archived_block =
+				pages > 0 && checkpoints.first().map_or(false, |(first, _)| block < *first);
+			let checkpoints = if archived_block {
+				// the last archived page starting at or before `block`
+				let (mut low, mut high) = (0, pages);
+				while high - low > 1 {
+					let middle = low + (high - low) / 2;
+					match archived(middle).first() {
+						Some((first, _)) if *first <= block => low = middle,
+						_ => high = middle,
+					}
+				}
+				archived(low)
+			} else {
+				checkpoints
+			};
+			if checkpoints.is_empty() {
+				return Ok(None)
+			}
+			match checkpoints.binary_search_by_key(&block, |(number, _)| *number) {
+				Ok(index) => Ok(Some(checkpoints[index].1)),
+				Err(0) => Err(Error::<T>::CheckpointUnavailable.into()),
+				Err(index) => Ok(Some(checkpoints[index - 1].1)),
+			}
+		}
+
 		fn token_initialized(token_id: &T::TokenId) -> DispatchResult {
 			ensure!(Self::is_initialized(token_id), Error::<T>::Uninitilized);
 			Ok(())
@@ -299,7 +1917,7 @@ pub mod pallet {
 		}
 
 		fn is_initialized(token_id: &T::TokenId) -> bool {
-			!Self::get_total_supply(&token_id).is_zero()
+			Self::get_token(*token_id).is_some()
 		}
 	}
 }
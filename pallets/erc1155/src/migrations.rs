@@ -0,0 +1,62 @@
+use crate::{
+	pallet::{
+		Approval, Config, MigrationInProgress, OperatorApprovals, Pallet, Tokens, TotalSupply,
+	},
+	TokenDetails,
+};
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+/// Flag a pending migration if the on-chain storage version is behind. The migration itself runs
+/// in bounded steps from `on_initialize`, see [`step`].
+pub fn on_runtime_upgrade<T: Config>() -> Weight {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	if on_chain < Pallet::<T>::current_storage_version() && !MigrationInProgress::<T>::get() {
+		MigrationInProgress::<T>::put(true);
+		return T::DbWeight::get().reads_writes(2, 1)
+	}
+	T::DbWeight::get().reads(2)
+}
+
+/// Migrate at most `max_items` entries towards the next storage version.
+pub fn step<T: Config>(max_items: u32) -> Weight {
+	let on_chain = Pallet::<T>::on_chain_storage_version();
+	let weight = T::DbWeight::get().reads(1);
+	if on_chain < 1 {
+		weight.saturating_add(v1::step::<T>(max_items))
+	} else {
+		MigrationInProgress::<T>::kill();
+		weight.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Move boolean `Approval` entries into `OperatorApprovals`, dropping revoked ones, and
+	/// `TotalSupply` entries into `Tokens` records. Drained legacy entries are removed, so every
+	/// step resumes where the previous one stopped.
+	pub fn step<T: Config>(max_items: u32) -> Weight {
+		let mut remaining = max_items as usize;
+		for (owner, operator, approved) in Approval::<T>::drain().take(remaining) {
+			if approved {
+				OperatorApprovals::<T>::insert(&owner, &operator, ());
+			}
+			remaining -= 1;
+		}
+		for (token_id, supply) in TotalSupply::<T>::drain().take(remaining) {
+			Tokens::<T>::insert(token_id, TokenDetails { owner: None, supply });
+			remaining -= 1;
+		}
+		let migrated = (max_items as usize - remaining) as Weight;
+		let mut weight = T::DbWeight::get().reads_writes(migrated + 1, migrated * 2);
+		if remaining > 0 {
+			StorageVersion::new(1).put::<Pallet<T>>();
+			MigrationInProgress::<T>::kill();
+			weight = weight.saturating_add(T::DbWeight::get().writes(2));
+		}
+		weight
+	}
+}
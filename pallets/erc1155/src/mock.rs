@@ -1,11 +1,14 @@
 use crate as pallet_erc1155;
-use frame_support::parameter_types;
+use frame_support::{ensure, parameter_types, BoundedVec, PalletId};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	DispatchError, DispatchResult,
 };
+use sp_std::cell::RefCell;
+use traits::{OnErc1155Dust, OnErc1155Received};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -18,6 +21,7 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Erc1155: pallet_erc1155::{Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -31,21 +35,21 @@ impl system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = frame_support::weights::constants::RocksDbWeight;
 	type Origin = Origin;
 	type Call = Call;
 	type Index = u64;
 	type BlockNumber = u64;
 	type Hash = H256;
 	type Hashing = BlakeTwo256;
-	type AccountId = u64;
+	type AccountId = u128;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Header = Header;
 	type Event = Event;
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u128>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -53,18 +57,117 @@ impl system::Config for Test {
 	type OnSetCode = ();
 }
 
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u128;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	pub const Decimals: u32 = 6;
+	pub const MaxCheckpoints: u32 = 4;
+	pub const MigrationItemsPerBlock: u32 = 2;
+	pub const MaxBatchSize: u32 = 10;
+	pub const MaxDataLength: u32 = 32;
+	pub const StringLimit: u32 = 50;
+	pub const AttributeDepositBase: u128 = 10;
+	pub const DepositPerByte: u128 = 1;
+	pub const KeyLimit: u32 = 16;
+	pub const ValueLimit: u32 = 32;
+	pub const MaxEmissionSchedules: u32 = 4;
+	pub const MaxLocks: u32 = 4;
+	pub const Erc1155PalletId: PalletId = PalletId(*b"py/e1155");
+	pub const MaxNestingDepth: u32 = 3;
+}
+
+pub const REJECTING_RECEIVER: u128 = 99;
+
+thread_local! {
+	static RECEIVED_DATA: RefCell<Vec<Vec<u8>>> = RefCell::new(Vec::new());
+	static DUST: RefCell<Vec<(u128, u32, u128)>> = RefCell::new(Vec::new());
+}
+
+pub fn received_data() -> Vec<Vec<u8>> {
+	RECEIVED_DATA.with(|data| data.borrow().clone())
+}
+
+pub struct MockReceiver;
+impl OnErc1155Received<u128, u32, u128> for MockReceiver {
+	fn on_received(
+		_operator: &u128,
+		_from: &u128,
+		to: &u128,
+		_token_id: u32,
+		_amount: u128,
+		data: &[u8],
+	) -> DispatchResult {
+		ensure!(*to != REJECTING_RECEIVER, DispatchError::Other("transfer rejected by receiver"));
+		RECEIVED_DATA.with(|received| received.borrow_mut().push(data.to_vec()));
+		Ok(())
+	}
+
+	fn on_received_weight(count: u32) -> u64 {
+		1_000 * count as u64
+	}
+}
+
+pub fn dust() -> Vec<(u128, u32, u128)> {
+	DUST.with(|dust| dust.borrow().clone())
+}
+
+pub struct MockDustHandler;
+impl OnErc1155Dust<u128, u32, u128> for MockDustHandler {
+	fn on_dust(who: &u128, token_id: u32, amount: u128) {
+		DUST.with(|dust| dust.borrow_mut().push((*who, token_id, amount)));
+	}
 }
 
 impl pallet_erc1155::Config for Test {
 	type Event = Event;
 	type TokenId = u32;
 	type Balance = u128;
+	type CollectionId = u32;
 	type Decimals = Decimals;
+	type ForceOrigin = frame_system::EnsureRoot<u128>;
+	type MaxCheckpoints = MaxCheckpoints;
+	type MigrationItemsPerBlock = MigrationItemsPerBlock;
+	type MaxBatchSize = MaxBatchSize;
+	type MaxDataLength = MaxDataLength;
+	type StringLimit = StringLimit;
+	type OnReceived = MockReceiver;
+	type Currency = Balances;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type MaxEmissionSchedules = MaxEmissionSchedules;
+	type MaxLocks = MaxLocks;
+	type PalletId = Erc1155PalletId;
+	type MaxNestingDepth = MaxNestingDepth;
+	type OnDust = MockDustHandler;
+	type FeeExempt = frame_support::traits::Nothing;
+	type WeightInfo = ();
+}
+
+pub fn bounded<T>(items: Vec<T>) -> BoundedVec<T, MaxBatchSize> {
+	items.try_into().unwrap()
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 1000), (2, 1000), (3, 1000)] }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+	storage.into()
 }
@@ -1,21 +1,38 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	mock::*,
+	pallet::{Approval, Balances as TokenBalances, OperatorApprovals, Tokens, TotalSupply},
+	CollectionDetails, DustPolicy, EmissionSchedule, Error, MinBalance, SupplyCap, TokenDetails,
+	TransferFee,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Get, GetStorageVersion, Hooks, StorageVersion},
+	weights::{GetDispatchInfo, RuntimeDbWeight},
+};
+use sp_runtime::{DispatchError, Perbill};
+use traits::{
+	AttributeNamespace, Erc1155 as _, Erc1155Attributes, Erc1155Checkpoints, Erc1155Lockable,
+	Erc1155Mintable, Erc1155Royalties,
+};
 
 const TOKEN_0_ID: u32 = 1;
 const TOKEN_1_ID: u32 = 2;
 
-const ALICE: u64 = 1;
-const BOB: u64 = 2;
+const ALICE: u128 = 1;
+const BOB: u128 = 2;
+const CHARLIE: u128 = 3;
 
 const MIL: u128 = (10 as u128).pow(6);
 
+const COLLECTION_ID: u32 = 7;
+
 #[test]
 fn init_should_work_1() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Erc1155::init(
 			Origin::signed(ALICE),
-			vec![TOKEN_0_ID, TOKEN_1_ID],
-			vec![1000, 1000]
+			bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+			bounded(vec![1000, 1000])
 		));
 		assert_eq!(Erc1155::get_balance(ALICE, TOKEN_0_ID), 1000 * MIL);
 		assert_eq!(Erc1155::get_total_supply(TOKEN_0_ID), 1000 * MIL);
@@ -27,8 +44,16 @@ fn init_should_work_1() {
 #[test]
 fn init_should_work_2() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Erc1155::init(Origin::signed(ALICE), vec![TOKEN_0_ID], vec![1000]));
-		assert_ok!(Erc1155::init(Origin::signed(BOB), vec![TOKEN_1_ID], vec![1000]));
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		assert_ok!(Erc1155::init(
+			Origin::signed(BOB),
+			bounded(vec![TOKEN_1_ID]),
+			bounded(vec![1000])
+		));
 		assert_eq!(Erc1155::get_balance(ALICE, TOKEN_0_ID), 1000 * MIL);
 		assert_eq!(Erc1155::get_total_supply(TOKEN_0_ID), 1000 * MIL);
 		assert_eq!(Erc1155::get_balance(BOB, TOKEN_1_ID), 1000 * MIL);
@@ -41,11 +66,11 @@ fn init_should_fail_1() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Erc1155::init(
 			Origin::signed(ALICE),
-			vec![TOKEN_0_ID, TOKEN_1_ID],
-			vec![1000, 1000]
+			bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+			bounded(vec![1000, 1000])
 		));
 		assert_noop!(
-			Erc1155::init(Origin::signed(ALICE), vec![TOKEN_0_ID], vec![1000]),
+			Erc1155::init(Origin::signed(ALICE), bounded(vec![TOKEN_0_ID]), bounded(vec![1000])),
 			Error::<Test>::AlreadyInitialized
 		);
 	});
@@ -55,7 +80,11 @@ fn init_should_fail_1() {
 fn init_should_fail_2() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			Erc1155::init(Origin::signed(ALICE), vec![TOKEN_0_ID, TOKEN_1_ID], vec![1000, 0]),
+			Erc1155::init(
+				Origin::signed(ALICE),
+				bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+				bounded(vec![1000, 0])
+			),
 			Error::<Test>::ZeroSupplyProvided
 		);
 	});
@@ -65,11 +94,19 @@ fn init_should_fail_2() {
 fn init_should_fail_3() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			Erc1155::init(Origin::signed(ALICE), vec![TOKEN_0_ID, TOKEN_1_ID], vec![1000]),
+			Erc1155::init(
+				Origin::signed(ALICE),
+				bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+				bounded(vec![1000])
+			),
 			Error::<Test>::InsufficientDataProvided
 		);
 		assert_noop!(
-			Erc1155::init(Origin::signed(ALICE), vec![TOKEN_1_ID], vec![1000, 100]),
+			Erc1155::init(
+				Origin::signed(ALICE),
+				bounded(vec![TOKEN_1_ID]),
+				bounded(vec![1000, 100])
+			),
 			Error::<Test>::InsufficientDataProvided
 		);
 	});
@@ -79,7 +116,11 @@ fn init_should_fail_3() {
 fn init_should_fail_4() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			Erc1155::init(Origin::signed(ALICE), vec![TOKEN_0_ID], vec![u128::MAX]),
+			Erc1155::init(
+				Origin::signed(ALICE),
+				bounded(vec![TOKEN_0_ID]),
+				bounded(vec![u128::MAX])
+			),
 			Error::<Test>::Overflow
 		);
 	});
@@ -88,25 +129,34 @@ fn init_should_fail_4() {
 #[test]
 fn set_approval_for_all_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Erc1155::init(Origin::signed(ALICE), vec![TOKEN_0_ID], vec![1000]));
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
 		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, true));
-		assert_eq!(Erc1155::get_approval(ALICE, BOB), true);
+		assert_eq!(Erc1155::get_approval(&ALICE, &BOB), true);
 		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, false));
-		assert_eq!(Erc1155::get_approval(ALICE, BOB), false);
+		assert_eq!(Erc1155::get_approval(&ALICE, &BOB), false);
 	});
 }
 
 #[test]
 fn transfer_from_single_should_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Erc1155::init(Origin::signed(ALICE), vec![TOKEN_0_ID], vec![1000]));
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
 		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, true));
 		assert_ok!(Erc1155::transfer_from_single(
 			Origin::signed(ALICE),
 			ALICE,
 			BOB,
 			TOKEN_0_ID,
-			100 * MIL
+			100 * MIL,
+			Default::default()
 		));
 		assert_eq!(Erc1155::get_balance(ALICE, TOKEN_0_ID), 900 * MIL);
 		assert_eq!(Erc1155::get_balance(BOB, TOKEN_0_ID), 100 * MIL);
@@ -116,9 +166,22 @@ fn transfer_from_single_should_work() {
 #[test]
 fn transfer_from_single_should_fail_1() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Erc1155::init(Origin::signed(ALICE), vec![TOKEN_0_ID], vec![1000]));
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		// approving the recipient does not let anyone else move the tokens
+		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, true));
 		assert_noop!(
-			Erc1155::transfer_from_single(Origin::signed(ALICE), ALICE, BOB, TOKEN_0_ID, 100 * MIL),
+			Erc1155::transfer_from_single(
+				Origin::signed(CHARLIE),
+				ALICE,
+				BOB,
+				TOKEN_0_ID,
+				100 * MIL,
+				Default::default()
+			),
 			Error::<Test>::TransferNotApproved
 		);
 	});
@@ -127,9 +190,20 @@ fn transfer_from_single_should_fail_1() {
 #[test]
 fn transfer_from_single_should_fail_2() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Erc1155::init(Origin::signed(ALICE), vec![TOKEN_0_ID], vec![1000]));
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
 		assert_noop!(
-			Erc1155::transfer_from_single(Origin::signed(ALICE), ALICE, BOB, TOKEN_0_ID, 0 * MIL),
+			Erc1155::transfer_from_single(
+				Origin::signed(ALICE),
+				ALICE,
+				BOB,
+				TOKEN_0_ID,
+				0 * MIL,
+				Default::default()
+			),
 			Error::<Test>::ZeroAmountTransfer
 		);
 	});
@@ -138,9 +212,20 @@ fn transfer_from_single_should_fail_2() {
 #[test]
 fn transfer_from_single_should_fail_3() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Erc1155::init(Origin::signed(ALICE), vec![TOKEN_0_ID], vec![1000]));
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
 		assert_noop!(
-			Erc1155::transfer_from_single(Origin::signed(ALICE), ALICE, ALICE, TOKEN_0_ID, 1 * MIL),
+			Erc1155::transfer_from_single(
+				Origin::signed(ALICE),
+				ALICE,
+				ALICE,
+				TOKEN_0_ID,
+				1 * MIL,
+				Default::default()
+			),
 			Error::<Test>::SelfTransfer
 		);
 	});
@@ -149,10 +234,21 @@ fn transfer_from_single_should_fail_3() {
 #[test]
 fn transfer_from_single_should_fail_4() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Erc1155::init(Origin::signed(ALICE), vec![TOKEN_0_ID], vec![1000]));
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
 		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), 0, true));
 		assert_noop!(
-			Erc1155::transfer_from_single(Origin::signed(ALICE), ALICE, 0, TOKEN_0_ID, 1 * MIL),
+			Erc1155::transfer_from_single(
+				Origin::signed(ALICE),
+				ALICE,
+				0,
+				TOKEN_0_ID,
+				1 * MIL,
+				Default::default()
+			),
 			Error::<Test>::ZeroAdressTransfer
 		);
 	});
@@ -161,7 +257,11 @@ fn transfer_from_single_should_fail_4() {
 #[test]
 fn transfer_from_single_should_fail_5() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Erc1155::init(Origin::signed(ALICE), vec![TOKEN_0_ID], vec![1000]));
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
 		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, true));
 		assert_noop!(
 			Erc1155::transfer_from_single(
@@ -169,7 +269,8 @@ fn transfer_from_single_should_fail_5() {
 				ALICE,
 				BOB,
 				TOKEN_0_ID,
-				1001 * MIL
+				1001 * MIL,
+				Default::default()
 			),
 			Error::<Test>::InsufficientFunds
 		);
@@ -179,10 +280,21 @@ fn transfer_from_single_should_fail_5() {
 #[test]
 fn transfer_from_single_should_fail_6() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Erc1155::init(Origin::signed(ALICE), vec![TOKEN_0_ID], vec![1000]));
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
 		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, true));
 		assert_noop!(
-			Erc1155::transfer_from_single(Origin::signed(ALICE), ALICE, BOB, TOKEN_1_ID, 50 * MIL),
+			Erc1155::transfer_from_single(
+				Origin::signed(ALICE),
+				ALICE,
+				BOB,
+				TOKEN_1_ID,
+				50 * MIL,
+				Default::default()
+			),
 			Error::<Test>::Uninitilized
 		);
 	});
@@ -193,16 +305,17 @@ fn transfer_from_batch_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Erc1155::init(
 			Origin::signed(ALICE),
-			vec![TOKEN_0_ID, TOKEN_1_ID],
-			vec![1000, 1000]
+			bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+			bounded(vec![1000, 1000])
 		));
 		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, true));
 		assert_ok!(Erc1155::transfer_from_batch(
 			Origin::signed(ALICE),
 			ALICE,
 			BOB,
-			vec![TOKEN_0_ID, TOKEN_1_ID],
-			vec![900 * MIL, 50 * MIL]
+			bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+			bounded(vec![900 * MIL, 50 * MIL]),
+			Default::default()
 		));
 		assert_eq!(Erc1155::get_balance(ALICE, TOKEN_0_ID), 100 * MIL);
 		assert_eq!(Erc1155::get_balance(ALICE, TOKEN_1_ID), 950 * MIL);
@@ -216,16 +329,18 @@ fn transfer_from_batch_should_fail_1() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Erc1155::init(
 			Origin::signed(ALICE),
-			vec![TOKEN_0_ID, TOKEN_1_ID],
-			vec![1000, 1000]
+			bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+			bounded(vec![1000, 1000])
 		));
+		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, true));
 		assert_noop!(
 			Erc1155::transfer_from_batch(
-				Origin::signed(ALICE),
+				Origin::signed(CHARLIE),
 				ALICE,
 				BOB,
-				vec![TOKEN_0_ID, TOKEN_1_ID],
-				vec![900 * MIL, 50 * MIL]
+				bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+				bounded(vec![900 * MIL, 50 * MIL]),
+				Default::default()
 			),
 			Error::<Test>::TransferNotApproved
 		);
@@ -237,8 +352,8 @@ fn transfer_from_batch_should_fail_2() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Erc1155::init(
 			Origin::signed(ALICE),
-			vec![TOKEN_0_ID, TOKEN_1_ID],
-			vec![1000, 1000]
+			bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+			bounded(vec![1000, 1000])
 		));
 		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, true));
 		assert_noop!(
@@ -246,8 +361,9 @@ fn transfer_from_batch_should_fail_2() {
 				Origin::signed(ALICE),
 				ALICE,
 				BOB,
-				vec![TOKEN_0_ID, TOKEN_1_ID],
-				vec![900 * MIL, 0]
+				bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+				bounded(vec![900 * MIL, 0]),
+				Default::default()
 			),
 			Error::<Test>::ZeroAmountTransfer
 		);
@@ -259,8 +375,8 @@ fn transfer_from_batch_should_fail_3() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Erc1155::init(
 			Origin::signed(ALICE),
-			vec![TOKEN_0_ID, TOKEN_1_ID],
-			vec![1000, 1000]
+			bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+			bounded(vec![1000, 1000])
 		));
 		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, true));
 		assert_noop!(
@@ -268,8 +384,9 @@ fn transfer_from_batch_should_fail_3() {
 				Origin::signed(ALICE),
 				ALICE,
 				ALICE,
-				vec![TOKEN_0_ID, TOKEN_1_ID],
-				vec![900 * MIL, 50 * MIL]
+				bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+				bounded(vec![900 * MIL, 50 * MIL]),
+				Default::default()
 			),
 			Error::<Test>::SelfTransfer
 		);
@@ -281,8 +398,8 @@ fn transfer_from_batch_should_fail_4() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Erc1155::init(
 			Origin::signed(ALICE),
-			vec![TOKEN_0_ID, TOKEN_1_ID],
-			vec![1000, 1000]
+			bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+			bounded(vec![1000, 1000])
 		));
 		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), 0, true));
 		assert_noop!(
@@ -290,8 +407,9 @@ fn transfer_from_batch_should_fail_4() {
 				Origin::signed(ALICE),
 				ALICE,
 				0,
-				vec![TOKEN_0_ID, TOKEN_1_ID],
-				vec![900 * MIL, 50 * MIL]
+				bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+				bounded(vec![900 * MIL, 50 * MIL]),
+				Default::default()
 			),
 			Error::<Test>::ZeroAdressTransfer
 		);
@@ -303,8 +421,8 @@ fn transfer_from_batch_should_fail_5() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Erc1155::init(
 			Origin::signed(ALICE),
-			vec![TOKEN_0_ID, TOKEN_1_ID],
-			vec![1000, 1000]
+			bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+			bounded(vec![1000, 1000])
 		));
 		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, true));
 		assert_noop!(
@@ -312,8 +430,9 @@ fn transfer_from_batch_should_fail_5() {
 				Origin::signed(ALICE),
 				ALICE,
 				BOB,
-				vec![TOKEN_0_ID, TOKEN_1_ID],
-				vec![1001 * MIL, 50 * MIL]
+				bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+				bounded(vec![1001 * MIL, 50 * MIL]),
+				Default::default()
 			),
 			Error::<Test>::InsufficientFunds
 		);
@@ -323,17 +442,1445 @@ fn transfer_from_batch_should_fail_5() {
 #[test]
 fn transfer_from_batch_should_fail_6() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Erc1155::init(Origin::signed(ALICE), vec![TOKEN_0_ID], vec![1000]));
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
 		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, true));
 		assert_noop!(
 			Erc1155::transfer_from_batch(
 				Origin::signed(ALICE),
 				ALICE,
 				BOB,
-				vec![TOKEN_0_ID, TOKEN_1_ID],
-				vec![100 * MIL, 50 * MIL]
+				bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+				bounded(vec![100 * MIL, 50 * MIL]),
+				Default::default()
 			),
 			Error::<Test>::Uninitilized
 		);
 	});
 }
+
+fn transfer_at(block: u64, from: u128, to: u128, amount: u128) {
+	System::set_block_number(block);
+	assert_ok!(Erc1155::transfer_from_single(
+		Origin::signed(from),
+		from,
+		to,
+		TOKEN_0_ID,
+		amount,
+		Default::default()
+	));
+}
+
+#[test]
+fn enable_checkpoints_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(2);
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		assert_ok!(Erc1155::enable_checkpoints(Origin::root(), TOKEN_0_ID));
+		assert_eq!(Erc1155::get_checkpointed_since(TOKEN_0_ID), Some(2));
+	});
+}
+
+#[test]
+fn enable_checkpoints_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Erc1155::enable_checkpoints(Origin::root(), TOKEN_0_ID),
+			Error::<Test>::Uninitilized
+		);
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		assert_noop!(
+			Erc1155::enable_checkpoints(Origin::signed(ALICE), TOKEN_0_ID),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Erc1155::enable_checkpoints(Origin::root(), TOKEN_0_ID));
+		assert_noop!(
+			Erc1155::enable_checkpoints(Origin::root(), TOKEN_0_ID),
+			Error::<Test>::CheckpointsAlreadyEnabled
+		);
+	});
+}
+
+#[test]
+fn balance_of_at_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, true));
+		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(BOB), ALICE, true));
+		System::set_block_number(2);
+		assert_ok!(Erc1155::enable_checkpoints(Origin::root(), TOKEN_0_ID));
+		transfer_at(4, ALICE, BOB, 100 * MIL);
+		transfer_at(4, ALICE, BOB, 100 * MIL);
+		transfer_at(6, BOB, ALICE, 50 * MIL);
+		System::set_block_number(8);
+
+		assert_eq!(Erc1155::balance_of_at(&ALICE, TOKEN_0_ID, 2), Ok(1000 * MIL));
+		assert_eq!(Erc1155::balance_of_at(&ALICE, TOKEN_0_ID, 3), Ok(1000 * MIL));
+		assert_eq!(Erc1155::balance_of_at(&ALICE, TOKEN_0_ID, 4), Ok(800 * MIL));
+		assert_eq!(Erc1155::balance_of_at(&ALICE, TOKEN_0_ID, 6), Ok(850 * MIL));
+		assert_eq!(Erc1155::balance_of_at(&ALICE, TOKEN_0_ID, 8), Ok(850 * MIL));
+		assert_eq!(Erc1155::balance_of_at(&BOB, TOKEN_0_ID, 3), Ok(0));
+		assert_eq!(Erc1155::balance_of_at(&BOB, TOKEN_0_ID, 5), Ok(200 * MIL));
+		assert_eq!(Erc1155::balance_of_at(&BOB, TOKEN_0_ID, 7), Ok(150 * MIL));
+		assert_eq!(Erc1155::balance_of_at(&CHARLIE, TOKEN_0_ID, 7), Ok(0));
+		assert_eq!(Erc1155::total_supply_at(TOKEN_0_ID, 5), Ok(1000 * MIL));
+	});
+}
+
+#[test]
+fn balance_of_at_should_fail() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+			bounded(vec![1000, 1000])
+		));
+		assert_eq!(
+			Erc1155::balance_of_at(&ALICE, TOKEN_1_ID, 1),
+			Err(Error::<Test>::CheckpointsDisabled.into())
+		);
+		System::set_block_number(2);
+		assert_ok!(Erc1155::enable_checkpoints(Origin::root(), TOKEN_0_ID));
+		assert_eq!(
+			Erc1155::balance_of_at(&ALICE, TOKEN_0_ID, 1),
+			Err(Error::<Test>::CheckpointUnavailable.into())
+		);
+		assert_eq!(
+			Erc1155::balance_of_at(&ALICE, TOKEN_0_ID, 3),
+			Err(Error::<Test>::CheckpointUnavailable.into())
+		);
+	});
+}
+
+#[test]
+fn checkpoints_should_be_archived() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		assert_ok!(Erc1155::enable_checkpoints(Origin::root(), TOKEN_0_ID));
+		// transfers to an account can't push its history out
+		for block in 2..=10 {
+			transfer_at(block, ALICE, BOB, 100 * MIL);
+		}
+		assert_eq!(Erc1155::get_balance_checkpoints(ALICE, TOKEN_0_ID).len(), 2);
+		assert_eq!(Erc1155::get_balance_checkpoint_pages(ALICE, TOKEN_0_ID), 2);
+		assert_eq!(Erc1155::get_balance_checkpoint_pages(BOB, TOKEN_0_ID), 2);
+		assert_eq!(Erc1155::balance_of_at(&ALICE, TOKEN_0_ID, 1), Ok(1000 * MIL));
+		assert_eq!(Erc1155::balance_of_at(&ALICE, TOKEN_0_ID, 3), Ok(800 * MIL));
+		assert_eq!(Erc1155::balance_of_at(&ALICE, TOKEN_0_ID, 6), Ok(500 * MIL));
+		assert_eq!(Erc1155::balance_of_at(&ALICE, TOKEN_0_ID, 9), Ok(200 * MIL));
+		assert_eq!(Erc1155::balance_of_at(&ALICE, TOKEN_0_ID, 10), Ok(100 * MIL));
+		assert_eq!(Erc1155::balance_of_at(&BOB, TOKEN_0_ID, 1), Ok(0));
+		assert_eq!(Erc1155::balance_of_at(&BOB, TOKEN_0_ID, 4), Ok(300 * MIL));
+		assert_eq!(Erc1155::total_supply_at(TOKEN_0_ID, 1), Ok(1000 * MIL));
+	});
+}
+
+fn insert_legacy_state() {
+	StorageVersion::new(0).put::<Erc1155>();
+	TotalSupply::<Test>::insert(TOKEN_0_ID, 1000 * MIL);
+	TotalSupply::<Test>::insert(TOKEN_1_ID, 500 * MIL);
+	crate::pallet::Balances::<Test>::insert(ALICE, TOKEN_0_ID, 1000 * MIL);
+	crate::pallet::Balances::<Test>::insert(BOB, TOKEN_1_ID, 500 * MIL);
+	Approval::<Test>::insert(ALICE, BOB, true);
+	Approval::<Test>::insert(BOB, ALICE, true);
+	Approval::<Test>::insert(BOB, CHARLIE, false);
+}
+
+#[test]
+fn migration_to_v1_should_work() {
+	new_test_ext().execute_with(|| {
+		insert_legacy_state();
+		let db_weight: RuntimeDbWeight = <Test as frame_system::Config>::DbWeight::get();
+
+		assert_eq!(Erc1155::on_runtime_upgrade(), db_weight.reads_writes(2, 1));
+		assert!(Erc1155::get_migration_in_progress());
+
+		// Two approvals are migrated per block, legacy entries stay readable meanwhile.
+		assert_eq!(Erc1155::on_initialize(1), db_weight.reads_writes(5, 4));
+		assert_eq!(Approval::<Test>::iter().count(), 1);
+		assert!(Erc1155::get_approval(&ALICE, &BOB));
+		assert!(Erc1155::get_approval(&BOB, &ALICE));
+		assert_eq!(Erc1155::get_total_supply(TOKEN_0_ID), 1000 * MIL);
+		assert_ok!(Erc1155::transfer_from_single(
+			Origin::signed(ALICE),
+			ALICE,
+			BOB,
+			TOKEN_0_ID,
+			100 * MIL,
+			Default::default()
+		));
+
+		Erc1155::on_initialize(2);
+		Erc1155::on_initialize(3);
+		assert!(!Erc1155::get_migration_in_progress());
+		assert_eq!(Erc1155::on_chain_storage_version(), 1);
+		assert_eq!(Approval::<Test>::iter().count(), 0);
+		assert_eq!(TotalSupply::<Test>::iter().count(), 0);
+		assert_eq!(OperatorApprovals::<Test>::iter().count(), 2);
+		assert!(!Erc1155::get_approval(&BOB, &CHARLIE));
+		assert_eq!(
+			Tokens::<Test>::get(TOKEN_0_ID),
+			Some(TokenDetails { owner: None, supply: 1000 * MIL })
+		);
+		assert_eq!(
+			Tokens::<Test>::get(TOKEN_1_ID),
+			Some(TokenDetails { owner: None, supply: 500 * MIL })
+		);
+		assert_eq!(Erc1155::get_balance(BOB, TOKEN_0_ID), 100 * MIL);
+
+		assert_eq!(Erc1155::on_initialize(4), db_weight.reads(1));
+		assert_eq!(Erc1155::on_runtime_upgrade(), db_weight.reads(2));
+	});
+}
+
+#[test]
+fn init_should_record_token_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		assert_eq!(
+			Erc1155::get_token(TOKEN_0_ID),
+			Some(TokenDetails { owner: Some(ALICE), supply: 1000 * MIL })
+		);
+		assert_eq!(TotalSupply::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn transfer_from_batch_weight_should_scale_with_batch_size() {
+	let batch_weight = |size: u32| {
+		crate::Call::<Test>::transfer_from_batch {
+			from: ALICE,
+			to: BOB,
+			token_ids: bounded((0..size).collect()),
+			amounts: bounded((0..size).map(|_| MIL).collect()),
+			data: Default::default(),
+		}
+		.get_dispatch_info()
+		.weight
+	};
+	assert!(batch_weight(1) < batch_weight(2));
+	assert!(batch_weight(10) > batch_weight(1) * 5);
+}
+
+#[test]
+fn transfer_weight_should_include_nesting_walk_and_receiver_hook() {
+	let weight = crate::Call::<Test>::transfer_from_single {
+		from: ALICE,
+		to: BOB,
+		token_id: TOKEN_0_ID,
+		amount: MIL,
+		data: Default::default(),
+	}
+	.get_dispatch_info()
+	.weight;
+	assert_eq!(
+		weight,
+		<() as crate::WeightInfo>::transfer_from_single(MaxNestingDepth::get()) + 1_000
+	);
+	assert!(weight > <() as crate::WeightInfo>::transfer_from_single(0) + 1_000);
+}
+
+#[test]
+fn transfer_from_single_should_emit_event_and_notify_receiver() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), CHARLIE, true));
+		assert_ok!(Erc1155::transfer_from_single(
+			Origin::signed(CHARLIE),
+			ALICE,
+			BOB,
+			TOKEN_0_ID,
+			100 * MIL,
+			b"order-42".to_vec().try_into().unwrap()
+		));
+		System::assert_last_event(Event::Erc1155(crate::Event::TransferSingle {
+			operator: CHARLIE,
+			from: ALICE,
+			to: BOB,
+			id: TOKEN_0_ID,
+			value: 100 * MIL,
+		}));
+		assert_eq!(received_data(), vec![b"order-42".to_vec()]);
+	});
+}
+
+#[test]
+fn transfer_from_batch_should_emit_event_and_notify_receiver() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+			bounded(vec![1000, 1000])
+		));
+		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, true));
+		assert_ok!(Erc1155::transfer_from_batch(
+			Origin::signed(ALICE),
+			ALICE,
+			BOB,
+			bounded(vec![TOKEN_0_ID, TOKEN_1_ID]),
+			bounded(vec![10 * MIL, 20 * MIL]),
+			b"batch".to_vec().try_into().unwrap()
+		));
+		System::assert_last_event(Event::Erc1155(crate::Event::TransferBatch {
+			operator: ALICE,
+			from: ALICE,
+			to: BOB,
+			ids: vec![TOKEN_0_ID, TOKEN_1_ID],
+			values: vec![10 * MIL, 20 * MIL],
+		}));
+		assert_eq!(received_data(), vec![b"batch".to_vec(), b"batch".to_vec()]);
+	});
+}
+
+#[test]
+fn transfer_rejected_by_receiver_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), REJECTING_RECEIVER, true));
+		assert_noop!(
+			Erc1155::transfer_from_single(
+				Origin::signed(ALICE),
+				ALICE,
+				REJECTING_RECEIVER,
+				TOKEN_0_ID,
+				100 * MIL,
+				Default::default()
+			),
+			DispatchError::Other("transfer rejected by receiver")
+		);
+	});
+}
+
+fn create_collection(max_items: Option<u32>) {
+	assert_ok!(Erc1155::create_collection(
+		Origin::signed(ALICE),
+		COLLECTION_ID,
+		max_items,
+		Perbill::from_percent(5)
+	));
+	assert_ok!(Erc1155::set_collection_admin(Origin::signed(ALICE), COLLECTION_ID, BOB, true));
+}
+
+#[test]
+fn create_collection_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_collection(Some(2));
+		assert_eq!(
+			Erc1155::get_collection(COLLECTION_ID),
+			Some(CollectionDetails {
+				owner: ALICE,
+				max_items: Some(2),
+				items: 0,
+				default_royalty: Perbill::from_percent(5),
+			})
+		);
+		assert!(Erc1155::is_collection_admin(COLLECTION_ID, &ALICE));
+		assert!(Erc1155::is_collection_admin(COLLECTION_ID, &BOB));
+		assert!(!Erc1155::is_collection_admin(COLLECTION_ID, &CHARLIE));
+		System::assert_last_event(Event::Erc1155(crate::Event::CollectionAdminSet {
+			collection_id: COLLECTION_ID,
+			admin: BOB,
+			is_admin: true,
+		}));
+	});
+}
+
+#[test]
+fn royalty_of_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_collection(None);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 1));
+		assert_eq!(Erc1155::royalty_of(TOKEN_0_ID), Some((ALICE, Perbill::from_percent(5))));
+		assert_eq!(Erc1155::royalty_of(TOKEN_1_ID), None);
+	});
+}
+
+#[test]
+fn is_issuer_should_work() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 1));
+		assert!(Erc1155::is_issuer(&ALICE, TOKEN_0_ID));
+		assert!(Erc1155::is_issuer(&BOB, TOKEN_0_ID));
+		assert!(!Erc1155::is_issuer(&CHARLIE, TOKEN_0_ID));
+		assert!(!Erc1155::is_issuer(&CHARLIE, TOKEN_1_ID));
+		assert_ok!(<Erc1155 as Erc1155Mintable<u128>>::create(&CHARLIE, TOKEN_1_ID));
+		assert!(Erc1155::is_issuer(&CHARLIE, TOKEN_1_ID));
+	});
+}
+
+#[test]
+fn create_collection_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		assert_noop!(
+			Erc1155::create_collection(
+				Origin::signed(CHARLIE),
+				COLLECTION_ID,
+				None,
+				Perbill::zero()
+			),
+			Error::<Test>::CollectionAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn set_collection_admin_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		// admins cannot appoint other admins
+		assert_noop!(
+			Erc1155::set_collection_admin(Origin::signed(BOB), COLLECTION_ID, CHARLIE, true),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn set_collection_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		assert_ok!(Erc1155::set_collection_metadata(
+			Origin::signed(BOB),
+			COLLECTION_ID,
+			b"ipfs://collection".to_vec().try_into().unwrap()
+		));
+		assert_eq!(Erc1155::get_collection_metadata(COLLECTION_ID).to_vec(), b"ipfs://collection");
+		assert_noop!(
+			Erc1155::set_collection_metadata(
+				Origin::signed(CHARLIE),
+				COLLECTION_ID,
+				Default::default()
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn mint_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_collection(None);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 100));
+		assert_ok!(Erc1155::mint(Origin::signed(ALICE), COLLECTION_ID, TOKEN_0_ID, BOB, 50));
+		System::assert_last_event(Event::Erc1155(crate::Event::TransferSingle {
+			operator: ALICE,
+			from: 0,
+			to: BOB,
+			id: TOKEN_0_ID,
+			value: 50,
+		}));
+		assert_eq!(Erc1155::get_balance(CHARLIE, TOKEN_0_ID), 100);
+		assert_eq!(Erc1155::get_balance(BOB, TOKEN_0_ID), 50);
+		assert_eq!(Erc1155::get_total_supply(TOKEN_0_ID), 150);
+		assert_eq!(Erc1155::collection_of(TOKEN_0_ID), Some(COLLECTION_ID));
+		assert_eq!(Erc1155::get_token(TOKEN_0_ID).unwrap().owner, Some(ALICE));
+		assert_eq!(Erc1155::get_collection(COLLECTION_ID).unwrap().items, 1);
+	});
+}
+
+#[test]
+fn mint_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		create_collection(Some(1));
+		assert_noop!(
+			Erc1155::mint(Origin::signed(CHARLIE), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 100),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 100));
+		assert_noop!(
+			Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_1_ID, CHARLIE, 100),
+			Error::<Test>::CollectionFull
+		);
+	});
+}
+
+#[test]
+fn mint_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		assert_ok!(Erc1155::init(
+			Origin::signed(BOB),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		// tokens outside the collection cannot be minted through it
+		assert_noop!(
+			Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 100),
+			Error::<Test>::AlreadyInitialized
+		);
+		assert_ok!(Erc1155::create_collection(Origin::signed(BOB), 8, None, Perbill::zero()));
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), 8, TOKEN_1_ID, CHARLIE, 100));
+		assert_noop!(
+			Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_1_ID, CHARLIE, 100),
+			Error::<Test>::WrongCollection
+		);
+	});
+}
+
+#[test]
+fn assign_to_collection_should_work() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		assert_ok!(Erc1155::init(
+			Origin::signed(BOB),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		assert_ok!(Erc1155::assign_to_collection(Origin::signed(BOB), TOKEN_0_ID, COLLECTION_ID));
+		assert_eq!(Erc1155::collection_of(TOKEN_0_ID), Some(COLLECTION_ID));
+		assert_noop!(
+			Erc1155::assign_to_collection(Origin::signed(BOB), TOKEN_0_ID, COLLECTION_ID),
+			Error::<Test>::AlreadyInCollection
+		);
+		assert_ok!(Erc1155::mint(Origin::signed(ALICE), COLLECTION_ID, TOKEN_0_ID, ALICE, 5));
+	});
+}
+
+#[test]
+fn assign_to_collection_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		assert_ok!(Erc1155::init(
+			Origin::signed(CHARLIE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		// the token owner is not a collection admin
+		assert_noop!(
+			Erc1155::assign_to_collection(Origin::signed(CHARLIE), TOKEN_0_ID, COLLECTION_ID),
+			Error::<Test>::NoPermission
+		);
+		// the collection admin does not own the token
+		assert_noop!(
+			Erc1155::assign_to_collection(Origin::signed(BOB), TOKEN_0_ID, COLLECTION_ID),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn freeze_should_work() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, ALICE, 100));
+		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), CHARLIE, true));
+		assert_ok!(Erc1155::freeze(Origin::signed(BOB), TOKEN_0_ID));
+		assert_noop!(
+			Erc1155::transfer_from_single(
+				Origin::signed(ALICE),
+				ALICE,
+				CHARLIE,
+				TOKEN_0_ID,
+				10,
+				Default::default()
+			),
+			Error::<Test>::TokenFrozen
+		);
+		assert_noop!(
+			Erc1155::transfer_from_batch(
+				Origin::signed(ALICE),
+				ALICE,
+				CHARLIE,
+				bounded(vec![TOKEN_0_ID]),
+				bounded(vec![10]),
+				Default::default()
+			),
+			Error::<Test>::TokenFrozen
+		);
+		assert_ok!(Erc1155::thaw(Origin::signed(ALICE), TOKEN_0_ID));
+		assert_ok!(Erc1155::transfer_from_single(
+			Origin::signed(ALICE),
+			ALICE,
+			CHARLIE,
+			TOKEN_0_ID,
+			10,
+			Default::default()
+		));
+	});
+}
+
+#[test]
+fn freeze_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		// only tokens in a collection have admins that can freeze them
+		assert_noop!(
+			Erc1155::freeze(Origin::signed(ALICE), TOKEN_0_ID),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_1_ID, ALICE, 100));
+		assert_noop!(
+			Erc1155::freeze(Origin::signed(CHARLIE), TOKEN_1_ID),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn set_token_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, ALICE, 100));
+		assert_ok!(Erc1155::set_token_metadata(
+			Origin::signed(BOB),
+			TOKEN_0_ID,
+			b"ipfs://token".to_vec().try_into().unwrap()
+		));
+		assert_eq!(Erc1155::get_token_metadata(TOKEN_0_ID).to_vec(), b"ipfs://token");
+	});
+}
+
+fn key(bytes: &[u8]) -> crate::AttributeKeyOf<Test> {
+	bytes.to_vec().try_into().unwrap()
+}
+
+fn value(bytes: &[u8]) -> crate::AttributeValueOf<Test> {
+	bytes.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn set_attribute_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_collection(None);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 100));
+		assert_ok!(Erc1155::set_attribute(
+			Origin::signed(BOB),
+			TOKEN_0_ID,
+			AttributeNamespace::Issuer,
+			key(b"rarity"),
+			value(b"epic")
+		));
+		System::assert_last_event(Event::Erc1155(crate::Event::AttributeSet {
+			token_id: TOKEN_0_ID,
+			namespace: AttributeNamespace::Issuer,
+			key: key(b"rarity"),
+			value: value(b"epic"),
+		}));
+		// base deposit plus one per byte of key and value
+		assert_eq!(Balances::reserved_balance(BOB), 10 + 6 + 4);
+		assert_ok!(Erc1155::set_attribute(
+			Origin::signed(CHARLIE),
+			TOKEN_0_ID,
+			AttributeNamespace::Owner(CHARLIE),
+			key(b"nickname"),
+			value(b"sword")
+		));
+		assert_eq!(Balances::reserved_balance(CHARLIE), 10 + 8 + 5);
+		assert_eq!(
+			Erc1155::attribute(TOKEN_0_ID, &AttributeNamespace::Issuer, b"rarity"),
+			Some(b"epic".to_vec())
+		);
+		let mut attributes = Erc1155::get_attributes(TOKEN_0_ID);
+		attributes.sort_by(|a, b| a.1.cmp(&b.1));
+		assert_eq!(
+			attributes,
+			vec![
+				(AttributeNamespace::Owner(CHARLIE), b"nickname".to_vec(), b"sword".to_vec()),
+				(AttributeNamespace::Issuer, b"rarity".to_vec(), b"epic".to_vec()),
+			]
+		);
+	});
+}
+
+#[test]
+fn set_attribute_should_move_deposit_on_overwrite() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 100));
+		assert_ok!(Erc1155::set_attribute(
+			Origin::signed(BOB),
+			TOKEN_0_ID,
+			AttributeNamespace::Issuer,
+			key(b"level"),
+			value(b"1")
+		));
+		assert_ok!(Erc1155::set_attribute(
+			Origin::signed(ALICE),
+			TOKEN_0_ID,
+			AttributeNamespace::Issuer,
+			key(b"level"),
+			value(b"10")
+		));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::reserved_balance(ALICE), 10 + 5 + 2);
+		assert_ok!(Erc1155::clear_attribute(
+			Origin::signed(BOB),
+			TOKEN_0_ID,
+			AttributeNamespace::Issuer,
+			key(b"level")
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Erc1155::attribute(TOKEN_0_ID, &AttributeNamespace::Issuer, b"level"), None);
+	});
+}
+
+#[test]
+fn set_attribute_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 100));
+		// holders cannot write issuer attributes
+		assert_noop!(
+			Erc1155::set_attribute(
+				Origin::signed(CHARLIE),
+				TOKEN_0_ID,
+				AttributeNamespace::Issuer,
+				key(b"level"),
+				value(b"99")
+			),
+			Error::<Test>::NoPermission
+		);
+		// nor attributes of another holder, or without holding the token
+		assert_noop!(
+			Erc1155::set_attribute(
+				Origin::signed(CHARLIE),
+				TOKEN_0_ID,
+				AttributeNamespace::Owner(BOB),
+				key(b"level"),
+				value(b"99")
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Erc1155::set_attribute(
+				Origin::signed(BOB),
+				TOKEN_0_ID,
+				AttributeNamespace::Owner(BOB),
+				key(b"level"),
+				value(b"99")
+			),
+			Error::<Test>::NoPermission
+		);
+		// the pallet namespace is only writable by other pallets
+		assert_noop!(
+			Erc1155::set_attribute(
+				Origin::signed(ALICE),
+				TOKEN_0_ID,
+				AttributeNamespace::Pallet,
+				key(b"level"),
+				value(b"99")
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn set_attribute_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 100));
+		assert_ok!(Balances::set_balance(Origin::root(), BOB, 15, 0));
+		assert_noop!(
+			Erc1155::set_attribute(
+				Origin::signed(BOB),
+				TOKEN_0_ID,
+				AttributeNamespace::Issuer,
+				key(b"durability"),
+				value(b"100")
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn pallet_attributes_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		assert_ok!(<Erc1155 as Erc1155Attributes<u128>>::set_attribute(
+			TOKEN_0_ID,
+			b"durability",
+			b"100"
+		));
+		assert_eq!(
+			Erc1155::attribute(TOKEN_0_ID, &AttributeNamespace::Pallet, b"durability"),
+			Some(b"100".to_vec())
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_noop!(
+			<Erc1155 as Erc1155Attributes<u128>>::set_attribute(TOKEN_0_ID, &[0u8; 17], b"1"),
+			Error::<Test>::AttributeTooLong
+		);
+		assert_ok!(<Erc1155 as Erc1155Attributes<u128>>::clear_attribute(
+			TOKEN_0_ID,
+			b"durability"
+		));
+		assert_noop!(
+			<Erc1155 as Erc1155Attributes<u128>>::clear_attribute(TOKEN_0_ID, b"durability"),
+			Error::<Test>::UnknownAttribute
+		);
+	});
+}
+
+fn schedules(schedules: Vec<EmissionSchedule<u128, u64>>) -> crate::EmissionSchedulesOf<Test> {
+	schedules.try_into().unwrap()
+}
+
+#[test]
+fn set_max_supply_should_work() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 100));
+		assert_ok!(Erc1155::set_max_supply(
+			Origin::signed(BOB),
+			TOKEN_0_ID,
+			250,
+			Default::default()
+		));
+		assert_eq!(
+			Erc1155::get_supply_cap(TOKEN_0_ID),
+			Some(SupplyCap { max_supply: 250, issued_before: 100, minted: 0 })
+		);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 150));
+		assert_noop!(
+			Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 1),
+			Error::<Test>::MaxSupplyExceeded
+		);
+		// the cap is immutable
+		assert_noop!(
+			Erc1155::set_max_supply(Origin::signed(ALICE), TOKEN_0_ID, 1000, Default::default()),
+			Error::<Test>::MaxSupplyAlreadySet
+		);
+	});
+}
+
+#[test]
+fn set_max_supply_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		create_collection(None);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 100));
+		assert_noop!(
+			Erc1155::set_max_supply(Origin::signed(CHARLIE), TOKEN_0_ID, 200, Default::default()),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Erc1155::set_max_supply(Origin::signed(BOB), TOKEN_0_ID, 99, Default::default()),
+			Error::<Test>::MaxSupplyBelowSupply
+		);
+		assert_noop!(
+			Erc1155::set_max_supply(
+				Origin::signed(BOB),
+				TOKEN_0_ID,
+				200,
+				schedules(vec![EmissionSchedule { start: 10, end: 5, amount: 50 }])
+			),
+			Error::<Test>::InvalidEmissionSchedule
+		);
+	});
+}
+
+#[test]
+fn emission_schedule_should_limit_mints() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_collection(None);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 100));
+		assert_ok!(Erc1155::set_max_supply(
+			Origin::signed(BOB),
+			TOKEN_0_ID,
+			1000,
+			schedules(vec![
+				EmissionSchedule { start: 10, end: 20, amount: 100 },
+				EmissionSchedule { start: 20, end: 20, amount: 50 },
+			])
+		));
+		assert_noop!(
+			Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 1),
+			Error::<Test>::EmissionScheduleExceeded
+		);
+		System::set_block_number(15);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 50));
+		assert_noop!(
+			Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 1),
+			Error::<Test>::EmissionScheduleExceeded
+		);
+		System::set_block_number(20);
+		assert_ok!(Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 100));
+		assert_noop!(
+			Erc1155::mint(Origin::signed(BOB), COLLECTION_ID, TOKEN_0_ID, CHARLIE, 1),
+			Error::<Test>::EmissionScheduleExceeded
+		);
+		assert_eq!(Erc1155::get_total_supply(TOKEN_0_ID), 250);
+	});
+}
+
+#[test]
+fn locks_should_restrict_transfers() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Erc1155::init(
+			Origin::signed(ALICE),
+			bounded(vec![TOKEN_0_ID]),
+			bounded(vec![1000])
+		));
+		assert_ok!(Erc1155::set_approval_for_all(Origin::signed(ALICE), BOB, true));
+		assert_ok!(Erc1155::set_lock(*b"lock_one", &ALICE, TOKEN_0_ID, 600 * MIL));
+		assert_ok!(Erc1155::set_lock(*b"lock_two", &ALICE, TOKEN_0_ID, 900 * MIL));
+		assert_eq!(Erc1155::locked_balance(&ALICE, TOKEN_0_ID), 900 * MIL);
+		assert_noop!(
+			Erc1155::transfer_from_single(
+				Origin::signed(ALICE),
+				ALICE,
+				BOB,
+				TOKEN_0_ID,
+				101 * MIL,
+				Default::default()
+			),
+			Error::<Test>::LiquidityRestrictions
+		);
+		assert_ok!(Erc1155::transfer_from_single(
+			Origin::signed(ALICE),
+			ALICE,
+			BOB,
+			TOKEN_0_ID,
+			100 * MIL,
+			Default::default()
+		));
+		Erc1155::remove_lock(*b"lock_two", &ALICE, TOKEN_0_ID);
+		assert_eq!(Erc1155::locked_balance(&ALICE, TOKEN_0_ID), 600 * MIL);
+		assert_ok!(Erc1155::transfer_from_single(
+			Origin::signed(ALICE),
+			ALICE,
+			BOB,
+			TOKEN_0_ID,
+			300 * MIL,
+			Default::default()
+		));
+		Erc1155::remove_lock(*b"lock_one", &ALICE, TOKEN_0_ID);
+		assert!(Erc1155::get_locks(ALICE, TOKEN_0_ID).is_empty());
+	});
+}
+
+#[test]
+fn set_lock_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		for id in 0..4u8 {
+			assert_ok!(Erc1155::set_lock([id; 8], &ALICE, TOKEN_0_ID, 1));
+		}
+		// updating an existing lock does not need a new slot
+		assert_ok!(Erc1155::set_lock([0; 8], &ALICE, TOKEN_0_ID, 2));
+		assert_noop!(Erc1155::set_lock([4; 8], &ALICE, TOKEN_0_ID, 1), Error::<Test>::TooManyLocks);
+	});
+}
+
+#[test]
+fn create_mint_and_burn_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(<Erc1155 as Erc1155Mintable<u128>>::create(&ALICE, TOKEN_0_ID));
+		assert_noop!(
+			<Erc1155 as Erc1155Mintable<u128>>::create(&BOB, TOKEN_0_ID),
+			Error::<Test>::AlreadyInitialized
+		);
+		assert_ok!(<Erc1155 as Erc1155Mintable<u128>>::mint(&BOB, TOKEN_0_ID, 100));
+		assert_ok!(<Erc1155 as Erc1155Mintable<u128>>::burn(&BOB, TOKEN_0_ID, 40));
+		System::assert_last_event(Event::Erc1155(crate::Event::TransferSingle {
+			operator: BOB,
+			from: BOB,
+			to: 0,
+			id: TOKEN_0_ID,
+			value: 40,
+		}));
+		assert_eq!(Erc1155::get_balance(BOB, TOKEN_0_ID), 60);
+		assert_eq!(Erc1155::get_total_supply(TOKEN_0_ID), 60);
+		assert_noop!(
+			<Erc1155 as Erc1155Mintable<u128>>::burn(&BOB, TOKEN_0_ID, 61),
+			Error::<Test>::InsufficientFunds
+		);
+		assert_ok!(Erc1155::set_lock(*b"lock_one", &BOB, TOKEN_0_ID, 50));
+		assert_noop!(
+			<Erc1155 as Erc1155Mintable<u128>>::burn(&BOB, TOKEN_0_ID, 11),
+			Error::<Test>::LiquidityRestrictions
+		);
+	});
+}
+
+const CHARACTER_ID: u32 = 10;
+const SWORD_ID: u32 = 11;
+const GEM_ID: u32 = 12;
+const POTION_ID: u32 = 13;
+
+fn create(id: u32, to: &u128, amount: u128) {
+	assert_ok!(<Erc1155 as Erc1155Mintable<u128>>::create(&ALICE, id));
+	assert_ok!(<Erc1155 as Erc1155Mintable<u128>>::mint(to, id, amount));
+}
+
+#[test]
+fn token_account_should_work() {
+	new_test_ext().execute_with(|| {
+		let account = Erc1155::token_account_id(CHARACTER_ID);
+		assert_ne!(account, Erc1155::token_account_id(SWORD_ID));
+		assert_eq!(Erc1155::token_of_account(&account), Some(CHARACTER_ID));
+		assert_eq!(Erc1155::token_of_account(&ALICE), None);
+	});
+}
+
+#[test]
+fn transfer_from_token_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let character = Erc1155::token_account_id(CHARACTER_ID);
+		create(CHARACTER_ID, &ALICE, 1);
+		create(POTION_ID, &character, 5);
+		assert_ok!(Erc1155::transfer_from_token(
+			Origin::signed(ALICE),
+			CHARACTER_ID,
+			BOB,
+			POTION_ID,
+			2,
+			Default::default()
+		));
+		System::assert_last_event(Event::Erc1155(crate::Event::TransferSingle {
+			operator: ALICE,
+			from: character,
+			to: BOB,
+			id: POTION_ID,
+			value: 2,
+		}));
+		assert_eq!(Erc1155::get_balance(character, POTION_ID), 3);
+
+		// the holdings move with the character
+		assert_ok!(Erc1155::transfer(&ALICE, &BOB, CHARACTER_ID, 1));
+		assert_noop!(
+			Erc1155::transfer_from_token(
+				Origin::signed(ALICE),
+				CHARACTER_ID,
+				ALICE,
+				POTION_ID,
+				1,
+				Default::default()
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Erc1155::transfer_from_token(
+			Origin::signed(BOB),
+			CHARACTER_ID,
+			BOB,
+			POTION_ID,
+			3,
+			Default::default()
+		));
+		assert_eq!(Erc1155::get_balance(BOB, POTION_ID), 5);
+	});
+}
+
+#[test]
+fn nested_tokens_should_follow_root_owner() {
+	new_test_ext().execute_with(|| {
+		create(CHARACTER_ID, &ALICE, 1);
+		create(SWORD_ID, &Erc1155::token_account_id(CHARACTER_ID), 1);
+		create(GEM_ID, &Erc1155::token_account_id(SWORD_ID), 1);
+		assert_eq!(Erc1155::get_parent(SWORD_ID), Some(CHARACTER_ID));
+		assert_eq!(Erc1155::get_parent(GEM_ID), Some(SWORD_ID));
+		assert_eq!(Erc1155::root_token(GEM_ID), Ok(CHARACTER_ID));
+
+		assert_ok!(Erc1155::transfer_from_token(
+			Origin::signed(ALICE),
+			SWORD_ID,
+			CHARLIE,
+			GEM_ID,
+			1,
+			Default::default()
+		));
+		assert_eq!(Erc1155::get_parent(GEM_ID), None);
+		assert_eq!(Erc1155::get_balance(CHARLIE, GEM_ID), 1);
+		assert_noop!(
+			Erc1155::transfer_from_token(
+				Origin::signed(CHARLIE),
+				SWORD_ID,
+				CHARLIE,
+				SWORD_ID,
+				1,
+				Default::default()
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn nesting_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		create(CHARACTER_ID, &ALICE, 1);
+		create(SWORD_ID, &Erc1155::token_account_id(CHARACTER_ID), 1);
+		create(POTION_ID, &ALICE, 5);
+		// a token can't hold itself or one of its ancestors
+		assert_noop!(
+			Erc1155::transfer(&ALICE, &Erc1155::token_account_id(CHARACTER_ID), CHARACTER_ID, 1),
+			Error::<Test>::NestingCycle
+		);
+		assert_noop!(
+			Erc1155::transfer(&ALICE, &Erc1155::token_account_id(SWORD_ID), CHARACTER_ID, 1),
+			Error::<Test>::NestingCycle
+		);
+		// fungible tokens have no single owner to control their holdings
+		assert_noop!(
+			Erc1155::transfer(&ALICE, &Erc1155::token_account_id(POTION_ID), CHARACTER_ID, 1),
+			Error::<Test>::InvalidParent
+		);
+	});
+}
+
+#[test]
+fn nesting_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		create(10, &ALICE, 1);
+		for id in 11..14 {
+			create(id, &Erc1155::token_account_id(id - 1), 1);
+		}
+		assert_eq!(Erc1155::root_token(13), Ok(10));
+		assert_ok!(<Erc1155 as Erc1155Mintable<u128>>::create(&ALICE, 14));
+		assert_noop!(
+			<Erc1155 as Erc1155Mintable<u128>>::mint(&Erc1155::token_account_id(13), 14, 1),
+			Error::<Test>::NestingTooDeep
+		);
+	});
+}
+
+const DUST_TOKEN_ID: u32 = 14;
+
+#[test]
+fn set_min_balance_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create(DUST_TOKEN_ID, &ALICE, 100);
+		assert_ok!(Erc1155::set_min_balance(
+			Origin::signed(ALICE),
+			DUST_TOKEN_ID,
+			10,
+			DustPolicy::Burn
+		));
+		assert_eq!(
+			Erc1155::get_min_balance(DUST_TOKEN_ID),
+			Some(MinBalance { amount: 10, dust: DustPolicy::Burn })
+		);
+		System::assert_last_event(Event::Erc1155(crate::Event::MinBalanceSet {
+			token_id: DUST_TOKEN_ID,
+			min_balance: 10,
+			dust: DustPolicy::Burn,
+		}));
+		assert_ok!(Erc1155::set_min_balance(
+			Origin::signed(ALICE),
+			DUST_TOKEN_ID,
+			0,
+			DustPolicy::Burn
+		));
+		assert_eq!(Erc1155::get_min_balance(DUST_TOKEN_ID), None);
+	});
+}
+
+#[test]
+fn set_min_balance_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		create(DUST_TOKEN_ID, &BOB, 100);
+		assert_noop!(
+			Erc1155::set_min_balance(Origin::signed(BOB), DUST_TOKEN_ID, 10, DustPolicy::Burn),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Erc1155::set_min_balance(Origin::signed(ALICE), TOKEN_0_ID, 10, DustPolicy::Burn),
+			Error::<Test>::Uninitilized
+		);
+	});
+}
+
+#[test]
+fn transfer_should_remove_zero_balances() {
+	new_test_ext().execute_with(|| {
+		create(DUST_TOKEN_ID, &ALICE, 100);
+		assert!(TokenBalances::<Test>::contains_key(ALICE, DUST_TOKEN_ID));
+		assert_ok!(Erc1155::transfer(&ALICE, &BOB, DUST_TOKEN_ID, 100));
+		assert!(!TokenBalances::<Test>::contains_key(ALICE, DUST_TOKEN_ID));
+		assert_ok!(<Erc1155 as Erc1155Mintable<u128>>::burn(&BOB, DUST_TOKEN_ID, 100));
+		assert!(!TokenBalances::<Test>::contains_key(BOB, DUST_TOKEN_ID));
+	});
+}
+
+#[test]
+fn transfer_should_sweep_dust() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create(DUST_TOKEN_ID, &ALICE, 100);
+		assert_ok!(Erc1155::set_min_balance(
+			Origin::signed(ALICE),
+			DUST_TOKEN_ID,
+			10,
+			DustPolicy::SweepToRecipient
+		));
+		assert_ok!(Erc1155::transfer(&ALICE, &BOB, DUST_TOKEN_ID, 95));
+		System::assert_last_event(Event::Erc1155(crate::Event::DustSwept {
+			from: ALICE,
+			to: BOB,
+			token_id: DUST_TOKEN_ID,
+			amount: 5,
+		}));
+		assert_eq!(Erc1155::get_balance(BOB, DUST_TOKEN_ID), 100);
+		assert!(!TokenBalances::<Test>::contains_key(ALICE, DUST_TOKEN_ID));
+		assert_eq!(Erc1155::get_total_supply(DUST_TOKEN_ID), 100);
+		assert!(dust().is_empty());
+	});
+}
+
+#[test]
+fn transfer_should_burn_dust() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create(DUST_TOKEN_ID, &ALICE, 100);
+		assert_ok!(Erc1155::set_min_balance(
+			Origin::signed(ALICE),
+			DUST_TOKEN_ID,
+			10,
+			DustPolicy::Burn
+		));
+		assert_ok!(Erc1155::transfer(&ALICE, &BOB, DUST_TOKEN_ID, 95));
+		System::assert_last_event(Event::Erc1155(crate::Event::DustBurned {
+			who: ALICE,
+			token_id: DUST_TOKEN_ID,
+			amount: 5,
+		}));
+		assert_eq!(Erc1155::get_balance(BOB, DUST_TOKEN_ID), 95);
+		assert!(!TokenBalances::<Test>::contains_key(ALICE, DUST_TOKEN_ID));
+		assert_eq!(Erc1155::get_total_supply(DUST_TOKEN_ID), 95);
+		assert_eq!(dust(), vec![(ALICE, DUST_TOKEN_ID, 5)]);
+	});
+}
+
+#[test]
+fn min_balance_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		create(DUST_TOKEN_ID, &ALICE, 100);
+		assert_ok!(Erc1155::set_min_balance(
+			Origin::signed(ALICE),
+			DUST_TOKEN_ID,
+			10,
+			DustPolicy::Burn
+		));
+		// recipients must end up with at least the minimum
+		assert_noop!(
+			Erc1155::transfer(&ALICE, &BOB, DUST_TOKEN_ID, 9),
+			Error::<Test>::BelowMinBalance
+		);
+		assert_noop!(
+			<Erc1155 as Erc1155Mintable<u128>>::mint(&BOB, DUST_TOKEN_ID, 9),
+			Error::<Test>::BelowMinBalance
+		);
+		assert_noop!(
+			<Erc1155 as Erc1155Mintable<u128>>::burn(&ALICE, DUST_TOKEN_ID, 95),
+			Error::<Test>::BelowMinBalance
+		);
+		// locked balances are never collected as dust
+		assert_ok!(Erc1155::set_lock(*b"lock_one", &ALICE, DUST_TOKEN_ID, 5));
+		assert_noop!(
+			Erc1155::transfer(&ALICE, &BOB, DUST_TOKEN_ID, 95),
+			Error::<Test>::LiquidityRestrictions
+		);
+	});
+}
+
+const FEE_TOKEN_ID: u32 = 15;
+const DAVE: u128 = 4;
+
+fn set_fee(percent: u32) {
+	assert_ok!(Erc1155::set_transfer_fee(
+		Origin::signed(ALICE),
+		FEE_TOKEN_ID,
+		Perbill::from_percent(percent),
+		CHARLIE
+	));
+}
+
+#[test]
+fn set_transfer_fee_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create(FEE_TOKEN_ID, &ALICE, 1000);
+		set_fee(10);
+		assert_eq!(
+			Erc1155::get_transfer_fee(FEE_TOKEN_ID),
+			Some(TransferFee { rate: Perbill::from_percent(10), recipient: CHARLIE })
+		);
+		System::assert_last_event(Event::Erc1155(crate::Event::TransferFeeSet {
+			token_id: FEE_TOKEN_ID,
+			rate: Perbill::from_percent(10),
+			recipient: CHARLIE,
+		}));
+		set_fee(0);
+		assert_eq!(Erc1155::get_transfer_fee(FEE_TOKEN_ID), None);
+	});
+}
+
+#[test]
+fn set_transfer_fee_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		create(FEE_TOKEN_ID, &BOB, 1000);
+		assert_noop!(
+			Erc1155::set_transfer_fee(
+				Origin::signed(BOB),
+				FEE_TOKEN_ID,
+				Perbill::from_percent(10),
+				BOB
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Erc1155::set_fee_exemption(Origin::signed(BOB), FEE_TOKEN_ID, BOB, true),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn transfer_fee_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create(FEE_TOKEN_ID, &ALICE, 1000);
+		set_fee(10);
+		assert_ok!(Erc1155::transfer_from_single(
+			Origin::signed(ALICE),
+			ALICE,
+			BOB,
+			FEE_TOKEN_ID,
+			100,
+			Default::default()
+		));
+		assert_eq!(Erc1155::get_balance(ALICE, FEE_TOKEN_ID), 900);
+		assert_eq!(Erc1155::get_balance(BOB, FEE_TOKEN_ID), 90);
+		assert_eq!(Erc1155::get_balance(CHARLIE, FEE_TOKEN_ID), 10);
+		assert_eq!(Erc1155::get_total_supply(FEE_TOKEN_ID), 1000);
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::Erc1155(crate::Event::TransferFeePaid {
+				from: ALICE,
+				to: BOB,
+				token_id: FEE_TOKEN_ID,
+				fee: 10,
+				received: 90,
+			})));
+		// the transfer event reports what the recipient received
+		System::assert_last_event(Event::Erc1155(crate::Event::TransferSingle {
+			operator: ALICE,
+			from: ALICE,
+			to: BOB,
+			id: FEE_TOKEN_ID,
+			value: 90,
+		}));
+
+		// batches pay the fee for every token and fees are rounded down
+		assert_ok!(Erc1155::transfer_from_batch(
+			Origin::signed(BOB),
+			BOB,
+			DAVE,
+			bounded(vec![FEE_TOKEN_ID]),
+			bounded(vec![19]),
+			Default::default()
+		));
+		assert_eq!(Erc1155::get_balance(DAVE, FEE_TOKEN_ID), 18);
+		assert_eq!(Erc1155::get_balance(CHARLIE, FEE_TOKEN_ID), 11);
+		System::assert_last_event(Event::Erc1155(crate::Event::TransferBatch {
+			operator: BOB,
+			from: BOB,
+			to: DAVE,
+			ids: vec![FEE_TOKEN_ID],
+			values: vec![18],
+		}));
+	});
+}
+
+#[test]
+fn transfer_fee_below_min_balance_should_be_waived() {
+	new_test_ext().execute_with(|| {
+		create(FEE_TOKEN_ID, &ALICE, 10_000);
+		set_fee(1);
+		assert_ok!(Erc1155::set_min_balance(
+			Origin::signed(ALICE),
+			FEE_TOKEN_ID,
+			10,
+			DustPolicy::Burn
+		));
+		// a fee of 1 could not be held by the empty fee account
+		assert_ok!(Erc1155::transfer(&ALICE, &BOB, FEE_TOKEN_ID, 100));
+		assert_eq!(Erc1155::get_balance(BOB, FEE_TOKEN_ID), 100);
+		assert_eq!(Erc1155::get_balance(CHARLIE, FEE_TOKEN_ID), 0);
+		// once the fee account can hold it the fee is charged
+		assert_ok!(Erc1155::transfer(&ALICE, &BOB, FEE_TOKEN_ID, 1000));
+		assert_eq!(Erc1155::get_balance(CHARLIE, FEE_TOKEN_ID), 10);
+		assert_ok!(Erc1155::transfer(&ALICE, &BOB, FEE_TOKEN_ID, 100));
+		assert_eq!(Erc1155::get_balance(BOB, FEE_TOKEN_ID), 1189);
+		assert_eq!(Erc1155::get_balance(CHARLIE, FEE_TOKEN_ID), 11);
+	});
+}
+
+#[test]
+fn transfer_fee_should_skip_exempt_accounts() {
+	new_test_ext().execute_with(|| {
+		create(FEE_TOKEN_ID, &ALICE, 1000);
+		set_fee(10);
+		// the recipient of the fee never pays it
+		assert_ok!(Erc1155::transfer(&ALICE, &CHARLIE, FEE_TOKEN_ID, 100));
+		assert_ok!(Erc1155::transfer(&CHARLIE, &BOB, FEE_TOKEN_ID, 50));
+		assert_eq!(Erc1155::get_balance(BOB, FEE_TOKEN_ID), 50);
+		assert_eq!(Erc1155::get_balance(CHARLIE, FEE_TOKEN_ID), 50);
+
+		assert_ok!(Erc1155::set_fee_exemption(Origin::signed(ALICE), FEE_TOKEN_ID, DAVE, true));
+		assert!(Erc1155::is_fee_exempt(&FEE_TOKEN_ID, &DAVE));
+		assert_ok!(Erc1155::transfer(&ALICE, &DAVE, FEE_TOKEN_ID, 100));
+		assert_ok!(Erc1155::transfer(&DAVE, &BOB, FEE_TOKEN_ID, 50));
+		assert_eq!(Erc1155::get_balance(BOB, FEE_TOKEN_ID), 100);
+		assert_eq!(Erc1155::get_balance(CHARLIE, FEE_TOKEN_ID), 50);
+
+		assert_ok!(Erc1155::set_fee_exemption(Origin::signed(ALICE), FEE_TOKEN_ID, DAVE, false));
+		assert_ok!(Erc1155::transfer(&DAVE, &BOB, FEE_TOKEN_ID, 50));
+		assert_eq!(Erc1155::get_balance(BOB, FEE_TOKEN_ID), 145);
+		assert_eq!(Erc1155::get_balance(CHARLIE, FEE_TOKEN_ID), 55);
+	});
+}
@@ -0,0 +1,86 @@
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Zero},
+	Perbill, RuntimeDebug,
+};
+use traits::LockIdentifier;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TokenDetails<AccountId, Balance> {
+	/// Account that initialized the token, `None` for tokens created before storage version 1.
+	pub owner: Option<AccountId>,
+	pub supply: Balance,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CollectionDetails<AccountId> {
+	pub owner: AccountId,
+	/// Maximum number of token ids in the collection, `None` for no limit.
+	pub max_items: Option<u32>,
+	pub items: u32,
+	/// Royalty applied to secondary sales of member tokens, paid to the collection owner.
+	pub default_royalty: Perbill,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SupplyCap<Balance> {
+	pub max_supply: Balance,
+	/// Supply that existed when the cap was declared.
+	pub issued_before: Balance,
+	/// Amount minted since the cap was declared.
+	pub minted: Balance,
+}
+
+/// Releases `amount` for minting linearly from `start` until `end`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EmissionSchedule<Balance, BlockNumber> {
+	pub start: BlockNumber,
+	pub end: BlockNumber,
+	pub amount: Balance,
+}
+
+impl<Balance, BlockNumber> EmissionSchedule<Balance, BlockNumber>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	pub fn unlocked_at(&self, now: BlockNumber) -> Balance {
+		if now < self.start {
+			Zero::zero()
+		} else if now >= self.end {
+			self.amount
+		} else {
+			Perbill::from_rational(now - self.start, self.end - self.start).mul_floor(self.amount)
+		}
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TokenLock<Balance> {
+	pub id: LockIdentifier,
+	pub amount: Balance,
+}
+
+/// What happens to a balance left below the minimum balance of its token by a transfer.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum DustPolicy {
+	/// Added to the amount received by the recipient of the transfer.
+	SweepToRecipient,
+	/// Burned and reported to `Config::OnDust`.
+	Burn,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MinBalance<Balance> {
+	/// Smallest nonzero balance an account may hold.
+	pub amount: Balance,
+	pub dust: DustPolicy,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TransferFee<AccountId> {
+	/// Share of every transfer paid to `recipient`, rounded down.
+	pub rate: Perbill,
+	pub recipient: AccountId,
+}
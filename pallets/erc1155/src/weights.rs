@@ -0,0 +1,394 @@
+//! Weights for pallet_erc1155.
+//!
+//! These are hand-written estimates that follow the storage accesses of each call, not the output
+//! of a benchmark run. Replace them with measured weights by running:
+//! ./target/release/node-template benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_erc1155 --extrinsic '*' --steps 50 --repeat 20
+//! --output ./pallets/erc1155/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_erc1155.
+pub trait WeightInfo {
+	fn init(b: u32) -> Weight;
+	fn transfer_from_single(d: u32) -> Weight;
+	fn transfer_from_batch(b: u32, d: u32) -> Weight;
+	fn set_approval_for_all() -> Weight;
+	fn enable_checkpoints() -> Weight;
+	fn create_collection() -> Weight;
+	fn set_collection_metadata() -> Weight;
+	fn set_collection_admin() -> Weight;
+	fn assign_to_collection() -> Weight;
+	fn mint() -> Weight;
+	fn set_token_metadata() -> Weight;
+	fn freeze() -> Weight;
+	fn thaw() -> Weight;
+	fn set_attribute() -> Weight;
+	fn clear_attribute() -> Weight;
+	fn set_max_supply(s: u32) -> Weight;
+	fn transfer_from_token(d: u32) -> Weight;
+	fn set_min_balance() -> Weight;
+	fn set_transfer_fee() -> Weight;
+	fn set_fee_exemption() -> Weight;
+}
+
+/// Weights for pallet_erc1155 using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Erc1155 Tokens (r:1 w:1)
+	// Storage: Erc1155 TotalSupply (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:1 w:0)
+	// Storage: Erc1155 Balances (r:0 w:1)
+	fn init(b: u32) -> Weight {
+		(9_520_000 as Weight)
+			.saturating_add((21_310_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(b as Weight)))
+	}
+	// Storage: Erc1155 Tokens (r:1 w:1)
+	// Storage: Erc1155 TotalSupply (r:1 w:0)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 OperatorApprovals (r:1 w:0)
+	// Storage: Erc1155 Approval (r:1 w:0)
+	// Storage: Erc1155 MinBalances (r:1 w:0)
+	// Storage: Erc1155 Balances (r:3 w:3)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:1 w:0)
+	// Storage: Erc1155 BalanceCheckpoints (r:3 w:3)
+	// Storage: Erc1155 SupplyCheckpoints (r:1 w:1)
+	// Storage: Erc1155 BalanceCheckpointPages (r:3 w:3)
+	// Storage: Erc1155 ArchivedBalanceCheckpoints (r:0 w:3)
+	// Storage: Erc1155 SupplyCheckpointPages (r:1 w:1)
+	// Storage: Erc1155 ArchivedSupplyCheckpoints (r:0 w:1)
+	// Storage: Erc1155 TransferFees (r:1 w:0)
+	// Storage: Erc1155 FeeExemptions (r:2 w:0)
+	// Storage: Erc1155 Parents (r:2 w:2)
+	fn transfer_from_single(d: u32) -> Weight {
+		(67_830_000 as Weight)
+			.saturating_add((3_920_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(24 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(T::DbWeight::get().writes(18 as Weight))
+	}
+	// Storage: Erc1155 OperatorApprovals (r:1 w:0)
+	// Storage: Erc1155 Approval (r:1 w:0)
+	// Storage: Erc1155 Tokens (r:1 w:1)
+	// Storage: Erc1155 TotalSupply (r:1 w:0)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 MinBalances (r:1 w:0)
+	// Storage: Erc1155 Balances (r:3 w:3)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:1 w:0)
+	// Storage: Erc1155 BalanceCheckpoints (r:3 w:3)
+	// Storage: Erc1155 SupplyCheckpoints (r:1 w:1)
+	// Storage: Erc1155 BalanceCheckpointPages (r:3 w:3)
+	// Storage: Erc1155 ArchivedBalanceCheckpoints (r:0 w:3)
+	// Storage: Erc1155 SupplyCheckpointPages (r:1 w:1)
+	// Storage: Erc1155 ArchivedSupplyCheckpoints (r:0 w:1)
+	// Storage: Erc1155 TransferFees (r:1 w:0)
+	// Storage: Erc1155 FeeExemptions (r:2 w:0)
+	// Storage: Erc1155 Parents (r:2 w:2)
+	fn transfer_from_batch(b: u32, d: u32) -> Weight {
+		(14_870_000 as Weight)
+			.saturating_add((59_070_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(
+				(3_920_000 as Weight).saturating_mul(b as Weight).saturating_mul(d as Weight),
+			)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((22 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().reads((b as Weight).saturating_mul(d as Weight)))
+			.saturating_add(T::DbWeight::get().writes((18 as Weight).saturating_mul(b as Weight)))
+	}
+	// Storage: Erc1155 Approval (r:0 w:1)
+	// Storage: Erc1155 OperatorApprovals (r:0 w:1)
+	fn set_approval_for_all() -> Weight {
+		(17_040_000 as Weight).saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 TotalSupply (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:1 w:1)
+	fn enable_checkpoints() -> Weight {
+		(19_880_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Erc1155 Collections (r:1 w:1)
+	fn create_collection() -> Weight {
+		(15_120_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Erc1155 Collections (r:1 w:0)
+	// Storage: Erc1155 CollectionAdmins (r:1 w:0)
+	// Storage: Erc1155 CollectionMetadata (r:0 w:1)
+	fn set_collection_metadata() -> Weight {
+		(18_430_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Erc1155 Collections (r:1 w:0)
+	// Storage: Erc1155 CollectionAdmins (r:0 w:1)
+	fn set_collection_admin() -> Weight {
+		(16_770_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 TotalSupply (r:1 w:0)
+	// Storage: Erc1155 TokenCollection (r:1 w:1)
+	// Storage: Erc1155 Collections (r:1 w:1)
+	// Storage: Erc1155 CollectionAdmins (r:1 w:0)
+	fn assign_to_collection() -> Weight {
+		(27_940_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Erc1155 Collections (r:2 w:1)
+	// Storage: Erc1155 CollectionAdmins (r:1 w:0)
+	// Storage: Erc1155 TokenCollection (r:1 w:1)
+	// Storage: Erc1155 Tokens (r:2 w:1)
+	// Storage: Erc1155 TotalSupply (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	// Storage: Erc1155 SupplyCheckpoints (r:1 w:1)
+	// Storage: Erc1155 Balances (r:1 w:1)
+	// Storage: Erc1155 BalanceCheckpoints (r:1 w:1)
+	// Storage: Erc1155 BalanceCheckpointPages (r:1 w:1)
+	// Storage: Erc1155 ArchivedBalanceCheckpoints (r:0 w:1)
+	// Storage: Erc1155 SupplyCheckpointPages (r:1 w:1)
+	// Storage: Erc1155 ArchivedSupplyCheckpoints (r:0 w:1)
+	// Storage: Erc1155 SupplyCaps (r:1 w:1)
+	// Storage: Erc1155 EmissionSchedules (r:1 w:0)
+	fn mint() -> Weight {
+		(55_210_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	// Storage: Erc1155 Collections (r:1 w:0)
+	// Storage: Erc1155 CollectionAdmins (r:1 w:0)
+	// Storage: Erc1155 TokenMetadata (r:0 w:1)
+	fn set_token_metadata() -> Weight {
+		(20_050_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	// Storage: Erc1155 Collections (r:1 w:0)
+	// Storage: Erc1155 CollectionAdmins (r:1 w:0)
+	// Storage: Erc1155 FrozenTokens (r:0 w:1)
+	fn freeze() -> Weight {
+		(18_960_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	// Storage: Erc1155 Collections (r:1 w:0)
+	// Storage: Erc1155 CollectionAdmins (r:1 w:0)
+	// Storage: Erc1155 FrozenTokens (r:0 w:1)
+	fn thaw() -> Weight {
+		(18_710_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 TotalSupply (r:1 w:0)
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	// Storage: Erc1155 Collections (r:1 w:0)
+	// Storage: Erc1155 CollectionAdmins (r:1 w:0)
+	// Storage: Erc1155 Attributes (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn set_attribute() -> Weight {
+		(46_280_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 TotalSupply (r:1 w:0)
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	// Storage: Erc1155 Collections (r:1 w:0)
+	// Storage: Erc1155 CollectionAdmins (r:1 w:0)
+	// Storage: Erc1155 Attributes (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_attribute() -> Weight {
+		(39_610_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 TotalSupply (r:1 w:0)
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	// Storage: Erc1155 Collections (r:1 w:0)
+	// Storage: Erc1155 CollectionAdmins (r:1 w:0)
+	// Storage: Erc1155 SupplyCaps (r:1 w:1)
+	// Storage: Erc1155 EmissionSchedules (r:0 w:1)
+	fn set_max_supply(s: u32) -> Weight {
+		(27_830_000 as Weight)
+			.saturating_add((412_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Erc1155 Parents (r:2 w:1)
+	// Storage: Erc1155 Tokens (r:3 w:0)
+	// Storage: Erc1155 Balances (r:3 w:2)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	// Storage: Erc1155 BalanceCheckpoints (r:2 w:2)
+	// Storage: Erc1155 BalanceCheckpointPages (r:2 w:2)
+	// Storage: Erc1155 ArchivedBalanceCheckpoints (r:0 w:2)
+	fn transfer_from_token(d: u32) -> Weight {
+		(56_340_000 as Weight)
+			.saturating_add((3_920_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	// Storage: Erc1155 Collections (r:1 w:0)
+	// Storage: Erc1155 MinBalances (r:0 w:1)
+	fn set_min_balance() -> Weight {
+		(18_470_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	// Storage: Erc1155 Collections (r:1 w:0)
+	// Storage: Erc1155 TransferFees (r:0 w:1)
+	fn set_transfer_fee() -> Weight {
+		(19_120_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	// Storage: Erc1155 Collections (r:1 w:0)
+	// Storage: Erc1155 FeeExemptions (r:0 w:1)
+	fn set_fee_exemption() -> Weight {
+		(18_930_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn init(b: u32) -> Weight {
+		(9_520_000 as Weight)
+			.saturating_add((21_310_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(b as Weight)))
+	}
+	fn transfer_from_single(d: u32) -> Weight {
+		(67_830_000 as Weight)
+			.saturating_add((3_920_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads(24 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(18 as Weight))
+	}
+	fn transfer_from_batch(b: u32, d: u32) -> Weight {
+		(14_870_000 as Weight)
+			.saturating_add((59_070_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(
+				(3_920_000 as Weight).saturating_mul(b as Weight).saturating_mul(d as Weight),
+			)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((22 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((b as Weight).saturating_mul(d as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((18 as Weight).saturating_mul(b as Weight)))
+	}
+	fn set_approval_for_all() -> Weight {
+		(17_040_000 as Weight).saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn enable_checkpoints() -> Weight {
+		(19_880_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn create_collection() -> Weight {
+		(15_120_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_collection_metadata() -> Weight {
+		(18_430_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_collection_admin() -> Weight {
+		(16_770_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn assign_to_collection() -> Weight {
+		(27_940_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn mint() -> Weight {
+		(55_210_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+	fn set_token_metadata() -> Weight {
+		(20_050_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn freeze() -> Weight {
+		(18_960_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn thaw() -> Weight {
+		(18_710_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_attribute() -> Weight {
+		(46_280_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn clear_attribute() -> Weight {
+		(39_610_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn set_max_supply(s: u32) -> Weight {
+		(27_830_000 as Weight)
+			.saturating_add((412_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn transfer_from_token(d: u32) -> Weight {
+		(56_340_000 as Weight)
+			.saturating_add((3_920_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn set_min_balance() -> Weight {
+		(18_470_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_transfer_fee() -> Weight {
+		(19_120_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_fee_exemption() -> Weight {
+		(18_930_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
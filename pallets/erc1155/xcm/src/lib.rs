@@ -0,0 +1,172 @@
+//! XCM support for `pallet_erc1155` tokens.
+//!
+//! Fungible tokens are identified as `Prefix/GeneralIndex(token_id)`, non-fungible tokens as
+//! instance `Index(token_id)` of the asset class `Prefix`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::traits::Get;
+use sp_runtime::traits::{One, Zero};
+use sp_std::{borrow::Borrow, marker::PhantomData, result};
+use traits::Erc1155Mintable;
+use xcm::latest::{prelude::*, Error as XcmError, Result as XcmResult};
+use xcm_executor::{
+	traits::{Convert, Error as MatchError, TransactAsset},
+	Assets,
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// A `MultiAsset` resolved to a token of `pallet_erc1155`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Erc1155Asset<TokenId, Balance> {
+	Fungible(TokenId, Balance),
+	/// The single unit of a token with a total supply of one.
+	NonFungible(TokenId),
+}
+
+pub trait MatchesErc1155<TokenId, Balance> {
+	fn matches_erc1155(
+		asset: &MultiAsset,
+	) -> result::Result<Erc1155Asset<TokenId, Balance>, MatchError>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<TokenId, Balance> MatchesErc1155<TokenId, Balance> for Tuple {
+	fn matches_erc1155(
+		asset: &MultiAsset,
+	) -> result::Result<Erc1155Asset<TokenId, Balance>, MatchError> {
+		for_tuples!( #(
+			match Tuple::matches_erc1155(asset) { o @ Ok(_) => return o, _ => () }
+		)* );
+		Err(MatchError::AssetNotFound)
+	}
+}
+
+/// Matches the tokens located under `Prefix`.
+pub struct AsErc1155<Prefix, TokenId, Balance, ConvertTokenId, ConvertBalance>(
+	PhantomData<(Prefix, TokenId, Balance, ConvertTokenId, ConvertBalance)>,
+);
+
+impl<Prefix, TokenId, Balance, ConvertTokenId, ConvertBalance> MatchesErc1155<TokenId, Balance>
+	for AsErc1155<Prefix, TokenId, Balance, ConvertTokenId, ConvertBalance>
+where
+	Prefix: Get<MultiLocation>,
+	ConvertTokenId: Convert<u128, TokenId>,
+	ConvertBalance: Convert<u128, Balance>,
+{
+	fn matches_erc1155(
+		asset: &MultiAsset,
+	) -> result::Result<Erc1155Asset<TokenId, Balance>, MatchError> {
+		let prefix = Prefix::get();
+		match asset {
+			MultiAsset { id: Concrete(location), fun: Fungible(amount) } => {
+				let index = match location.match_and_split(&prefix) {
+					Some(GeneralIndex(index)) => index,
+					_ => return Err(MatchError::AssetNotFound),
+				};
+				let token_id = ConvertTokenId::convert_ref(index)
+					.map_err(|()| MatchError::AssetIdConversionFailed)?;
+				let amount = ConvertBalance::convert_ref(amount)
+					.map_err(|()| MatchError::AmountToBalanceConversionFailed)?;
+				Ok(Erc1155Asset::Fungible(token_id, amount))
+			},
+			MultiAsset {
+				id: Concrete(location),
+				fun: NonFungible(AssetInstance::Index(index)),
+			} if location == &prefix => {
+				let token_id = ConvertTokenId::convert_ref(index)
+					.map_err(|()| MatchError::AssetIdConversionFailed)?;
+				Ok(Erc1155Asset::NonFungible(token_id))
+			},
+			_ => Err(MatchError::AssetNotFound),
+		}
+	}
+}
+
+/// `TransactAsset` implementation for `pallet_erc1155`. Deposits mint and withdrawals burn, so
+/// the receiving chain must have created the token beforehand. Neither uses up the supply cap
+/// of the token, a round trip leaves its mint allowance unchanged. Tokens sent to another chain
+/// through a reserve transfer stay in that chain's sovereign account.
+pub struct Erc1155Adapter<Tokens, Matcher, AccountIdConverter, AccountId>(
+	PhantomData<(Tokens, Matcher, AccountIdConverter, AccountId)>,
+);
+
+impl<Tokens, Matcher, AccountIdConverter, AccountId>
+	Erc1155Adapter<Tokens, Matcher, AccountIdConverter, AccountId>
+where
+	Tokens: Erc1155Mintable<AccountId>,
+	Matcher: MatchesErc1155<Tokens::TokenId, Tokens::Balance>,
+	AccountIdConverter: Convert<MultiLocation, AccountId>,
+{
+	fn account_id(location: impl Borrow<MultiLocation>) -> result::Result<AccountId, XcmError> {
+		AccountIdConverter::convert_ref(location)
+			.map_err(|()| MatchError::AccountIdConversionFailed.into())
+	}
+
+	/// Token id and amount `what` refers to. A non-fungible token must currently exist on this
+	/// chain with a total supply of one.
+	fn token(what: &MultiAsset) -> result::Result<(Tokens::TokenId, Tokens::Balance), XcmError> {
+		match Matcher::matches_erc1155(what)? {
+			Erc1155Asset::Fungible(token_id, amount) => Ok((token_id, amount)),
+			Erc1155Asset::NonFungible(token_id) => {
+				let supply = Tokens::total_supply(token_id).map_err(Self::error)?;
+				if !supply.is_one() {
+					return Err(XcmError::FailedToTransactAsset("token is not non-fungible"))
+				}
+				Ok((token_id, One::one()))
+			},
+		}
+	}
+
+	fn error(error: sp_runtime::DispatchError) -> XcmError {
+		XcmError::FailedToTransactAsset(error.into())
+	}
+}
+
+impl<Tokens, Matcher, AccountIdConverter, AccountId> TransactAsset
+	for Erc1155Adapter<Tokens, Matcher, AccountIdConverter, AccountId>
+where
+	Tokens: Erc1155Mintable<AccountId>,
+	Matcher: MatchesErc1155<Tokens::TokenId, Tokens::Balance>,
+	AccountIdConverter: Convert<MultiLocation, AccountId>,
+{
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation) -> XcmResult {
+		let who = Self::account_id(who)?;
+		let (token_id, amount) = match Matcher::matches_erc1155(what)? {
+			Erc1155Asset::Fungible(token_id, amount) => (token_id, amount),
+			Erc1155Asset::NonFungible(token_id) => {
+				// the instance only exists on one chain at a time
+				let supply = Tokens::total_supply(token_id).map_err(Self::error)?;
+				if !supply.is_zero() {
+					return Err(XcmError::FailedToTransactAsset("token is already issued"))
+				}
+				(token_id, One::one())
+			},
+		};
+		Tokens::deposit(&who, token_id, amount).map_err(Self::error)
+	}
+
+	fn withdraw_asset(what: &MultiAsset, who: &MultiLocation) -> result::Result<Assets, XcmError> {
+		let who = Self::account_id(who)?;
+		let (token_id, amount) = Self::token(what)?;
+		Tokens::withdraw(&who, token_id, amount).map_err(Self::error)?;
+		Ok(what.clone().into())
+	}
+
+	fn transfer_asset(
+		what: &MultiAsset,
+		from: &MultiLocation,
+		to: &MultiLocation,
+	) -> result::Result<Assets, XcmError> {
+		let from = Self::account_id(from)?;
+		let to = Self::account_id(to)?;
+		let (token_id, amount) = Self::token(what)?;
+		Tokens::transfer(&from, &to, token_id, amount).map_err(Self::error)?;
+		Ok(what.clone().into())
+	}
+}
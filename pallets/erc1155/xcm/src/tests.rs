@@ -0,0 +1,266 @@
+use crate::{
+	mock::{parachain::*, *},
+	Erc1155Asset, MatchesErc1155,
+};
+use frame_support::assert_ok;
+use xcm::{latest::prelude::*, VersionedXcm};
+use xcm_executor::traits::Error as MatchError;
+use xcm_simulator::TestExt;
+
+const TOKEN_ID: u32 = 1;
+const ITEM_ID: u32 = 7;
+const MAX_WEIGHT: u64 = 1_000_000_000;
+
+fn erc1155_index() -> u8 {
+	<Erc1155 as frame_support::traits::PalletInfoAccess>::index() as u8
+}
+
+/// Location of fungible `token_id` under `prefix`.
+fn token(prefix: MultiLocation, token_id: u32, amount: u128) -> MultiAsset {
+	let location = prefix.pushed_with_interior(GeneralIndex(token_id.into())).unwrap();
+	MultiAsset { id: Concrete(location), fun: Fungible(amount) }
+}
+
+fn item(prefix: MultiLocation, token_id: u32) -> MultiAsset {
+	MultiAsset { id: Concrete(prefix), fun: NonFungible(AssetInstance::Index(token_id.into())) }
+}
+
+fn account(who: &AccountId) -> MultiLocation {
+	AccountId32 { network: Any, id: who.clone().into() }.into()
+}
+
+fn execute(who: &AccountId, message: Xcm<Call>) {
+	assert_ok!(ParachainPalletXcm::execute(
+		Origin::signed(who.clone()),
+		Box::new(VersionedXcm::V2(message)),
+		MAX_WEIGHT
+	));
+}
+
+fn create(to: &AccountId, token_id: u32, amount: u128) {
+	assert_ok!(<Erc1155 as traits::Erc1155Mintable<AccountId>>::create(&ALICE, token_id));
+	if amount > 0 {
+		assert_ok!(<Erc1155 as traits::Erc1155Mintable<AccountId>>::mint(to, token_id, amount));
+	}
+}
+
+/// Send `asset` held by `from` on parachain 1 to `to` on parachain 2.
+fn transfer_to_para_b(from: &AccountId, asset: MultiAsset, to: &AccountId) {
+	execute(
+		from,
+		Xcm(vec![TransferReserveAsset {
+			assets: asset.into(),
+			dest: (Parent, Parachain(2)).into(),
+			xcm: Xcm(vec![DepositAsset {
+				assets: All.into(),
+				max_assets: 1,
+				beneficiary: account(to),
+			}]),
+		}]),
+	);
+}
+
+#[test]
+fn matcher_should_work() {
+	ParaA::execute_with(|| {
+		let local = LocalTokens::get();
+		assert_eq!(
+			Erc1155Matcher::matches_erc1155(&token(local.clone(), TOKEN_ID, 10)),
+			Ok(Erc1155Asset::Fungible(TOKEN_ID, 10))
+		);
+		assert_eq!(
+			Erc1155Matcher::matches_erc1155(&token(ParaATokens::get(), TOKEN_ID, 10)),
+			Ok(Erc1155Asset::Fungible(TOKEN_ID, 10))
+		);
+		assert_eq!(
+			Erc1155Matcher::matches_erc1155(&item(local.clone(), ITEM_ID)),
+			Ok(Erc1155Asset::NonFungible(ITEM_ID))
+		);
+		assert_eq!(
+			Erc1155Matcher::matches_erc1155(&token(Parent.into(), TOKEN_ID, 10)),
+			Err(MatchError::AssetNotFound)
+		);
+		// the non-fungible instance belongs to the class, not to a token location
+		assert_eq!(
+			Erc1155Matcher::matches_erc1155(&item(
+				local.pushed_with_interior(GeneralIndex(ITEM_ID.into())).unwrap(),
+				ITEM_ID
+			)),
+			Err(MatchError::AssetNotFound)
+		);
+		assert_eq!(
+			Erc1155Matcher::matches_erc1155(&MultiAsset {
+				id: Concrete(PalletInstance(erc1155_index()).into()),
+				fun: NonFungible(AssetInstance::Index(u128::MAX)),
+			}),
+			Err(MatchError::AssetIdConversionFailed)
+		);
+	});
+}
+
+#[test]
+fn reserve_transfer_should_work() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| create(&ALICE, TOKEN_ID, 1_000));
+	ParaB::execute_with(|| create(&ALICE, TOKEN_ID, 0));
+
+	ParaA::execute_with(|| {
+		transfer_to_para_b(&ALICE, token(LocalTokens::get(), TOKEN_ID, 400), &BOB);
+		assert_eq!(Erc1155::get_balance(ALICE, TOKEN_ID), 600);
+		assert_eq!(Erc1155::get_balance(sibling_account_id(2), TOKEN_ID), 400);
+	});
+
+	ParaB::execute_with(|| {
+		assert_eq!(Erc1155::get_balance(BOB, TOKEN_ID), 400);
+		assert_eq!(Erc1155::get_total_supply(TOKEN_ID), 400);
+		// send part of it back to the reserve
+		execute(
+			&BOB,
+			Xcm(vec![
+				WithdrawAsset(token(ParaATokens::get(), TOKEN_ID, 100).into()),
+				InitiateReserveWithdraw {
+					assets: All.into(),
+					reserve: (Parent, Parachain(1)).into(),
+					xcm: Xcm(vec![DepositAsset {
+						assets: All.into(),
+						max_assets: 1,
+						beneficiary: account(&ALICE),
+					}]),
+				},
+			]),
+		);
+		assert_eq!(Erc1155::get_balance(BOB, TOKEN_ID), 300);
+		assert_eq!(Erc1155::get_total_supply(TOKEN_ID), 300);
+	});
+
+	ParaA::execute_with(|| {
+		assert_eq!(Erc1155::get_balance(ALICE, TOKEN_ID), 700);
+		assert_eq!(Erc1155::get_balance(sibling_account_id(2), TOKEN_ID), 300);
+		assert_eq!(Erc1155::get_total_supply(TOKEN_ID), 1_000);
+	});
+}
+
+#[test]
+fn non_fungible_reserve_transfer_should_work() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| create(&ALICE, ITEM_ID, 1));
+	ParaB::execute_with(|| create(&ALICE, ITEM_ID, 0));
+
+	ParaA::execute_with(|| {
+		transfer_to_para_b(&ALICE, item(LocalTokens::get(), ITEM_ID), &BOB);
+		assert_eq!(Erc1155::get_balance(ALICE, ITEM_ID), 0);
+		assert_eq!(Erc1155::get_balance(sibling_account_id(2), ITEM_ID), 1);
+	});
+
+	ParaB::execute_with(|| {
+		assert_eq!(Erc1155::get_balance(BOB, ITEM_ID), 1);
+		assert_eq!(Erc1155::get_total_supply(ITEM_ID), 1);
+	});
+}
+
+#[test]
+fn non_fungible_deposit_should_fail_1() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| create(&ALICE, ITEM_ID, 1));
+	// the item already exists on parachain 2, a second instance must not be minted
+	ParaB::execute_with(|| create(&ALICE, ITEM_ID, 1));
+
+	ParaA::execute_with(|| transfer_to_para_b(&ALICE, item(LocalTokens::get(), ITEM_ID), &BOB));
+
+	ParaB::execute_with(|| {
+		assert_eq!(Erc1155::get_balance(BOB, ITEM_ID), 0);
+		assert_eq!(Erc1155::get_total_supply(ITEM_ID), 1);
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			Event::MsgQueue(mock_msg_queue::Event::Fail(
+				_,
+				XcmError::FailedToTransactAsset("token is already issued")
+			))
+		)));
+	});
+}
+
+/// Send `amount` of the reserve-backed token held by `from` on parachain 2 back to `to` on
+/// parachain 1.
+fn withdraw_to_para_a(from: &AccountId, amount: u128, to: &AccountId) {
+	execute(
+		from,
+		Xcm(vec![
+			WithdrawAsset(token(ParaATokens::get(), TOKEN_ID, amount).into()),
+			InitiateReserveWithdraw {
+				assets: All.into(),
+				reserve: (Parent, Parachain(1)).into(),
+				xcm: Xcm(vec![DepositAsset {
+					assets: All.into(),
+					max_assets: 1,
+					beneficiary: account(to),
+				}]),
+			},
+		]),
+	);
+}
+
+#[test]
+fn capped_token_round_trip_should_work() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| create(&ALICE, TOKEN_ID, 1_000));
+	ParaB::execute_with(|| {
+		create(&ALICE, TOKEN_ID, 0);
+		assert_ok!(Erc1155::set_max_supply(
+			Origin::signed(ALICE),
+			TOKEN_ID,
+			400,
+			Default::default()
+		));
+	});
+
+	for _ in 0..3 {
+		ParaA::execute_with(|| {
+			transfer_to_para_b(&ALICE, token(LocalTokens::get(), TOKEN_ID, 400), &BOB)
+		});
+		ParaB::execute_with(|| {
+			assert_eq!(Erc1155::get_balance(BOB, TOKEN_ID), 400);
+			withdraw_to_para_a(&BOB, 400, &ALICE);
+			assert_eq!(Erc1155::get_total_supply(TOKEN_ID), 0);
+		});
+	}
+
+	ParaB::execute_with(|| {
+		// deposits do not use up the mint allowance of the cap
+		assert_eq!(Erc1155::get_supply_cap(TOKEN_ID).unwrap().minted, 0);
+	});
+	ParaA::execute_with(|| {
+		assert_eq!(Erc1155::get_balance(ALICE, TOKEN_ID), 1_000);
+		assert_eq!(Erc1155::get_balance(sibling_account_id(2), TOKEN_ID), 0);
+	});
+}
+
+#[test]
+fn capped_token_deposit_should_fail_1() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| create(&ALICE, TOKEN_ID, 1_000));
+	ParaB::execute_with(|| {
+		create(&ALICE, TOKEN_ID, 0);
+		assert_ok!(Erc1155::set_max_supply(
+			Origin::signed(ALICE),
+			TOKEN_ID,
+			400,
+			Default::default()
+		));
+	});
+
+	ParaA::execute_with(|| {
+		transfer_to_para_b(&ALICE, token(LocalTokens::get(), TOKEN_ID, 500), &BOB)
+	});
+
+	// the total supply on parachain 2 may never exceed the cap
+	ParaB::execute_with(|| {
+		assert_eq!(Erc1155::get_balance(BOB, TOKEN_ID), 0);
+		assert_eq!(Erc1155::get_total_supply(TOKEN_ID), 0);
+	});
+}
@@ -0,0 +1,80 @@
+//! Benchmarking setup for pallet-fractionalization
+
+use super::*;
+
+use crate::Pallet as Fractionalization;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Currency;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use traits::{Erc1155, Erc1155Mintable};
+
+const SEED: u32 = 0;
+
+/// Fractionalize a fresh item owned by the caller into 1000 shares.
+fn setup<T: Config>() -> (T::AccountId, TokenIdOf<T>, TokenIdOf<T>)
+where
+	TokenIdOf<T>: From<u32>,
+{
+	let caller: T::AccountId = whitelisted_caller();
+	let item_id: TokenIdOf<T> = 0u32.into();
+	let share_token_id: TokenIdOf<T> = 1u32.into();
+	T::Tokens::create(&caller, item_id).unwrap();
+	T::Tokens::mint(&caller, item_id, 1u32.into()).unwrap();
+	Fractionalization::<T>::fractionalize(
+		RawOrigin::Signed(caller.clone()).into(),
+		item_id,
+		share_token_id,
+		1_000u32.into(),
+		1_000u32.into(),
+	)
+	.unwrap();
+	(caller, item_id, share_token_id)
+}
+
+benchmarks! {
+	where_clause { where TokenIdOf<T>: From<u32> }
+
+	fractionalize {
+		let caller: T::AccountId = whitelisted_caller();
+		let item_id: TokenIdOf<T> = 0u32.into();
+		T::Tokens::create(&caller, item_id)?;
+		T::Tokens::mint(&caller, item_id, 1u32.into())?;
+	}: _(RawOrigin::Signed(caller.clone()), item_id, 1u32.into(), 1_000u32.into(), 1_000u32.into())
+	verify {
+		assert!(Fractionalization::<T>::get_vault(TokenIdOf::<T>::from(1u32)).is_some());
+	}
+
+	redeem {
+		let (caller, item_id, share_token_id) = setup::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), share_token_id)
+	verify {
+		assert_eq!(T::Tokens::balance_of(&caller, item_id)?, 1u32.into());
+	}
+
+	buyout {
+		let (caller, item_id, share_token_id) = setup::<T>();
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		T::Tokens::transfer(&caller, &buyer, share_token_id, 100u32.into())?;
+		T::Currency::make_free_balance_be(&buyer, NativeBalanceOf::<T>::max_value());
+	}: _(RawOrigin::Signed(buyer.clone()), share_token_id, 1_000_000u32.into())
+	verify {
+		assert_eq!(T::Tokens::balance_of(&buyer, item_id)?, 1u32.into());
+	}
+
+	claim_proceeds {
+		let (caller, _, share_token_id) = setup::<T>();
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		T::Currency::make_free_balance_be(&buyer, NativeBalanceOf::<T>::max_value());
+		Fractionalization::<T>::buyout(
+			RawOrigin::Signed(buyer).into(),
+			share_token_id,
+			1_000_000u32.into(),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), share_token_id)
+	verify {
+		assert!(Fractionalization::<T>::get_vault(share_token_id).is_none());
+	}
+
+	impl_benchmark_test_suite!(Fractionalization, crate::mock::new_test_ext(), crate::mock::Test);
+}
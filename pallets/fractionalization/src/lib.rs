@@ -0,0 +1,301 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{Vault, VaultState, WeightInfo};
+	use frame_support::{
+		dispatch::DispatchResult,
+		ensure,
+		pallet_prelude::*,
+		traits::{Currency, ExistenceRequirement},
+		transactional, PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{AccountIdConversion, CheckedSub, One, Saturating, Zero},
+		Perbill,
+	};
+	use traits::{Erc1155, Erc1155Mintable};
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type TokenIdOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::TokenId;
+
+	pub type NativeBalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type VaultOf<T> = Vault<
+		<T as frame_system::Config>::AccountId,
+		TokenIdOf<T>,
+		BalanceOf<T>,
+		NativeBalanceOf<T>,
+	>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		type Tokens: Erc1155Mintable<Self::AccountId>;
+		/// Currency buyouts are paid in.
+		type Currency: Currency<Self::AccountId>;
+		/// Account holding fractionalized items, buyout proceeds are kept in sub-accounts.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// Vaults keyed by their share token id.
+	#[pallet::storage]
+	#[pallet::getter(fn get_vault)]
+	pub(super) type Vaults<T: Config> = StorageMap<_, Blake2_128Concat, TokenIdOf<T>, VaultOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		Fractionalized {
+			item_id: TokenIdOf<T>,
+			share_token_id: TokenIdOf<T>,
+			curator: T::AccountId,
+			shares: BalanceOf<T>,
+		},
+		Redeemed {
+			item_id: TokenIdOf<T>,
+			share_token_id: TokenIdOf<T>,
+			who: T::AccountId,
+		},
+		BoughtOut {
+			item_id: TokenIdOf<T>,
+			share_token_id: TokenIdOf<T>,
+			buyer: T::AccountId,
+			price: NativeBalanceOf<T>,
+		},
+		ProceedsClaimed {
+			share_token_id: TokenIdOf<T>,
+			who: T::AccountId,
+			shares: BalanceOf<T>,
+			amount: NativeBalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		NotNonFungible,
+		NotItemOwner,
+		ZeroShares,
+		UnknownVault,
+		IncompleteShares,
+		BuyoutPriceTooLow,
+		ReservePriceTooLow,
+		AlreadyBoughtOut,
+		NotBoughtOut,
+		NoShares,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Lock a non-fungible item in the pallet and mint `shares` of the new token
+		/// `share_token_id` to the sender. The reserve price has to reach the minimum balance of
+		/// the currency, the proceeds of a buyout open the vault account.
+		#[pallet::weight(T::WeightInfo::fractionalize())]
+		#[transactional]
+		pub fn fractionalize(
+			origin: OriginFor<T>,
+			item_id: TokenIdOf<T>,
+			share_token_id: TokenIdOf<T>,
+			shares: BalanceOf<T>,
+			reserve_price: NativeBalanceOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(!shares.is_zero(), Error::<T>::ZeroShares);
+			ensure!(
+				reserve_price >= T::Currency::minimum_balance(),
+				Error::<T>::ReservePriceTooLow
+			);
+			ensure!(T::Tokens::total_supply(item_id)?.is_one(), Error::<T>::NotNonFungible);
+			ensure!(T::Tokens::balance_of(&sender, item_id)?.is_one(), Error::<T>::NotItemOwner);
+			let account = Self::account_id();
+			T::Tokens::transfer(&sender, &account, item_id, One::one())?;
+			// the pallet issues the share token so nobody can mint more shares
+			T::Tokens::create(&account, share_token_id)?;
+			T::Tokens::mint(&sender, share_token_id, shares)?;
+			Vaults::<T>::insert(
+				&share_token_id,
+				Vault {
+					item_id,
+					curator: sender.clone(),
+					total_shares: shares,
+					reserve_price,
+					state: VaultState::Locked,
+				},
+			);
+			Self::deposit_event(Event::Fractionalized {
+				item_id,
+				share_token_id,
+				curator: sender,
+				shares,
+			});
+			Ok(())
+		}
+
+		/// Burn all shares of a vault to take the item back out.
+		#[pallet::weight(T::WeightInfo::redeem())]
+		#[transactional]
+		pub fn redeem(origin: OriginFor<T>, share_token_id: TokenIdOf<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let vault = Self::get_vault(&share_token_id).ok_or(Error::<T>::UnknownVault)?;
+			ensure!(vault.state == VaultState::Locked, Error::<T>::AlreadyBoughtOut);
+			ensure!(
+				T::Tokens::balance_of(&sender, share_token_id)? == vault.total_shares,
+				Error::<T>::IncompleteShares
+			);
+			T::Tokens::burn(&sender, share_token_id, vault.total_shares)?;
+			T::Tokens::transfer(&Self::account_id(), &sender, vault.item_id, One::one())?;
+			Vaults::<T>::remove(&share_token_id);
+			Self::deposit_event(Event::Redeemed {
+				item_id: vault.item_id,
+				share_token_id,
+				who: sender,
+			});
+			Ok(())
+		}
+
+		/// Buy the item for `price`, at least the reserve price. The buyer's own shares are burned
+		/// and count towards the price, the rest is paid into the vault for the other
+		/// shareholders to claim.
+		#[pallet::weight(T::WeightInfo::buyout())]
+		#[transactional]
+		pub fn buyout(
+			origin: OriginFor<T>,
+			share_token_id: TokenIdOf<T>,
+			price: NativeBalanceOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let vault = Self::get_vault(&share_token_id).ok_or(Error::<T>::UnknownVault)?;
+			ensure!(vault.state == VaultState::Locked, Error::<T>::AlreadyBoughtOut);
+			ensure!(price >= vault.reserve_price, Error::<T>::BuyoutPriceTooLow);
+			let own_shares = T::Tokens::balance_of(&sender, share_token_id)?;
+			if !own_shares.is_zero() {
+				T::Tokens::burn(&sender, share_token_id, own_shares)?;
+			}
+			let outstanding = vault
+				.total_shares
+				.checked_sub(&own_shares)
+				.ok_or(Error::<T>::IncompleteShares)?;
+			let proceeds = Perbill::from_rational(outstanding, vault.total_shares) * price;
+			if !proceeds.is_zero() {
+				T::Currency::transfer(
+					&sender,
+					&Self::vault_account_id(share_token_id),
+					proceeds,
+					ExistenceRequirement::AllowDeath,
+				)?;
+			}
+			T::Tokens::transfer(&Self::account_id(), &sender, vault.item_id, One::one())?;
+			if outstanding.is_zero() {
+				Vaults::<T>::remove(&share_token_id);
+			} else {
+				Vaults::<T>::insert(
+					&share_token_id,
+					Vault {
+						state: VaultState::BoughtOut { outstanding, proceeds },
+						..vault.clone()
+					},
+				);
+			}
+			Self::deposit_event(Event::BoughtOut {
+				item_id: vault.item_id,
+				share_token_id,
+				buyer: sender,
+				price,
+			});
+			Ok(())
+		}
+
+		/// Burn the sender's shares of a bought out vault for their part of the proceeds.
+		#[pallet::weight(T::WeightInfo::claim_proceeds())]
+		#[transactional]
+		pub fn claim_proceeds(
+			origin: OriginFor<T>,
+			share_token_id: TokenIdOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let vault = Self::get_vault(&share_token_id).ok_or(Error::<T>::UnknownVault)?;
+			let (outstanding, proceeds) = match vault.state {
+				VaultState::BoughtOut { outstanding, proceeds } => (outstanding, proceeds),
+				VaultState::Locked => return Err(Error::<T>::NotBoughtOut.into()),
+			};
+			let shares = T::Tokens::balance_of(&sender, share_token_id)?;
+			ensure!(!shares.is_zero(), Error::<T>::NoShares);
+			// the last claim takes whatever is left so no rounding dust stays behind
+			let amount = if shares >= outstanding {
+				proceeds
+			} else {
+				Perbill::from_rational(shares, outstanding) * proceeds
+			};
+			T::Tokens::burn(&sender, share_token_id, shares)?;
+			if !amount.is_zero() {
+				T::Currency::transfer(
+					&Self::vault_account_id(share_token_id),
+					&sender,
+					amount,
+					ExistenceRequirement::AllowDeath,
+				)?;
+			}
+			let outstanding = outstanding.saturating_sub(shares);
+			if outstanding.is_zero() {
+				Vaults::<T>::remove(&share_token_id);
+			} else {
+				Vaults::<T>::insert(
+					&share_token_id,
+					Vault {
+						state: VaultState::BoughtOut {
+							outstanding,
+							proceeds: proceeds.saturating_sub(amount),
+						},
+						..vault
+					},
+				);
+			}
+			Self::deposit_event(Event::ProceedsClaimed {
+				share_token_id,
+				who: sender,
+				shares,
+				amount,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding all fractionalized items.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// Account holding the buyout proceeds of a vault.
+		pub fn vault_account_id(share_token_id: TokenIdOf<T>) -> T::AccountId {
+			T::PalletId::get().into_sub_account(share_token_id)
+		}
+	}
+}
@@ -0,0 +1,190 @@
+use crate::{mock::*, Error, VaultState};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+
+fn balance_of(who: u64, token_id: u32) -> u128 {
+	Erc1155::get_balance(who, token_id)
+}
+
+fn transfer(from: u64, to: u64, token_id: u32, amount: u128) -> sp_runtime::DispatchResult {
+	<Erc1155 as traits::Erc1155<u64>>::transfer(&from, &to, token_id, amount)
+}
+
+fn fractionalize() {
+	assert_ok!(Fractionalization::fractionalize(
+		Origin::signed(ALICE),
+		ITEM_ID,
+		SHARE_ID,
+		100,
+		500
+	));
+}
+
+#[test]
+fn fractionalize_should_work() {
+	new_test_ext().execute_with(|| {
+		fractionalize();
+		System::assert_last_event(Event::Fractionalization(crate::Event::Fractionalized {
+			item_id: ITEM_ID,
+			share_token_id: SHARE_ID,
+			curator: ALICE,
+			shares: 100,
+		}));
+		assert_eq!(balance_of(Fractionalization::account_id(), ITEM_ID), 1);
+		assert_eq!(balance_of(ALICE, ITEM_ID), 0);
+		assert_eq!(balance_of(ALICE, SHARE_ID), 100);
+		assert_eq!(
+			Erc1155::get_token(SHARE_ID).unwrap().owner,
+			Some(Fractionalization::account_id())
+		);
+		assert_eq!(Fractionalization::get_vault(SHARE_ID).unwrap().state, VaultState::Locked);
+	});
+}
+
+#[test]
+fn fractionalize_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Fractionalization::fractionalize(Origin::signed(ALICE), ITEM_ID, SHARE_ID, 0, 500),
+			Error::<Test>::ZeroShares
+		);
+		assert_noop!(
+			Fractionalization::fractionalize(Origin::signed(ALICE), ITEM_ID, SHARE_ID, 100, 0),
+			Error::<Test>::ReservePriceTooLow
+		);
+		assert_noop!(
+			Fractionalization::fractionalize(Origin::signed(BOB), ITEM_ID, SHARE_ID, 100, 500),
+			Error::<Test>::NotItemOwner
+		);
+		<Erc1155 as traits::Erc1155Mintable<u64>>::mint(&ALICE, ITEM_ID, 1).unwrap();
+		assert_noop!(
+			Fractionalization::fractionalize(Origin::signed(ALICE), ITEM_ID, SHARE_ID, 100, 500),
+			Error::<Test>::NotNonFungible
+		);
+	});
+}
+
+#[test]
+fn fractionalize_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		// the share token must not exist yet
+		assert_noop!(
+			Fractionalization::fractionalize(Origin::signed(ALICE), ITEM_ID, ITEM_ID, 100, 500),
+			pallet_erc1155::Error::<Test>::AlreadyInitialized
+		);
+	});
+}
+
+#[test]
+fn redeem_should_work() {
+	new_test_ext().execute_with(|| {
+		fractionalize();
+		assert_ok!(transfer(ALICE, BOB, SHARE_ID, 40));
+		assert_noop!(
+			Fractionalization::redeem(Origin::signed(ALICE), SHARE_ID),
+			Error::<Test>::IncompleteShares
+		);
+		assert_ok!(transfer(BOB, ALICE, SHARE_ID, 40));
+		assert_ok!(Fractionalization::redeem(Origin::signed(ALICE), SHARE_ID));
+		assert_eq!(balance_of(ALICE, ITEM_ID), 1);
+		assert_eq!(balance_of(ALICE, SHARE_ID), 0);
+		assert_eq!(Erc1155::get_token(SHARE_ID).unwrap().supply, 0);
+		assert!(Fractionalization::get_vault(SHARE_ID).is_none());
+	});
+}
+
+#[test]
+fn buyout_should_work() {
+	new_test_ext().execute_with(|| {
+		fractionalize();
+		assert_ok!(transfer(ALICE, BOB, SHARE_ID, 40));
+		assert_ok!(transfer(ALICE, CHARLIE, SHARE_ID, 20));
+		assert_ok!(Fractionalization::buyout(Origin::signed(BOB), SHARE_ID, 500));
+		System::assert_last_event(Event::Fractionalization(crate::Event::BoughtOut {
+			item_id: ITEM_ID,
+			share_token_id: SHARE_ID,
+			buyer: BOB,
+			price: 500,
+		}));
+		// BOB's 40 shares count towards the price
+		assert_eq!(Balances::free_balance(BOB), 700);
+		assert_eq!(Balances::free_balance(Fractionalization::vault_account_id(SHARE_ID)), 300);
+		assert_eq!(balance_of(BOB, ITEM_ID), 1);
+		assert_eq!(balance_of(BOB, SHARE_ID), 0);
+		assert_eq!(
+			Fractionalization::get_vault(SHARE_ID).unwrap().state,
+			VaultState::BoughtOut { outstanding: 60, proceeds: 300 }
+		);
+	});
+}
+
+#[test]
+fn buyout_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Fractionalization::buyout(Origin::signed(BOB), SHARE_ID, 500),
+			Error::<Test>::UnknownVault
+		);
+		fractionalize();
+		assert_noop!(
+			Fractionalization::buyout(Origin::signed(BOB), SHARE_ID, 499),
+			Error::<Test>::BuyoutPriceTooLow
+		);
+		assert_noop!(
+			Fractionalization::buyout(Origin::signed(BOB), SHARE_ID, 1_001),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		// the buyer may spend their whole balance
+		assert_ok!(Fractionalization::buyout(Origin::signed(BOB), SHARE_ID, 1_000));
+		assert_eq!(Balances::free_balance(BOB), 0);
+		assert_noop!(
+			Fractionalization::buyout(Origin::signed(CHARLIE), SHARE_ID, 500),
+			Error::<Test>::AlreadyBoughtOut
+		);
+		assert_noop!(
+			Fractionalization::redeem(Origin::signed(ALICE), SHARE_ID),
+			Error::<Test>::AlreadyBoughtOut
+		);
+	});
+}
+
+#[test]
+fn claim_proceeds_should_work() {
+	new_test_ext().execute_with(|| {
+		fractionalize();
+		assert_ok!(transfer(ALICE, CHARLIE, SHARE_ID, 30));
+		let _ = Balances::deposit_creating(&BOB, 1_000);
+		assert_ok!(Fractionalization::buyout(Origin::signed(BOB), SHARE_ID, 1_000));
+
+		assert_ok!(Fractionalization::claim_proceeds(Origin::signed(CHARLIE), SHARE_ID));
+		System::assert_last_event(Event::Fractionalization(crate::Event::ProceedsClaimed {
+			share_token_id: SHARE_ID,
+			who: CHARLIE,
+			shares: 30,
+			amount: 300,
+		}));
+		assert_eq!(Balances::free_balance(CHARLIE), 1_300);
+		assert_eq!(balance_of(CHARLIE, SHARE_ID), 0);
+
+		assert_ok!(Fractionalization::claim_proceeds(Origin::signed(ALICE), SHARE_ID));
+		assert_eq!(Balances::free_balance(ALICE), 1_700);
+		assert_eq!(Balances::free_balance(Fractionalization::vault_account_id(SHARE_ID)), 0);
+		assert_eq!(Erc1155::get_token(SHARE_ID).unwrap().supply, 0);
+		assert!(Fractionalization::get_vault(SHARE_ID).is_none());
+	});
+}
+
+#[test]
+fn claim_proceeds_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		fractionalize();
+		assert_noop!(
+			Fractionalization::claim_proceeds(Origin::signed(ALICE), SHARE_ID),
+			Error::<Test>::NotBoughtOut
+		);
+		assert_ok!(Fractionalization::buyout(Origin::signed(BOB), SHARE_ID, 500));
+		assert_noop!(
+			Fractionalization::claim_proceeds(Origin::signed(CHARLIE), SHARE_ID),
+			Error::<Test>::NoShares
+		);
+	});
+}
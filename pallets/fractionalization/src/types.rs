@@ -0,0 +1,21 @@
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum VaultState<Balance, NativeBalance> {
+	/// The item is held by the pallet and can be redeemed or bought out.
+	Locked,
+	/// The item was bought out, shareholders can claim `proceeds` for `outstanding` shares.
+	BoughtOut { outstanding: Balance, proceeds: NativeBalance },
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Vault<AccountId, TokenId, Balance, NativeBalance> {
+	pub item_id: TokenId,
+	pub curator: AccountId,
+	pub total_shares: Balance,
+	/// Lowest price for which the whole item can be bought out.
+	pub reserve_price: NativeBalance,
+	pub state: VaultState<Balance, NativeBalance>,
+}
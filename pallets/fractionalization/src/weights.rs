@@ -0,0 +1,102 @@
+//! Weights for pallet_fractionalization.
+//!
+//! These are hand-written estimates that follow the storage accesses of each call, not the output
+//! of a benchmark run. Replace them with measured weights by running:
+//! ./target/release/node-template benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_fractionalization --extrinsic '*' --steps 50 --repeat 20
+//! --output ./pallets/fractionalization/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_fractionalization.
+pub trait WeightInfo {
+	fn fractionalize() -> Weight;
+	fn redeem() -> Weight;
+	fn buyout() -> Weight;
+	fn claim_proceeds() -> Weight;
+}
+
+/// Weights for pallet_fractionalization using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Erc1155 Tokens (r:3 w:1)
+	// Storage: Erc1155 TotalSupply (r:2 w:0)
+	// Storage: Erc1155 Balances (r:3 w:3)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:3 w:0)
+	// Storage: Erc1155 SupplyCaps (r:1 w:0)
+	// Storage: Fractionalization Vaults (r:0 w:1)
+	fn fractionalize() -> Weight {
+		(98_140_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Fractionalization Vaults (r:1 w:1)
+	// Storage: Erc1155 Tokens (r:2 w:1)
+	// Storage: Erc1155 TotalSupply (r:1 w:0)
+	// Storage: Erc1155 Balances (r:3 w:3)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:2 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:3 w:0)
+	fn redeem() -> Weight {
+		(81_570_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Fractionalization Vaults (r:1 w:1)
+	// Storage: Erc1155 Tokens (r:2 w:1)
+	// Storage: Erc1155 TotalSupply (r:1 w:0)
+	// Storage: Erc1155 Balances (r:3 w:3)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:2 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:3 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn buyout() -> Weight {
+		(112_690_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Fractionalization Vaults (r:1 w:1)
+	// Storage: Erc1155 Tokens (r:1 w:1)
+	// Storage: Erc1155 Balances (r:1 w:1)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn claim_proceeds() -> Weight {
+		(74_030_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn fractionalize() -> Weight {
+		(98_140_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn redeem() -> Weight {
+		(81_570_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn buyout() -> Weight {
+		(112_690_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	fn claim_proceeds() -> Weight {
+		(74_030_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+}
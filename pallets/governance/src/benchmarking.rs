@@ -0,0 +1,100 @@
+//! Benchmarking setup for pallet-governance
+
+use super::*;
+
+use crate::Pallet as Governance;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
+use frame_system::RawOrigin as SystemOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::{boxed::Box, vec};
+use traits::{Erc1155, Erc1155Mintable};
+
+const SEED: u32 = 0;
+
+/// Give the caller governance tokens and open a proposal to remark.
+fn setup<T: Config>() -> T::AccountId
+where
+	T::Tokens: Erc1155Mintable<T::AccountId>,
+{
+	let caller: T::AccountId = whitelisted_caller();
+	let token_id = T::GovernanceTokenId::get();
+	if T::Tokens::total_supply(token_id).is_err() {
+		T::Tokens::create(&caller, token_id).unwrap();
+	}
+	T::Tokens::mint(&caller, token_id, T::ProposalThreshold::get() + 1_000u32.into()).unwrap();
+	T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value() / 2u32.into());
+	let call: T::Proposal = frame_system::Call::<T>::remark { remark: vec![] }.into();
+	Governance::<T>::propose(SystemOrigin::Signed(caller.clone()).into(), Box::new(call)).unwrap();
+	caller
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T::Tokens: Erc1155Mintable<T::AccountId>,
+	}
+
+	propose {
+		let caller = setup::<T>();
+		let call: T::Proposal = frame_system::Call::<T>::remark { remark: vec![] }.into();
+	}: _(SystemOrigin::Signed(caller), Box::new(call))
+	verify {
+		assert!(Governance::<T>::get_referendum(1).is_some());
+	}
+
+	vote {
+		let caller = setup::<T>();
+	}: _(SystemOrigin::Signed(caller.clone()), 0, true, 1_000u32.into(), Conviction::Locked6x)
+	verify {
+		assert!(Governance::<T>::get_vote(0, &caller).is_some());
+	}
+
+	close {
+		let v in 1 .. T::MaxVoters::get();
+		let caller = setup::<T>();
+		let balance = T::Tokens::balance_of(&caller, T::GovernanceTokenId::get())?;
+		Governance::<T>::vote(
+			SystemOrigin::Signed(caller.clone()).into(),
+			0,
+			true,
+			balance,
+			Conviction::None,
+		)?;
+		for i in 1 .. v {
+			let voter: T::AccountId = account("voter", i, SEED);
+			T::Tokens::mint(&voter, T::GovernanceTokenId::get(), 1u32.into())?;
+			Governance::<T>::vote(
+				SystemOrigin::Signed(voter).into(),
+				0,
+				false,
+				1u32.into(),
+				Conviction::None,
+			)?;
+		}
+		let end = Governance::<T>::get_referendum(0).unwrap().end;
+		frame_system::Pallet::<T>::set_block_number(end);
+	}: _(SystemOrigin::Signed(caller.clone()), 0, 1_000_000_000)
+	verify {
+		assert!(Governance::<T>::get_referendum(0).is_none());
+		assert!(Governance::<T>::get_vote(0, &caller).is_none());
+	}
+
+	unlock {
+		let caller = setup::<T>();
+		Governance::<T>::vote(
+			SystemOrigin::Signed(caller.clone()).into(),
+			0,
+			true,
+			1_000u32.into(),
+			Conviction::Locked6x,
+		)?;
+		let until = Governance::<T>::get_voting_lock(&caller).unwrap().until;
+		frame_system::Pallet::<T>::set_block_number(until);
+	}: _(SystemOrigin::Signed(caller.clone()))
+	verify {
+		assert!(Governance::<T>::get_voting_lock(&caller).is_none());
+	}
+
+	impl_benchmark_test_suite!(Governance, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,365 @@
+//! Token-weighted governance over `pallet_erc1155` balances.
+//!
+//! Holders of the configured governance token propose calls and vote on them with part of their
+//! balance. The voted tokens stay locked until the vote ends, or longer in exchange for a
+//! conviction multiplier. After the voting period anybody can close a proposal: if the turnout
+//! reaches the quorum and the ayes outweigh the nays, its call is dispatched from
+//! `RawOrigin::Approved`, which other pallets accept through `EnsureGovernance`. Closing removes
+//! the votes and returns the deposit the proposer reserved.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{Conviction, Referendum, Tally, Vote, VotingLock, WeightInfo};
+	use frame_support::{
+		dispatch::{DispatchResult, Dispatchable, GetDispatchInfo, PostDispatchInfo},
+		ensure,
+		pallet_prelude::*,
+		storage::{with_transaction, TransactionOutcome},
+		traits::{Currency, ReservableCurrency},
+		transactional,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{Saturating, Zero},
+		Perbill,
+	};
+	use sp_std::boxed::Box;
+	use traits::{Erc1155, Erc1155Lockable, LockIdentifier};
+
+	pub const GOVERNANCE_ID: LockIdentifier = *b"govern  ";
+
+	pub type ProposalId = u64;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type TokenIdOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::TokenId;
+
+	pub type DepositBalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type ReferendumOf<T> = Referendum<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::Proposal,
+		BalanceOf<T>,
+		DepositBalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	pub type VotingLockOf<T> = VotingLock<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		type Tokens: Erc1155Lockable<Self::AccountId>;
+		/// The outer origin, approved proposals are dispatched from `RawOrigin::Approved`.
+		type Origin: From<RawOrigin>;
+		/// Calls holders can propose.
+		type Proposal: Parameter
+			+ Dispatchable<Origin = <Self as Config>::Origin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+		/// Token whose balances are the voting power.
+		#[pallet::constant]
+		type GovernanceTokenId: Get<TokenIdOf<Self>>;
+		/// Governance tokens needed to propose.
+		#[pallet::constant]
+		type ProposalThreshold: Get<BalanceOf<Self>>;
+		/// Currency of the proposal deposit.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Reserved from the proposer until the proposal is closed, pays for storing it and its
+		/// votes.
+		#[pallet::constant]
+		type ProposalDeposit: Get<DepositBalanceOf<Self>>;
+		/// Maximum number of votes on a proposal, bounds the votes removed when it is closed.
+		#[pallet::constant]
+		type MaxVoters: Get<u32>;
+		#[pallet::constant]
+		type VotingPeriod: Get<Self::BlockNumber>;
+		/// Base period conviction locks are multiples of.
+		#[pallet::constant]
+		type LockPeriod: Get<Self::BlockNumber>;
+		/// Share of the token supply that has to vote for a proposal to pass.
+		#[pallet::constant]
+		type Quorum: Get<Perbill>;
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Origin of the calls of approved proposals.
+	#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo)]
+	pub enum RawOrigin {
+		Approved,
+	}
+
+	#[pallet::origin]
+	pub type Origin = RawOrigin;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_proposal_id)]
+	pub(super) type NextProposalId<T: Config> = StorageValue<_, ProposalId, ValueQuery>;
+
+	/// Proposals that have not been closed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn get_referendum)]
+	pub(super) type Referenda<T: Config> =
+		StorageMap<_, Blake2_128Concat, ProposalId, ReferendumOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_vote)]
+	pub(super) type Votes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		Blake2_128Concat,
+		T::AccountId,
+		Vote<BalanceOf<T>>,
+	>;
+
+	/// The largest balance an account voted with and when it can be unlocked.
+	#[pallet::storage]
+	#[pallet::getter(fn get_voting_lock)]
+	pub(super) type VotingLocks<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, VotingLockOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		Proposed {
+			proposal_id: ProposalId,
+			proposer: T::AccountId,
+			end: T::BlockNumber,
+		},
+		Voted {
+			proposal_id: ProposalId,
+			voter: T::AccountId,
+			aye: bool,
+			balance: BalanceOf<T>,
+			conviction: Conviction,
+		},
+		/// The proposal passed and its call was dispatched with `result`.
+		Approved {
+			proposal_id: ProposalId,
+			result: DispatchResult,
+		},
+		Rejected {
+			proposal_id: ProposalId,
+		},
+		Unlocked {
+			who: T::AccountId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account holds fewer governance tokens than required.
+		InsufficientBalance,
+		ZeroBalance,
+		UnknownProposal,
+		VotingClosed,
+		VotingOpen,
+		AlreadyVoted,
+		/// The proposal has `Config::MaxVoters` votes.
+		TooManyVoters,
+		/// The voting lock has not expired yet.
+		StillLocked,
+		NotLocked,
+		/// The weight bound is below the weight of the proposed call.
+		WrongProposalWeight,
+		Overflow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Put `call` to the vote of the governance token holders.
+		#[pallet::weight(T::WeightInfo::propose())]
+		#[transactional]
+		pub fn propose(origin: OriginFor<T>, call: Box<T::Proposal>) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			ensure!(
+				T::Tokens::balance_of(&proposer, T::GovernanceTokenId::get())? >=
+					T::ProposalThreshold::get(),
+				Error::<T>::InsufficientBalance
+			);
+			let deposit = T::ProposalDeposit::get();
+			T::Currency::reserve(&proposer, deposit)?;
+			let proposal_id = Self::get_next_proposal_id();
+			NextProposalId::<T>::put(proposal_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+			let end =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::VotingPeriod::get());
+			Referenda::<T>::insert(
+				proposal_id,
+				Referendum {
+					proposer: proposer.clone(),
+					call: *call,
+					deposit,
+					end,
+					tally: Tally::default(),
+					voters: 0,
+				},
+			);
+			Self::deposit_event(Event::Proposed { proposal_id, proposer, end });
+			Ok(())
+		}
+
+		/// Vote with `balance` governance tokens, which stay locked until the vote ends plus the
+		/// lock periods of `conviction`.
+		#[pallet::weight(T::WeightInfo::vote())]
+		#[transactional]
+		pub fn vote(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			aye: bool,
+			balance: BalanceOf<T>,
+			conviction: Conviction,
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+			ensure!(!balance.is_zero(), Error::<T>::ZeroBalance);
+			let mut referendum =
+				Self::get_referendum(proposal_id).ok_or(Error::<T>::UnknownProposal)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < referendum.end,
+				Error::<T>::VotingClosed
+			);
+			ensure!(!Votes::<T>::contains_key(proposal_id, &voter), Error::<T>::AlreadyVoted);
+			ensure!(referendum.voters < T::MaxVoters::get(), Error::<T>::TooManyVoters);
+			let token_id = T::GovernanceTokenId::get();
+			ensure!(
+				T::Tokens::balance_of(&voter, token_id)? >= balance,
+				Error::<T>::InsufficientBalance
+			);
+
+			let until = referendum.end.saturating_add(
+				T::LockPeriod::get().saturating_mul(conviction.lock_periods().into()),
+			);
+			let lock = match Self::get_voting_lock(&voter) {
+				Some(lock) =>
+					VotingLock { amount: lock.amount.max(balance), until: lock.until.max(until) },
+				None => VotingLock { amount: balance, until },
+			};
+			T::Tokens::set_lock(GOVERNANCE_ID, &voter, token_id, lock.amount)?;
+			VotingLocks::<T>::insert(&voter, lock);
+
+			let vote = Vote { aye, balance, conviction };
+			referendum.tally.add(&vote);
+			referendum.voters += 1;
+			Referenda::<T>::insert(proposal_id, referendum);
+			Votes::<T>::insert(proposal_id, &voter, vote);
+			Self::deposit_event(Event::Voted { proposal_id, voter, aye, balance, conviction });
+			Ok(())
+		}
+
+		/// Count the votes of an ended proposal and dispatch its call if it passed, anybody can
+		/// close it. `weight_bound` has to cover the weight of the call.
+		#[pallet::weight(T::WeightInfo::close(T::MaxVoters::get()).saturating_add(*weight_bound))]
+		pub fn close(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			#[pallet::compact] weight_bound: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let referendum =
+				Self::get_referendum(proposal_id).ok_or(Error::<T>::UnknownProposal)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= referendum.end,
+				Error::<T>::VotingOpen
+			);
+			let close_weight = T::WeightInfo::close(referendum.voters);
+			// without the governance token the votes can't be counted, the proposal fails
+			if !Self::is_approved(&referendum.tally).unwrap_or(false) {
+				Self::remove_referendum(proposal_id, &referendum);
+				Self::deposit_event(Event::Rejected { proposal_id });
+				return Ok(Some(close_weight).into())
+			}
+			let call_weight = referendum.call.get_dispatch_info().weight;
+			ensure!(call_weight <= weight_bound, Error::<T>::WrongProposalWeight);
+			Self::remove_referendum(proposal_id, &referendum);
+			// a failed call leaves no changes behind, the referendum is closed either way
+			let result =
+				with_transaction(|| match referendum.call.dispatch(RawOrigin::Approved.into()) {
+					Ok(info) => TransactionOutcome::Commit(Ok(info)),
+					Err(err) => TransactionOutcome::Rollback(Err(err)),
+				});
+			let actual_weight = match result {
+				Ok(info) => info.actual_weight,
+				Err(err) => err.post_info.actual_weight,
+			}
+			.unwrap_or(call_weight);
+			Self::deposit_event(Event::Approved {
+				proposal_id,
+				result: result.map(|_| ()).map_err(|err| err.error),
+			});
+			Ok(Some(close_weight.saturating_add(actual_weight)).into())
+		}
+
+		/// Release the governance tokens of the caller once all its voting locks expired.
+		#[pallet::weight(T::WeightInfo::unlock())]
+		pub fn unlock(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let lock = Self::get_voting_lock(&who).ok_or(Error::<T>::NotLocked)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= lock.until,
+				Error::<T>::StillLocked
+			);
+			T::Tokens::remove_lock(GOVERNANCE_ID, &who, T::GovernanceTokenId::get());
+			VotingLocks::<T>::remove(&who);
+			Self::deposit_event(Event::Unlocked { who });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Remove a closed proposal with its votes and return the deposit of the proposer.
+		fn remove_referendum(proposal_id: ProposalId, referendum: &ReferendumOf<T>) {
+			Referenda::<T>::remove(proposal_id);
+			Votes::<T>::remove_prefix(proposal_id, Some(referendum.voters));
+			T::Currency::unreserve(&referendum.proposer, referendum.deposit);
+		}
+
+		/// Whether the turnout reaches the quorum and the ayes outweigh the nays.
+		pub fn is_approved(tally: &Tally<BalanceOf<T>>) -> Result<bool, DispatchError> {
+			let total_supply = T::Tokens::total_supply(T::GovernanceTokenId::get())?;
+			Ok(tally.turnout >= T::Quorum::get().mul_ceil(total_supply) && tally.ayes > tally.nays)
+		}
+	}
+}
+
+/// Ensures the origin of an approved governance proposal.
+pub struct EnsureGovernance;
+
+impl<O: Into<Result<RawOrigin, O>> + From<RawOrigin>> frame_support::traits::EnsureOrigin<O>
+	for EnsureGovernance
+{
+	type Success = ();
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().map(|RawOrigin::Approved| ())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> O {
+		O::from(RawOrigin::Approved)
+	}
+}
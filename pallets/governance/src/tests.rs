@@ -0,0 +1,263 @@
+use crate::{mock::*, Conviction, Error, Tally, VotingLock};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError, Perbill};
+use traits::Erc1155Lockable;
+
+const DAVE: u64 = 4;
+
+fn transfer(from: u64, to: u64, token_id: u32, amount: u128) -> sp_runtime::DispatchResult {
+	<Erc1155 as traits::Erc1155<u64>>::transfer(&from, &to, token_id, amount)
+}
+
+/// Proposal to set the dex fee to `percent`.
+fn set_fee_call(percent: u32) -> Box<Call> {
+	Box::new(Call::Dex(pallet_dex::Call::set_fee { fee: Perbill::from_percent(percent) }))
+}
+
+fn propose(call: Box<Call>) {
+	assert_ok!(Governance::propose(Origin::signed(ALICE), call));
+}
+
+#[test]
+fn propose_should_work() {
+	new_test_ext().execute_with(|| {
+		propose(set_fee_call(2));
+		System::assert_last_event(Event::Governance(crate::Event::Proposed {
+			proposal_id: 0,
+			proposer: ALICE,
+			end: 11,
+		}));
+		let referendum = Governance::get_referendum(0).unwrap();
+		assert_eq!(referendum.end, 11);
+		assert_eq!(referendum.tally, Tally::default());
+		assert_eq!(referendum.deposit, 100);
+		assert_eq!(Balances::reserved_balance(ALICE), 100);
+		assert_eq!(Governance::get_next_proposal_id(), 1);
+	});
+}
+
+#[test]
+fn propose_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(transfer(CHARLIE, DAVE, GOV_ID, 9));
+		assert_noop!(
+			Governance::propose(Origin::signed(DAVE), set_fee_call(2)),
+			Error::<Test>::InsufficientBalance
+		);
+		// the threshold is met but the deposit can't be reserved
+		assert_ok!(transfer(CHARLIE, DAVE, GOV_ID, 1));
+		assert_noop!(
+			Governance::propose(Origin::signed(DAVE), set_fee_call(2)),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn vote_should_work() {
+	new_test_ext().execute_with(|| {
+		propose(set_fee_call(2));
+		assert_ok!(Governance::vote(Origin::signed(ALICE), 0, true, 100, Conviction::Locked2x));
+		assert_ok!(Governance::vote(Origin::signed(BOB), 0, false, 300, Conviction::None));
+		System::assert_last_event(Event::Governance(crate::Event::Voted {
+			proposal_id: 0,
+			voter: BOB,
+			aye: false,
+			balance: 300,
+			conviction: Conviction::None,
+		}));
+		assert_eq!(
+			Governance::get_referendum(0).unwrap().tally,
+			Tally { ayes: 200, nays: 30, turnout: 400 }
+		);
+		// locked until the end of the vote plus two lock periods
+		assert_eq!(Governance::get_voting_lock(ALICE), Some(VotingLock { amount: 100, until: 21 }));
+		assert_eq!(Erc1155::locked_balance(&ALICE, GOV_ID), 100);
+		assert_noop!(
+			transfer(ALICE, DAVE, GOV_ID, 501),
+			pallet_erc1155::Error::<Test>::LiquidityRestrictions
+		);
+		assert_ok!(transfer(ALICE, DAVE, GOV_ID, 500));
+	});
+}
+
+#[test]
+fn vote_should_work_across_proposals() {
+	new_test_ext().execute_with(|| {
+		propose(set_fee_call(2));
+		propose(set_fee_call(3));
+		assert_ok!(Governance::vote(Origin::signed(ALICE), 0, true, 100, Conviction::Locked2x));
+		assert_ok!(Governance::vote(Origin::signed(ALICE), 1, true, 300, Conviction::None));
+		// the same tokens vote on both, the lock covers the largest vote and the longest period
+		assert_eq!(Governance::get_voting_lock(ALICE), Some(VotingLock { amount: 300, until: 21 }));
+		assert_eq!(Erc1155::locked_balance(&ALICE, GOV_ID), 300);
+	});
+}
+
+#[test]
+fn vote_should_fail() {
+	new_test_ext().execute_with(|| {
+		propose(set_fee_call(2));
+		assert_noop!(
+			Governance::vote(Origin::signed(ALICE), 0, true, 0, Conviction::None),
+			Error::<Test>::ZeroBalance
+		);
+		assert_noop!(
+			Governance::vote(Origin::signed(ALICE), 1, true, 100, Conviction::None),
+			Error::<Test>::UnknownProposal
+		);
+		assert_noop!(
+			Governance::vote(Origin::signed(CHARLIE), 0, true, 101, Conviction::None),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_ok!(Governance::vote(Origin::signed(CHARLIE), 0, true, 100, Conviction::None));
+		assert_noop!(
+			Governance::vote(Origin::signed(CHARLIE), 0, false, 100, Conviction::None),
+			Error::<Test>::AlreadyVoted
+		);
+		System::set_block_number(11);
+		assert_noop!(
+			Governance::vote(Origin::signed(BOB), 0, true, 100, Conviction::None),
+			Error::<Test>::VotingClosed
+		);
+	});
+}
+
+#[test]
+fn vote_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(transfer(CHARLIE, DAVE, GOV_ID, 10));
+		propose(set_fee_call(2));
+		for voter in [ALICE, BOB, CHARLIE] {
+			assert_ok!(Governance::vote(Origin::signed(voter), 0, true, 10, Conviction::None));
+		}
+		assert_noop!(
+			Governance::vote(Origin::signed(DAVE), 0, true, 10, Conviction::None),
+			Error::<Test>::TooManyVoters
+		);
+		assert_eq!(Governance::get_referendum(0).unwrap().voters, 3);
+	});
+}
+
+#[test]
+fn close_should_work() {
+	new_test_ext().execute_with(|| {
+		propose(set_fee_call(2));
+		assert_ok!(Governance::vote(Origin::signed(ALICE), 0, true, 300, Conviction::None));
+		assert_ok!(Governance::vote(Origin::signed(BOB), 0, false, 100, Conviction::None));
+		assert_noop!(
+			Governance::close(Origin::signed(CHARLIE), 0, 1_000),
+			Error::<Test>::VotingOpen
+		);
+		System::set_block_number(11);
+		assert_noop!(
+			Governance::close(Origin::signed(CHARLIE), 0, 0),
+			Error::<Test>::WrongProposalWeight
+		);
+		assert_ok!(Governance::close(Origin::signed(CHARLIE), 0, 1_000));
+		System::assert_last_event(Event::Governance(crate::Event::Approved {
+			proposal_id: 0,
+			result: Ok(()),
+		}));
+		assert_eq!(Dex::fee(), Perbill::from_percent(98));
+		assert!(Governance::get_referendum(0).is_none());
+		assert_eq!(Governance::get_vote(0, ALICE), None);
+		assert_eq!(Governance::get_vote(0, BOB), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_noop!(
+			Governance::close(Origin::signed(CHARLIE), 0, 1_000),
+			Error::<Test>::UnknownProposal
+		);
+	});
+}
+
+#[test]
+fn close_should_work_for_pause() {
+	new_test_ext().execute_with(|| {
+		// only approved proposals can pause the pool
+		assert_noop!(Dex::set_paused(Origin::signed(ALICE), true), DispatchError::BadOrigin);
+		assert_noop!(Dex::set_paused(Origin::root(), true), DispatchError::BadOrigin);
+		propose(Box::new(Call::Dex(pallet_dex::Call::set_paused { paused: true })));
+		assert_ok!(Governance::vote(Origin::signed(BOB), 0, true, 300, Conviction::Locked1x));
+		System::set_block_number(11);
+		assert_ok!(Governance::close(Origin::signed(CHARLIE), 0, 1_000));
+		assert!(Dex::is_paused());
+	});
+}
+
+#[test]
+fn close_should_reject_below_quorum() {
+	new_test_ext().execute_with(|| {
+		propose(set_fee_call(2));
+		// 100 of 1000 tokens voted, the quorum is 200
+		assert_ok!(Governance::vote(Origin::signed(CHARLIE), 0, true, 100, Conviction::Locked6x));
+		System::set_block_number(11);
+		assert_ok!(Governance::close(Origin::signed(CHARLIE), 0, 1_000));
+		System::assert_last_event(Event::Governance(crate::Event::Rejected { proposal_id: 0 }));
+		assert_eq!(Dex::fee(), Perbill::from_percent(99));
+		assert!(Governance::get_referendum(0).is_none());
+		assert_eq!(Governance::get_vote(0, CHARLIE), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn close_should_reject_by_conviction() {
+	new_test_ext().execute_with(|| {
+		propose(set_fee_call(2));
+		// 300 tokens without conviction are outweighed by 100 tokens locked for one period
+		assert_ok!(Governance::vote(Origin::signed(ALICE), 0, true, 300, Conviction::None));
+		assert_ok!(Governance::vote(Origin::signed(CHARLIE), 0, false, 100, Conviction::Locked1x));
+		System::set_block_number(11);
+		assert_ok!(Governance::close(Origin::signed(BOB), 0, 1_000));
+		System::assert_last_event(Event::Governance(crate::Event::Rejected { proposal_id: 0 }));
+		assert_eq!(Dex::fee(), Perbill::from_percent(99));
+	});
+}
+
+#[test]
+fn close_should_reject_without_governance_token() {
+	new_test_ext().execute_with(|| {
+		propose(set_fee_call(2));
+		assert_ok!(Governance::vote(Origin::signed(ALICE), 0, true, 300, Conviction::None));
+		System::set_block_number(11);
+		// the votes of a governance token that doesn't exist can't be counted
+		GovernanceTokenId::set(&99);
+		assert_ok!(Governance::close(Origin::signed(CHARLIE), 0, 1_000));
+		System::assert_last_event(Event::Governance(crate::Event::Rejected { proposal_id: 0 }));
+		assert_eq!(Dex::fee(), Perbill::from_percent(99));
+		assert!(Governance::get_referendum(0).is_none());
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn unlock_should_work() {
+	new_test_ext().execute_with(|| {
+		propose(set_fee_call(2));
+		assert_ok!(Governance::vote(Origin::signed(ALICE), 0, true, 300, Conviction::Locked1x));
+		System::set_block_number(15);
+		assert_noop!(Governance::unlock(Origin::signed(ALICE)), Error::<Test>::StillLocked);
+		System::set_block_number(16);
+		assert_ok!(Governance::unlock(Origin::signed(ALICE)));
+		System::assert_last_event(Event::Governance(crate::Event::Unlocked { who: ALICE }));
+		assert_eq!(Governance::get_voting_lock(ALICE), None);
+		assert_eq!(Erc1155::locked_balance(&ALICE, GOV_ID), 0);
+		assert_ok!(transfer(ALICE, DAVE, GOV_ID, 600));
+	});
+}
+
+#[test]
+fn unlock_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Governance::unlock(Origin::signed(ALICE)), Error::<Test>::NotLocked);
+	});
+}
+
+#[test]
+fn conviction_votes_should_work() {
+	assert_eq!(Conviction::None.votes(105u128), 10);
+	assert_eq!(Conviction::Locked1x.votes(105u128), 105);
+	assert_eq!(Conviction::Locked6x.votes(105u128), 630);
+	assert_eq!(Conviction::Locked6x.lock_periods(), 32);
+}
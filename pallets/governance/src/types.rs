@@ -0,0 +1,110 @@
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Saturating},
+	RuntimeDebug,
+};
+
+/// How long tokens stay locked after the vote ends, in exchange for more votes per token.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum Conviction {
+	/// 0.1 votes per token, locked until the vote ends.
+	None,
+	/// 1 vote per token, locked for one lock period.
+	Locked1x,
+	/// 2 votes per token, locked for 2 lock periods.
+	Locked2x,
+	/// 3 votes per token, locked for 4 lock periods.
+	Locked3x,
+	/// 4 votes per token, locked for 8 lock periods.
+	Locked4x,
+	/// 5 votes per token, locked for 16 lock periods.
+	Locked5x,
+	/// 6 votes per token, locked for 32 lock periods.
+	Locked6x,
+}
+
+impl Default for Conviction {
+	fn default() -> Self {
+		Conviction::None
+	}
+}
+
+impl Conviction {
+	/// Lock periods the tokens stay locked after the vote ends.
+	pub fn lock_periods(self) -> u32 {
+		match self {
+			Conviction::None => 0,
+			Conviction::Locked1x => 1,
+			Conviction::Locked2x => 2,
+			Conviction::Locked3x => 4,
+			Conviction::Locked4x => 8,
+			Conviction::Locked5x => 16,
+			Conviction::Locked6x => 32,
+		}
+	}
+
+	/// Votes `balance` is worth at this conviction.
+	pub fn votes<Balance: AtLeast32BitUnsigned>(self, balance: Balance) -> Balance {
+		let multiplier: u32 = match self {
+			Conviction::None => return balance / 10u32.into(),
+			Conviction::Locked1x => 1,
+			Conviction::Locked2x => 2,
+			Conviction::Locked3x => 3,
+			Conviction::Locked4x => 4,
+			Conviction::Locked5x => 5,
+			Conviction::Locked6x => 6,
+		};
+		balance.saturating_mul(multiplier.into())
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Vote<Balance> {
+	pub aye: bool,
+	pub balance: Balance,
+	pub conviction: Conviction,
+}
+
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Tally<Balance> {
+	/// Conviction weighted votes in favour.
+	pub ayes: Balance,
+	/// Conviction weighted votes against.
+	pub nays: Balance,
+	/// Tokens voted with, regardless of conviction, counted against the quorum.
+	pub turnout: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> Tally<Balance> {
+	pub fn add(&mut self, vote: &Vote<Balance>) {
+		let votes = vote.conviction.votes(vote.balance);
+		if vote.aye {
+			self.ayes = self.ayes.saturating_add(votes);
+		} else {
+			self.nays = self.nays.saturating_add(votes);
+		}
+		self.turnout = self.turnout.saturating_add(vote.balance);
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Referendum<AccountId, Call, Balance, DepositBalance, BlockNumber> {
+	pub proposer: AccountId,
+	/// Dispatched from the governance origin if the proposal passes.
+	pub call: Call,
+	/// Reserved from the proposer until the proposal is closed.
+	pub deposit: DepositBalance,
+	/// Voting closes at the start of this block.
+	pub end: BlockNumber,
+	pub tally: Tally<Balance>,
+	/// Number of votes cast, each is stored until the proposal is closed.
+	pub voters: u32,
+}
+
+/// Governance tokens of an account locked by its votes.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VotingLock<Balance, BlockNumber> {
+	pub amount: Balance,
+	pub until: BlockNumber,
+}
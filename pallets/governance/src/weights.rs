@@ -0,0 +1,92 @@
+//! Weights for pallet_governance.
+//!
+//! These are hand-written estimates that follow the storage accesses of each call, not the output
+//! of a benchmark run. Replace them with measured weights by running:
+//! ./target/release/node-template benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_governance --extrinsic '*' --steps 50 --repeat 20
+//! --output ./pallets/governance/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_governance.
+pub trait WeightInfo {
+	fn propose() -> Weight;
+	fn vote() -> Weight;
+	fn close(v: u32) -> Weight;
+	fn unlock() -> Weight;
+}
+
+/// Weights for pallet_governance using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Erc1155 Balances (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Governance NextProposalId (r:1 w:1)
+	// Storage: Governance Referenda (r:0 w:1)
+	fn propose() -> Weight {
+		(41_870_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Governance Referenda (r:1 w:1)
+	// Storage: Governance Votes (r:1 w:1)
+	// Storage: Governance VotingLocks (r:1 w:1)
+	// Storage: Erc1155 Balances (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:1)
+	fn vote() -> Weight {
+		(48_230_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Governance Referenda (r:1 w:1)
+	// Storage: Erc1155 TotalSupply (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Governance Votes (r:0 w:1)
+	fn close(v: u32) -> Weight {
+		(43_120_000 as Weight)
+			.saturating_add((1_840_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((v as Weight).saturating_mul(1 as Weight)))
+	}
+	// Storage: Governance VotingLocks (r:1 w:1)
+	// Storage: Erc1155 Locks (r:1 w:1)
+	fn unlock() -> Weight {
+		(26_080_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn propose() -> Weight {
+		(41_870_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn vote() -> Weight {
+		(48_230_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn close(v: u32) -> Weight {
+		(43_120_000 as Weight)
+			.saturating_add((1_840_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((v as Weight).saturating_mul(1 as Weight)))
+	}
+	fn unlock() -> Weight {
+		(26_080_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+}
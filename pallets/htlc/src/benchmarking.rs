@@ -0,0 +1,94 @@
+//! Benchmarking setup for pallet-htlc
+
+use super::*;
+
+use crate::Pallet as Htlc;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_std::{convert::TryInto, vec};
+use traits::Erc1155Mintable;
+
+const SEED: u32 = 0;
+
+/// Lock 1000 of a fresh token for a swap with a preimage of `p` bytes.
+fn setup<T: Config>(p: u32) -> (T::AccountId, SwapId, PreimageOf<T>)
+where
+	T::Tokens: Erc1155Mintable<T::AccountId>,
+	TokenIdOf<T>: From<u32>,
+{
+	let caller: T::AccountId = whitelisted_caller();
+	let recipient: T::AccountId = account("recipient", 0, SEED);
+	let token_id: TokenIdOf<T> = 0u32.into();
+	T::Tokens::create(&caller, token_id).unwrap();
+	T::Tokens::mint(&caller, token_id, 1_000u32.into()).unwrap();
+	let preimage: PreimageOf<T> = vec![1u8; p as usize].try_into().unwrap();
+	let hashlock = sp_io::hashing::sha2_256(&preimage);
+	let expires_at = frame_system::Pallet::<T>::block_number() + T::MinTimeout::get();
+	Htlc::<T>::lock(
+		RawOrigin::Signed(caller.clone()).into(),
+		recipient.clone(),
+		token_id,
+		1_000u32.into(),
+		hashlock,
+		T::MinTimeout::get(),
+	)
+	.unwrap();
+	let swap_id = Htlc::<T>::swap_id(&Swap {
+		sender: caller.clone(),
+		recipient,
+		token_id,
+		amount: 1_000u32.into(),
+		hashlock,
+		expires_at,
+	});
+	(caller, swap_id, preimage)
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T::Tokens: Erc1155Mintable<T::AccountId>,
+			TokenIdOf<T>: From<u32>,
+	}
+
+	lock {
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let token_id: TokenIdOf<T> = 0u32.into();
+		T::Tokens::create(&caller, token_id)?;
+		T::Tokens::mint(&caller, token_id, 1_000u32.into())?;
+		let hashlock = [1u8; 32];
+		let swap_id = Htlc::<T>::swap_id(&Swap {
+			sender: caller.clone(),
+			recipient: recipient.clone(),
+			token_id,
+			amount: 1_000u32.into(),
+			hashlock,
+			expires_at: frame_system::Pallet::<T>::block_number() + T::MinTimeout::get(),
+		});
+	}: _(RawOrigin::Signed(caller), recipient, token_id, 1_000u32.into(), hashlock, T::MinTimeout::get())
+	verify {
+		assert!(Htlc::<T>::get_swap(&swap_id).is_some());
+	}
+
+	claim {
+		let p in 0 .. T::MaxPreimageLength::get();
+		let (caller, swap_id, preimage) = setup::<T>(p);
+	}: _(RawOrigin::Signed(caller), swap_id, preimage)
+	verify {
+		assert!(Htlc::<T>::get_swap(&swap_id).is_none());
+	}
+
+	refund {
+		let (caller, swap_id, _) = setup::<T>(32);
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::MinTimeout::get(),
+		);
+	}: _(RawOrigin::Signed(caller), swap_id)
+	verify {
+		assert!(Htlc::<T>::get_swap(&swap_id).is_none());
+	}
+
+	impl_benchmark_test_suite!(Htlc, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,228 @@
+//! Hash-time-locked swaps of `pallet_erc1155` tokens.
+//!
+//! The sender locks tokens for a recipient under the SHA-256 hash of a secret and a timeout.
+//! Before the timeout anybody can submit the secret to release the tokens to the recipient, which
+//! publishes it in the `Claimed` event for the counterpart swap on the other chain. From the
+//! timeout on the tokens can only go back to the sender.
+//!
+//! Swaps are identified by the hash of all their terms, so a swap locked by someone else under the
+//! same hashlock cannot block or stand in for the one the counterparty expects.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{HashLock, Swap, SwapId, WeightInfo};
+	use frame_support::{
+		dispatch::DispatchResult, ensure, pallet_prelude::*, transactional, PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_io::hashing::blake2_256;
+	use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+	use traits::Erc1155;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type TokenIdOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::TokenId;
+
+	pub type SwapOf<T> = Swap<
+		<T as frame_system::Config>::AccountId,
+		TokenIdOf<T>,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	pub type PreimageOf<T> = BoundedVec<u8, <T as Config>::MaxPreimageLength>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		type Tokens: Erc1155<Self::AccountId>;
+		/// Account holding the locked tokens.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		#[pallet::constant]
+		type MaxPreimageLength: Get<u32>;
+		/// Shortest timeout, leaves the recipient time to claim after the secret is revealed.
+		#[pallet::constant]
+		type MinTimeout: Get<Self::BlockNumber>;
+		#[pallet::constant]
+		type MaxTimeout: Get<Self::BlockNumber>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// Open swaps keyed by their `swap_id`.
+	#[pallet::storage]
+	#[pallet::getter(fn get_swap)]
+	pub(super) type Swaps<T: Config> = StorageMap<_, Blake2_128Concat, SwapId, SwapOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		Locked {
+			swap_id: SwapId,
+			hashlock: HashLock,
+			sender: T::AccountId,
+			recipient: T::AccountId,
+			token_id: TokenIdOf<T>,
+			amount: BalanceOf<T>,
+			expires_at: T::BlockNumber,
+		},
+		/// The tokens were released to `recipient`, `preimage` unlocks the counterpart swap.
+		Claimed {
+			swap_id: SwapId,
+			hashlock: HashLock,
+			recipient: T::AccountId,
+			preimage: PreimageOf<T>,
+		},
+		Refunded {
+			swap_id: SwapId,
+			sender: T::AccountId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		ZeroAmount,
+		InvalidTimeout,
+		SwapExists,
+		UnknownSwap,
+		InvalidPreimage,
+		SwapExpired,
+		SwapNotExpired,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Lock `amount` of `token_id` for `recipient` until `timeout` blocks from now, the id of
+		/// the swap is in the `Locked` event.
+		#[pallet::weight(T::WeightInfo::lock())]
+		#[transactional]
+		pub fn lock(
+			origin: OriginFor<T>,
+			recipient: T::AccountId,
+			token_id: TokenIdOf<T>,
+			amount: BalanceOf<T>,
+			hashlock: HashLock,
+			timeout: T::BlockNumber,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(
+				timeout >= T::MinTimeout::get() && timeout <= T::MaxTimeout::get(),
+				Error::<T>::InvalidTimeout
+			);
+			let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(timeout);
+			let swap = Swap {
+				sender: sender.clone(),
+				recipient: recipient.clone(),
+				token_id,
+				amount,
+				hashlock,
+				expires_at,
+			};
+			let swap_id = Self::swap_id(&swap);
+			ensure!(!Swaps::<T>::contains_key(&swap_id), Error::<T>::SwapExists);
+			T::Tokens::transfer(&sender, &Self::account_id(), token_id, amount)?;
+			Swaps::<T>::insert(&swap_id, swap);
+			Self::deposit_event(Event::Locked {
+				swap_id,
+				hashlock,
+				sender,
+				recipient,
+				token_id,
+				amount,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Release the tokens to the recipient by revealing the preimage of the hashlock, anybody
+		/// can submit it.
+		#[pallet::weight(T::WeightInfo::claim(preimage.len() as u32))]
+		#[transactional]
+		pub fn claim(
+			origin: OriginFor<T>,
+			swap_id: SwapId,
+			preimage: PreimageOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let swap = Self::get_swap(&swap_id).ok_or(Error::<T>::UnknownSwap)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < swap.expires_at,
+				Error::<T>::SwapExpired
+			);
+			ensure!(
+				sp_io::hashing::sha2_256(&preimage) == swap.hashlock,
+				Error::<T>::InvalidPreimage
+			);
+			T::Tokens::transfer(&Self::account_id(), &swap.recipient, swap.token_id, swap.amount)?;
+			Swaps::<T>::remove(&swap_id);
+			Self::deposit_event(Event::Claimed {
+				swap_id,
+				hashlock: swap.hashlock,
+				recipient: swap.recipient,
+				preimage,
+			});
+			Ok(())
+		}
+
+		/// Return the tokens of an expired swap to its sender, anybody can trigger it.
+		#[pallet::weight(T::WeightInfo::refund())]
+		#[transactional]
+		pub fn refund(origin: OriginFor<T>, swap_id: SwapId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let swap = Self::get_swap(&swap_id).ok_or(Error::<T>::UnknownSwap)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= swap.expires_at,
+				Error::<T>::SwapNotExpired
+			);
+			T::Tokens::transfer(&Self::account_id(), &swap.sender, swap.token_id, swap.amount)?;
+			Swaps::<T>::remove(&swap_id);
+			Self::deposit_event(Event::Refunded { swap_id, sender: swap.sender });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding the tokens of all open swaps.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		/// Id of `swap`, lets the counterparty check all terms of the swap it is claiming.
+		pub fn swap_id(swap: &SwapOf<T>) -> SwapId {
+			(
+				&swap.sender,
+				&swap.recipient,
+				swap.token_id,
+				swap.amount,
+				swap.hashlock,
+				swap.expires_at,
+			)
+				.using_encoded(blake2_256)
+		}
+	}
+}
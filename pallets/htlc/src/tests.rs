@@ -0,0 +1,166 @@
+use crate::{mock::*, Error, HashLock, PreimageOf, Swap, SwapId};
+use frame_support::{assert_noop, assert_ok};
+use sp_std::convert::TryInto;
+
+const SECRET: &[u8] = b"open sesame";
+
+fn balance_of(who: u64, token_id: u32) -> u128 {
+	Erc1155::get_balance(who, token_id)
+}
+
+fn preimage(secret: &[u8]) -> PreimageOf<Test> {
+	secret.to_vec().try_into().unwrap()
+}
+
+fn hashlock() -> HashLock {
+	sp_io::hashing::sha2_256(SECRET)
+}
+
+fn swap(sender: u64, recipient: u64, expires_at: u64) -> Swap<u64, u32, u128, u64> {
+	Swap { sender, recipient, token_id: TOKEN_ID, amount: 100, hashlock: hashlock(), expires_at }
+}
+
+fn lock() -> SwapId {
+	let expires_at = System::block_number() + 20;
+	assert_ok!(Htlc::lock(Origin::signed(ALICE), BOB, TOKEN_ID, 100, hashlock(), 20));
+	Htlc::swap_id(&swap(ALICE, BOB, expires_at))
+}
+
+#[test]
+fn lock_should_work() {
+	new_test_ext().execute_with(|| {
+		let swap_id = lock();
+		System::assert_last_event(Event::Htlc(crate::Event::Locked {
+			swap_id,
+			hashlock: hashlock(),
+			sender: ALICE,
+			recipient: BOB,
+			token_id: TOKEN_ID,
+			amount: 100,
+			expires_at: 21,
+		}));
+		assert_eq!(balance_of(ALICE, TOKEN_ID), 900);
+		assert_eq!(balance_of(Htlc::account_id(), TOKEN_ID), 100);
+		assert_eq!(Htlc::get_swap(swap_id), Some(swap(ALICE, BOB, 21)));
+	});
+}
+
+#[test]
+fn lock_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Htlc::lock(Origin::signed(ALICE), BOB, TOKEN_ID, 0, hashlock(), 20),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			Htlc::lock(Origin::signed(ALICE), BOB, TOKEN_ID, 100, hashlock(), 9),
+			Error::<Test>::InvalidTimeout
+		);
+		assert_noop!(
+			Htlc::lock(Origin::signed(ALICE), BOB, TOKEN_ID, 100, hashlock(), 101),
+			Error::<Test>::InvalidTimeout
+		);
+	});
+}
+
+#[test]
+fn lock_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		lock();
+		assert_noop!(
+			Htlc::lock(Origin::signed(ALICE), BOB, TOKEN_ID, 100, hashlock(), 20),
+			Error::<Test>::SwapExists
+		);
+		assert!(Htlc::lock(Origin::signed(BOB), ALICE, TOKEN_ID, 100, [0u8; 32], 20).is_err());
+	});
+}
+
+#[test]
+fn lock_with_same_hashlock_should_not_block_other_swaps() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(<Erc1155 as traits::Erc1155Mintable<u64>>::mint(&CHARLIE, TOKEN_ID, 100));
+		// a swap locked first under the same hashlock is a separate swap
+		assert_ok!(Htlc::lock(Origin::signed(CHARLIE), BOB, TOKEN_ID, 100, hashlock(), 20));
+		let swap_id = lock();
+		assert_ne!(swap_id, Htlc::swap_id(&swap(CHARLIE, BOB, 21)));
+		assert_ok!(Htlc::claim(Origin::signed(BOB), swap_id, preimage(SECRET)));
+		assert_eq!(balance_of(BOB, TOKEN_ID), 100);
+		assert_eq!(Htlc::get_swap(swap_id), None);
+		assert_eq!(
+			Htlc::get_swap(Htlc::swap_id(&swap(CHARLIE, BOB, 21))),
+			Some(swap(CHARLIE, BOB, 21))
+		);
+	});
+}
+
+#[test]
+fn claim_should_work() {
+	new_test_ext().execute_with(|| {
+		let swap_id = lock();
+		System::set_block_number(20);
+		assert_ok!(Htlc::claim(Origin::signed(CHARLIE), swap_id, preimage(SECRET)));
+		System::assert_last_event(Event::Htlc(crate::Event::Claimed {
+			swap_id,
+			hashlock: hashlock(),
+			recipient: BOB,
+			preimage: preimage(SECRET),
+		}));
+		assert_eq!(balance_of(BOB, TOKEN_ID), 100);
+		assert_eq!(balance_of(Htlc::account_id(), TOKEN_ID), 0);
+		assert_eq!(Htlc::get_swap(swap_id), None);
+		// the hashlock can be used again once the swap is closed
+		lock();
+	});
+}
+
+#[test]
+fn claim_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		let swap_id = Htlc::swap_id(&swap(ALICE, BOB, 21));
+		assert_noop!(
+			Htlc::claim(Origin::signed(BOB), swap_id, preimage(SECRET)),
+			Error::<Test>::UnknownSwap
+		);
+		lock();
+		assert_noop!(
+			Htlc::claim(Origin::signed(BOB), swap_id, preimage(b"wrong")),
+			Error::<Test>::InvalidPreimage
+		);
+	});
+}
+
+#[test]
+fn claim_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		let swap_id = lock();
+		System::set_block_number(21);
+		assert_noop!(
+			Htlc::claim(Origin::signed(BOB), swap_id, preimage(SECRET)),
+			Error::<Test>::SwapExpired
+		);
+	});
+}
+
+#[test]
+fn refund_should_work() {
+	new_test_ext().execute_with(|| {
+		let swap_id = lock();
+		System::set_block_number(21);
+		assert_ok!(Htlc::refund(Origin::signed(CHARLIE), swap_id));
+		System::assert_last_event(Event::Htlc(crate::Event::Refunded { swap_id, sender: ALICE }));
+		assert_eq!(balance_of(ALICE, TOKEN_ID), 1_000);
+		assert_eq!(balance_of(Htlc::account_id(), TOKEN_ID), 0);
+		assert_eq!(Htlc::get_swap(swap_id), None);
+	});
+}
+
+#[test]
+fn refund_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		let swap_id = Htlc::swap_id(&swap(ALICE, BOB, 21));
+		assert_noop!(Htlc::refund(Origin::signed(ALICE), swap_id), Error::<Test>::UnknownSwap);
+		lock();
+		System::set_block_number(20);
+		assert_noop!(Htlc::refund(Origin::signed(ALICE), swap_id), Error::<Test>::SwapNotExpired);
+	});
+}
@@ -0,0 +1,20 @@
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// SHA-256 hash of the secret preimage, compatible with HTLCs on other chains.
+pub type HashLock = [u8; 32];
+
+/// Blake2-256 hash of the sender, recipient, token id, amount, hashlock and expiry of a swap.
+pub type SwapId = [u8; 32];
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Swap<AccountId, TokenId, Balance, BlockNumber> {
+	pub sender: AccountId,
+	pub recipient: AccountId,
+	pub token_id: TokenId,
+	pub amount: Balance,
+	pub hashlock: HashLock,
+	/// First block the swap can no longer be claimed and the sender may take the tokens back.
+	pub expires_at: BlockNumber,
+}
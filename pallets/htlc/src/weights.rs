@@ -0,0 +1,82 @@
+//! Weights for pallet_htlc.
+//!
+//! These are hand-written estimates that follow the storage accesses of each call, not the output
+//! of a benchmark run. Replace them with measured weights by running:
+//! ./target/release/node-template benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_htlc --extrinsic '*' --steps 50 --repeat 20
+//! --output ./pallets/htlc/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_htlc.
+pub trait WeightInfo {
+	fn lock() -> Weight;
+	fn claim(p: u32) -> Weight;
+	fn refund() -> Weight;
+}
+
+/// Weights for pallet_htlc using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Htlc Swaps (r:1 w:1)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	fn lock() -> Weight {
+		(61_280_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Htlc Swaps (r:1 w:1)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	fn claim(p: u32) -> Weight {
+		(58_940_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Htlc Swaps (r:1 w:1)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	fn refund() -> Weight {
+		(55_310_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn lock() -> Weight {
+		(61_280_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn claim(p: u32) -> Weight {
+		(58_940_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn refund() -> Weight {
+		(55_310_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+}
@@ -0,0 +1,126 @@
+//! Benchmarking setup for pallet-loot-box
+
+use super::*;
+
+use crate::Pallet as LootBox;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::traits::{Get, Hooks};
+use frame_system::RawOrigin;
+use sp_io::hashing::blake2_256;
+use sp_runtime::traits::Saturating;
+use sp_std::{convert::TryInto, vec::Vec};
+use traits::{Erc1155, Erc1155Mintable};
+
+const SECRET: [u8; 32] = [7; 32];
+
+/// Create box 0 with `n` outcomes over tokens `1..=n` and give the caller one box.
+fn setup<T: Config>(n: u32, commit_reveal: bool) -> T::AccountId
+where
+	T::Tokens: Erc1155Mintable<T::AccountId>,
+	TokenIdOf<T>: From<u32>,
+{
+	let caller: T::AccountId = whitelisted_caller();
+	for token_id in 0..=n {
+		T::Tokens::create(&caller, token_id.into()).unwrap();
+	}
+	T::Tokens::mint(&caller, 0u32.into(), 1u32.into()).unwrap();
+	LootBox::<T>::define(
+		RawOrigin::Signed(caller.clone()).into(),
+		0u32.into(),
+		outcomes::<T>(n),
+		commit_reveal,
+	)
+	.unwrap();
+	caller
+}
+
+fn outcomes<T: Config>(n: u32) -> OutcomesOf<T>
+where
+	TokenIdOf<T>: From<u32>,
+{
+	(1..=n)
+		.map(|token_id| Outcome { token_id: token_id.into(), amount: 1u32.into(), weight: 1 })
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+fn commit<T: Config>(caller: T::AccountId) -> T::BlockNumber
+where
+	TokenIdOf<T>: From<u32>,
+{
+	LootBox::<T>::commit(RawOrigin::Signed(caller).into(), 0u32.into(), blake2_256(&SECRET))
+		.unwrap();
+	frame_system::Pallet::<T>::block_number().saturating_add(T::RevealDelay::get())
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T::Tokens: Erc1155Mintable<T::AccountId>,
+			TokenIdOf<T>: From<u32>,
+	}
+
+	define {
+		let n in 1 .. T::MaxOutcomes::get();
+		let caller: T::AccountId = whitelisted_caller();
+		for token_id in 0..=n {
+			T::Tokens::create(&caller, token_id.into())?;
+		}
+	}: _(RawOrigin::Signed(caller), 0u32.into(), outcomes::<T>(n), false)
+	verify {
+		assert!(LootBox::<T>::get_box(TokenIdOf::<T>::from(0u32)).is_some());
+	}
+
+	open {
+		let n in 1 .. T::MaxOutcomes::get();
+		let caller = setup::<T>(n, false);
+	}: _(RawOrigin::Signed(caller.clone()), 0u32.into())
+	verify {
+		assert_eq!(T::Tokens::balance_of(&caller, 0u32.into()), Ok(0u32.into()));
+	}
+
+	commit {
+		let caller = setup::<T>(1, true);
+	}: _(RawOrigin::Signed(caller), 0u32.into(), blake2_256(&SECRET))
+	verify {
+		assert!(LootBox::<T>::get_commitment(0).is_some());
+	}
+
+	reveal {
+		let n in 1 .. T::MaxOutcomes::get();
+		let caller = setup::<T>(n, true);
+		let reveal_at = commit::<T>(caller.clone());
+		frame_system::Pallet::<T>::set_block_number(reveal_at);
+		LootBox::<T>::on_initialize(reveal_at);
+	}: _(RawOrigin::Signed(caller), 0, SECRET)
+	verify {
+		assert!(LootBox::<T>::get_commitment(0).is_none());
+	}
+
+	clear_expired {
+		let caller = setup::<T>(1, true);
+		let reveal_at = commit::<T>(caller.clone());
+		let expires_at = reveal_at.saturating_add(T::RevealPeriod::get());
+		frame_system::Pallet::<T>::set_block_number(reveal_at);
+		LootBox::<T>::on_initialize(reveal_at);
+		frame_system::Pallet::<T>::set_block_number(expires_at);
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert!(LootBox::<T>::get_commitment(0).is_none());
+		assert!(LootBox::<T>::get_seed(reveal_at).is_none());
+	}
+
+	on_initialize {
+		let caller = setup::<T>(1, true);
+		let reveal_at = commit::<T>(caller);
+		frame_system::Pallet::<T>::set_block_number(reveal_at);
+	}: {
+		LootBox::<T>::on_initialize(reveal_at);
+	}
+	verify {
+		assert!(LootBox::<T>::get_seed(reveal_at).is_some());
+	}
+
+	impl_benchmark_test_suite!(LootBox, crate::mock::new_test_ext(), crate::mock::Test);
+}
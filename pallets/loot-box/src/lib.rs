@@ -0,0 +1,334 @@
+//! Loot boxes over `pallet_erc1155` tokens.
+//!
+//! An issuer defines one of its tokens as a box with weighted outcomes over other tokens it
+//! issues. Opening a box burns one unit of it and mints a random outcome to the opener. Boxes
+//! open at once with the configured `Randomness`, or, for valuable contents, in two steps: the
+//! opener burns the box with the hash of a secret, and reveals the secret once the randomness of
+//! a later block has been recorded. Commitments not revealed within `Config::RevealPeriod` of
+//! that block expire and can be cleared by anybody, the burned box is lost. Outcomes are only
+//! minted while the issuer of the box still issues their tokens.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{Commitment, LootBox, Outcome, WeightInfo};
+	use codec::{Decode, Encode};
+	use frame_support::{
+		dispatch::DispatchResult, ensure, pallet_prelude::*, traits::Randomness, transactional,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_io::hashing::blake2_256;
+	use sp_runtime::traits::{One, Saturating, Zero};
+	use traits::{Erc1155, Erc1155Mintable};
+
+	pub type CommitmentId = u64;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type TokenIdOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::TokenId;
+
+	pub type OutcomeOf<T> = Outcome<TokenIdOf<T>, BalanceOf<T>>;
+
+	pub type OutcomesOf<T> = BoundedVec<OutcomeOf<T>, <T as Config>::MaxOutcomes>;
+
+	pub type LootBoxOf<T> = LootBox<<T as frame_system::Config>::AccountId, OutcomesOf<T>>;
+
+	pub type CommitmentOf<T> = Commitment<
+		<T as frame_system::Config>::AccountId,
+		TokenIdOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		type Tokens: Erc1155Mintable<Self::AccountId>;
+		/// Source of the outcomes, e.g. `pallet_randomness_collective_flip`.
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+		#[pallet::constant]
+		type MaxOutcomes: Get<u32>;
+		/// Blocks between burning a box and the randomness it is opened with, at least one.
+		#[pallet::constant]
+		type RevealDelay: Get<Self::BlockNumber>;
+		/// Blocks after the randomness is recorded during which a commitment can be revealed.
+		#[pallet::constant]
+		type RevealPeriod: Get<Self::BlockNumber>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// Box definitions keyed by the token id of the box.
+	#[pallet::storage]
+	#[pallet::getter(fn get_box)]
+	pub(super) type Boxes<T: Config> = StorageMap<_, Blake2_128Concat, TokenIdOf<T>, LootBoxOf<T>>;
+
+	/// Distinguishes the randomness subjects of boxes opened in the same block.
+	#[pallet::storage]
+	pub(super) type Nonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_commitment_id)]
+	pub(super) type NextCommitmentId<T: Config> = StorageValue<_, CommitmentId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_commitment)]
+	pub(super) type Commitments<T: Config> =
+		StorageMap<_, Blake2_128Concat, CommitmentId, CommitmentOf<T>>;
+
+	/// Unrevealed commitments waiting for the randomness of a block.
+	#[pallet::storage]
+	pub(super) type SeedUsers<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, u32, ValueQuery>;
+
+	/// Randomness recorded at the start of a block for the commitments revealed with it.
+	#[pallet::storage]
+	#[pallet::getter(fn get_seed)]
+	pub(super) type Seeds<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, T::Hash>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		Defined {
+			box_id: TokenIdOf<T>,
+			issuer: T::AccountId,
+			commit_reveal: bool,
+		},
+		/// `who` burned a box to open it with the randomness of `reveal_at`.
+		Committed {
+			commitment_id: CommitmentId,
+			who: T::AccountId,
+			box_id: TokenIdOf<T>,
+			reveal_at: T::BlockNumber,
+		},
+		Opened {
+			who: T::AccountId,
+			box_id: TokenIdOf<T>,
+			token_id: TokenIdOf<T>,
+			amount: BalanceOf<T>,
+		},
+		/// An unrevealed commitment expired and was removed.
+		Expired {
+			commitment_id: CommitmentId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Only the issuer of the box and its outcomes can define and mint it.
+		NotIssuer,
+		AlreadyDefined,
+		NoOutcomes,
+		ZeroAmount,
+		ZeroWeight,
+		UnknownBox,
+		/// The box has to be opened with `commit` and `reveal`.
+		CommitRevealRequired,
+		/// The box opens with `open`.
+		NotCommitReveal,
+		UnknownCommitment,
+		NotCommitter,
+		/// The randomness of the reveal block has not been recorded yet.
+		TooEarly,
+		/// The secret does not match the committed hash.
+		InvalidSecret,
+		/// The reveal period of the commitment is over.
+		Expired,
+		/// The commitment can still be revealed.
+		NotExpired,
+		Overflow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			if !SeedUsers::<T>::contains_key(now) {
+				return T::DbWeight::get().reads(1)
+			}
+			let (seed, _) = T::Randomness::random(b"loot-box/seed");
+			Seeds::<T>::insert(now, seed);
+			T::WeightInfo::on_initialize()
+		}
+
+		fn integrity_test() {
+			assert!(!T::RevealDelay::get().is_zero(), "RevealDelay must be at least one block");
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Turn `box_id` into a box of `outcomes`, opened in two steps if `commit_reveal`.
+		#[pallet::weight(T::WeightInfo::define(outcomes.len() as u32))]
+		pub fn define(
+			origin: OriginFor<T>,
+			box_id: TokenIdOf<T>,
+			outcomes: OutcomesOf<T>,
+			commit_reveal: bool,
+		) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+			ensure!(!Boxes::<T>::contains_key(box_id), Error::<T>::AlreadyDefined);
+			ensure!(T::Tokens::is_issuer(&issuer, box_id), Error::<T>::NotIssuer);
+			ensure!(!outcomes.is_empty(), Error::<T>::NoOutcomes);
+			let mut total_weight = 0u32;
+			for outcome in outcomes.iter() {
+				ensure!(!outcome.amount.is_zero(), Error::<T>::ZeroAmount);
+				ensure!(outcome.weight > 0, Error::<T>::ZeroWeight);
+				ensure!(T::Tokens::is_issuer(&issuer, outcome.token_id), Error::<T>::NotIssuer);
+				total_weight =
+					total_weight.checked_add(outcome.weight).ok_or(Error::<T>::Overflow)?;
+			}
+			Boxes::<T>::insert(
+				box_id,
+				LootBox { issuer: issuer.clone(), outcomes, total_weight, commit_reveal },
+			);
+			Self::deposit_event(Event::Defined { box_id, issuer, commit_reveal });
+			Ok(())
+		}
+
+		/// Burn a box and mint its outcome right away.
+		#[pallet::weight(T::WeightInfo::open(T::MaxOutcomes::get()))]
+		#[transactional]
+		pub fn open(origin: OriginFor<T>, box_id: TokenIdOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let loot_box = Self::get_box(box_id).ok_or(Error::<T>::UnknownBox)?;
+			ensure!(!loot_box.commit_reveal, Error::<T>::CommitRevealRequired);
+			T::Tokens::burn(&who, box_id, One::one())?;
+			let nonce = Nonce::<T>::mutate(|nonce| {
+				*nonce = nonce.wrapping_add(1);
+				*nonce
+			});
+			let (random, _) = T::Randomness::random(&(b"loot-box/open", nonce).encode());
+			let outcome = loot_box.pick(Self::to_u64(random.as_ref())).clone();
+			Self::mint_outcome(who, box_id, &loot_box.issuer, outcome)
+		}
+
+		/// Burn a box to open it later with `reveal`, `hash` is the blake2-256 hash of a secret
+		/// only the caller knows.
+		#[pallet::weight(T::WeightInfo::commit())]
+		#[transactional]
+		pub fn commit(
+			origin: OriginFor<T>,
+			box_id: TokenIdOf<T>,
+			hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let loot_box = Self::get_box(box_id).ok_or(Error::<T>::UnknownBox)?;
+			ensure!(loot_box.commit_reveal, Error::<T>::NotCommitReveal);
+			T::Tokens::burn(&who, box_id, One::one())?;
+			let commitment_id = Self::get_next_commitment_id();
+			NextCommitmentId::<T>::put(commitment_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+			let reveal_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::RevealDelay::get());
+			SeedUsers::<T>::mutate(reveal_at, |users| *users = users.saturating_add(1));
+			Commitments::<T>::insert(
+				commitment_id,
+				Commitment { who: who.clone(), box_id, hash, reveal_at },
+			);
+			Self::deposit_event(Event::Committed { commitment_id, who, box_id, reveal_at });
+			Ok(())
+		}
+
+		/// Open a committed box with the secret behind its hash.
+		#[pallet::weight(T::WeightInfo::reveal(T::MaxOutcomes::get()))]
+		#[transactional]
+		pub fn reveal(
+			origin: OriginFor<T>,
+			commitment_id: CommitmentId,
+			secret: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let commitment =
+				Self::get_commitment(commitment_id).ok_or(Error::<T>::UnknownCommitment)?;
+			ensure!(commitment.who == who, Error::<T>::NotCommitter);
+			let seed = Self::get_seed(commitment.reveal_at).ok_or(Error::<T>::TooEarly)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < Self::expires_at(&commitment),
+				Error::<T>::Expired
+			);
+			ensure!(blake2_256(&secret) == commitment.hash, Error::<T>::InvalidSecret);
+			let loot_box = Self::get_box(commitment.box_id).ok_or(Error::<T>::UnknownBox)?;
+			let random = (seed, secret, commitment_id).using_encoded(blake2_256);
+			let outcome = loot_box.pick(Self::to_u64(&random)).clone();
+
+			Self::remove_commitment(commitment_id, &commitment);
+			Self::mint_outcome(who, commitment.box_id, &loot_box.issuer, outcome)
+		}
+
+		/// Remove a commitment that was not revealed in its reveal period, anybody can clear it.
+		#[pallet::weight(T::WeightInfo::clear_expired())]
+		pub fn clear_expired(origin: OriginFor<T>, commitment_id: CommitmentId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let commitment =
+				Self::get_commitment(commitment_id).ok_or(Error::<T>::UnknownCommitment)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= Self::expires_at(&commitment),
+				Error::<T>::NotExpired
+			);
+			Self::remove_commitment(commitment_id, &commitment);
+			Self::deposit_event(Event::Expired { commitment_id });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn to_u64(random: &[u8]) -> u64 {
+			u64::decode(&mut &random[..]).unwrap_or_default()
+		}
+
+		/// First block the commitment can no longer be revealed in.
+		fn expires_at(commitment: &CommitmentOf<T>) -> T::BlockNumber {
+			commitment.reveal_at.saturating_add(T::RevealPeriod::get())
+		}
+
+		/// Remove a commitment and the seed of its reveal block once no commitment needs it.
+		fn remove_commitment(commitment_id: CommitmentId, commitment: &CommitmentOf<T>) {
+			Commitments::<T>::remove(commitment_id);
+			let users = SeedUsers::<T>::get(commitment.reveal_at).saturating_sub(1);
+			if users == 0 {
+				SeedUsers::<T>::remove(commitment.reveal_at);
+				Seeds::<T>::remove(commitment.reveal_at);
+			} else {
+				SeedUsers::<T>::insert(commitment.reveal_at, users);
+			}
+		}
+
+		/// Mint `outcome` to `who` if `issuer` still issues its token.
+		fn mint_outcome(
+			who: T::AccountId,
+			box_id: TokenIdOf<T>,
+			issuer: &T::AccountId,
+			outcome: OutcomeOf<T>,
+		) -> DispatchResult {
+			ensure!(T::Tokens::is_issuer(issuer, outcome.token_id), Error::<T>::NotIssuer);
+			T::Tokens::mint(&who, outcome.token_id, outcome.amount)?;
+			Self::deposit_event(Event::Opened {
+				who,
+				box_id,
+				token_id: outcome.token_id,
+				amount: outcome.amount,
+			});
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,273 @@
+use crate::{mock::*, Commitment, Error, Outcome};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, BoundedVec};
+use sp_io::hashing::blake2_256;
+use sp_runtime::Perbill;
+use sp_std::convert::TryInto;
+
+const SECRET: [u8; 32] = [7; 32];
+/// Member of a collection of CHARLIE.
+const RUBY: u32 = 13;
+
+fn balance_of(who: u64, token_id: u32) -> u128 {
+	Erc1155::get_balance(who, token_id)
+}
+
+fn outcome(token_id: u32, amount: u128, weight: u32) -> Outcome<u32, u128> {
+	Outcome { token_id, amount, weight }
+}
+
+/// One gold in four, three silver otherwise.
+fn outcomes() -> BoundedVec<Outcome<u32, u128>, MaxOutcomes> {
+	vec![outcome(GOLD, 1, 1), outcome(SILVER, 3, 3)].try_into().unwrap()
+}
+
+fn define(commit_reveal: bool) {
+	assert_ok!(LootBox::define(Origin::signed(ALICE), BOX_ID, outcomes(), commit_reveal));
+}
+
+#[test]
+fn pick_should_work() {
+	new_test_ext().execute_with(|| {
+		define(false);
+		let loot_box = LootBox::get_box(BOX_ID).unwrap();
+		assert_eq!(loot_box.total_weight, 4);
+		assert_eq!(loot_box.pick(0).token_id, GOLD);
+		assert_eq!(loot_box.pick(1).token_id, SILVER);
+		assert_eq!(loot_box.pick(3).token_id, SILVER);
+		assert_eq!(loot_box.pick(4).token_id, GOLD);
+		assert_eq!(loot_box.pick(u64::MAX).token_id, SILVER);
+	});
+}
+
+#[test]
+fn define_should_work() {
+	new_test_ext().execute_with(|| {
+		define(true);
+		System::assert_last_event(Event::LootBox(crate::Event::Defined {
+			box_id: BOX_ID,
+			issuer: ALICE,
+			commit_reveal: true,
+		}));
+		let loot_box = LootBox::get_box(BOX_ID).unwrap();
+		assert_eq!(loot_box.issuer, ALICE);
+		assert_eq!(loot_box.outcomes, outcomes());
+		assert!(loot_box.commit_reveal);
+	});
+}
+
+#[test]
+fn define_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LootBox::define(Origin::signed(BOB), BOX_ID, outcomes(), false),
+			Error::<Test>::NotIssuer
+		);
+		// BOB's box can't hand out ALICE's tokens
+		<Erc1155 as traits::Erc1155Mintable<u64>>::create(&BOB, 13).unwrap();
+		assert_noop!(
+			LootBox::define(Origin::signed(BOB), 13, outcomes(), false),
+			Error::<Test>::NotIssuer
+		);
+		define(false);
+		assert_noop!(
+			LootBox::define(Origin::signed(ALICE), BOX_ID, outcomes(), false),
+			Error::<Test>::AlreadyDefined
+		);
+	});
+}
+
+#[test]
+fn define_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LootBox::define(Origin::signed(ALICE), BOX_ID, Default::default(), false),
+			Error::<Test>::NoOutcomes
+		);
+		assert_noop!(
+			LootBox::define(
+				Origin::signed(ALICE),
+				BOX_ID,
+				vec![outcome(GOLD, 0, 1)].try_into().unwrap(),
+				false
+			),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			LootBox::define(
+				Origin::signed(ALICE),
+				BOX_ID,
+				vec![outcome(GOLD, 1, 0)].try_into().unwrap(),
+				false
+			),
+			Error::<Test>::ZeroWeight
+		);
+		assert_noop!(
+			LootBox::define(
+				Origin::signed(ALICE),
+				BOX_ID,
+				vec![outcome(GOLD, 1, u32::MAX), outcome(SILVER, 1, 1)].try_into().unwrap(),
+				false
+			),
+			Error::<Test>::Overflow
+		);
+	});
+}
+
+#[test]
+fn open_should_work() {
+	new_test_ext().execute_with(|| {
+		define(false);
+		set_random(4);
+		assert_ok!(LootBox::open(Origin::signed(BOB), BOX_ID));
+		System::assert_last_event(Event::LootBox(crate::Event::Opened {
+			who: BOB,
+			box_id: BOX_ID,
+			token_id: GOLD,
+			amount: 1,
+		}));
+		set_random(6);
+		assert_ok!(LootBox::open(Origin::signed(BOB), BOX_ID));
+		assert_eq!(balance_of(BOB, BOX_ID), 1);
+		assert_eq!(balance_of(BOB, GOLD), 1);
+		assert_eq!(balance_of(BOB, SILVER), 3);
+		assert_eq!(Erc1155::get_token(BOX_ID).unwrap().supply, 1);
+	});
+}
+
+#[test]
+fn open_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(LootBox::open(Origin::signed(BOB), BOX_ID), Error::<Test>::UnknownBox);
+		define(false);
+		assert_noop!(
+			LootBox::open(Origin::signed(CHARLIE), BOX_ID),
+			pallet_erc1155::Error::<Test>::InsufficientFunds
+		);
+		assert_noop!(
+			LootBox::commit(Origin::signed(BOB), BOX_ID, blake2_256(&SECRET)),
+			Error::<Test>::NotCommitReveal
+		);
+	});
+}
+
+#[test]
+fn commit_reveal_should_work() {
+	new_test_ext().execute_with(|| {
+		define(true);
+		assert_noop!(
+			LootBox::open(Origin::signed(BOB), BOX_ID),
+			Error::<Test>::CommitRevealRequired
+		);
+		assert_ok!(LootBox::commit(Origin::signed(BOB), BOX_ID, blake2_256(&SECRET)));
+		assert_ok!(LootBox::commit(Origin::signed(BOB), BOX_ID, blake2_256(&[8; 32])));
+		System::assert_last_event(Event::LootBox(crate::Event::Committed {
+			commitment_id: 1,
+			who: BOB,
+			box_id: BOX_ID,
+			reveal_at: 3,
+		}));
+		assert_eq!(
+			LootBox::get_commitment(0),
+			Some(Commitment { who: BOB, box_id: BOX_ID, hash: blake2_256(&SECRET), reveal_at: 3 })
+		);
+		assert_eq!(balance_of(BOB, BOX_ID), 1);
+
+		run_to_block(3);
+		assert!(LootBox::get_seed(3).is_some());
+		assert_ok!(LootBox::reveal(Origin::signed(BOB), 0, SECRET));
+		assert_eq!(LootBox::get_commitment(0), None);
+		// kept for the other commitment
+		assert!(LootBox::get_seed(3).is_some());
+		assert_ok!(LootBox::reveal(Origin::signed(BOB), 1, [8; 32]));
+		assert_eq!(LootBox::get_seed(3), None);
+		assert_eq!(balance_of(BOB, GOLD) + balance_of(BOB, SILVER) / 3, 2);
+	});
+}
+
+#[test]
+fn reveal_should_fail() {
+	new_test_ext().execute_with(|| {
+		define(true);
+		assert_noop!(
+			LootBox::reveal(Origin::signed(BOB), 0, SECRET),
+			Error::<Test>::UnknownCommitment
+		);
+		assert_ok!(LootBox::commit(Origin::signed(BOB), BOX_ID, blake2_256(&SECRET)));
+		run_to_block(2);
+		assert_noop!(LootBox::reveal(Origin::signed(BOB), 0, SECRET), Error::<Test>::TooEarly);
+		run_to_block(3);
+		assert_noop!(
+			LootBox::reveal(Origin::signed(CHARLIE), 0, SECRET),
+			Error::<Test>::NotCommitter
+		);
+		assert_noop!(
+			LootBox::reveal(Origin::signed(BOB), 0, [8; 32]),
+			Error::<Test>::InvalidSecret
+		);
+		assert_ok!(LootBox::reveal(Origin::signed(BOB), 0, SECRET));
+		assert_noop!(
+			LootBox::reveal(Origin::signed(BOB), 0, SECRET),
+			Error::<Test>::UnknownCommitment
+		);
+	});
+}
+
+#[test]
+fn clear_expired_should_work() {
+	new_test_ext().execute_with(|| {
+		define(true);
+		assert_ok!(LootBox::commit(Origin::signed(BOB), BOX_ID, blake2_256(&SECRET)));
+		run_to_block(7);
+		assert_noop!(LootBox::clear_expired(Origin::signed(CHARLIE), 0), Error::<Test>::NotExpired);
+		run_to_block(8);
+		assert_noop!(LootBox::reveal(Origin::signed(BOB), 0, SECRET), Error::<Test>::Expired);
+		assert_ok!(LootBox::clear_expired(Origin::signed(CHARLIE), 0));
+		System::assert_last_event(Event::LootBox(crate::Event::Expired { commitment_id: 0 }));
+		assert_eq!(LootBox::get_commitment(0), None);
+		assert_eq!(LootBox::get_seed(3), None);
+		assert_eq!(balance_of(BOB, BOX_ID), 2);
+		assert_noop!(
+			LootBox::clear_expired(Origin::signed(CHARLIE), 0),
+			Error::<Test>::UnknownCommitment
+		);
+	});
+}
+
+#[test]
+fn open_should_fail_without_issuer_rights() {
+	new_test_ext().execute_with(|| {
+		// ALICE issues RUBY as an admin of the collection of CHARLIE
+		assert_ok!(Erc1155::create_collection(Origin::signed(CHARLIE), 1, None, Perbill::zero()));
+		assert_ok!(Erc1155::mint(Origin::signed(CHARLIE), 1, RUBY, CHARLIE, 1));
+		assert_ok!(Erc1155::set_collection_admin(Origin::signed(CHARLIE), 1, ALICE, true));
+		let outcomes = vec![outcome(RUBY, 1, 1)].try_into().unwrap();
+		assert_ok!(LootBox::define(Origin::signed(ALICE), BOX_ID, outcomes, false));
+		assert_ok!(LootBox::open(Origin::signed(BOB), BOX_ID));
+		assert_eq!(balance_of(BOB, RUBY), 1);
+
+		assert_ok!(Erc1155::set_collection_admin(Origin::signed(CHARLIE), 1, ALICE, false));
+		assert_noop!(LootBox::open(Origin::signed(BOB), BOX_ID), Error::<Test>::NotIssuer);
+		assert_eq!(balance_of(BOB, BOX_ID), 2);
+	});
+}
+
+#[test]
+fn reveal_should_fail_without_issuer_rights() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Erc1155::create_collection(Origin::signed(CHARLIE), 1, None, Perbill::zero()));
+		assert_ok!(Erc1155::mint(Origin::signed(CHARLIE), 1, RUBY, CHARLIE, 1));
+		assert_ok!(Erc1155::set_collection_admin(Origin::signed(CHARLIE), 1, ALICE, true));
+		let outcomes = vec![outcome(RUBY, 1, 1)].try_into().unwrap();
+		assert_ok!(LootBox::define(Origin::signed(ALICE), BOX_ID, outcomes, true));
+		assert_ok!(LootBox::commit(Origin::signed(BOB), BOX_ID, blake2_256(&SECRET)));
+		assert_ok!(Erc1155::set_collection_admin(Origin::signed(CHARLIE), 1, ALICE, false));
+		run_to_block(3);
+		assert_noop!(LootBox::reveal(Origin::signed(BOB), 0, SECRET), Error::<Test>::NotIssuer);
+		assert!(LootBox::get_commitment(0).is_some());
+	});
+}
+
+#[test]
+fn integrity_test_should_work() {
+	<LootBox as Hooks<u64>>::integrity_test();
+}
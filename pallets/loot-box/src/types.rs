@@ -0,0 +1,51 @@
+use codec::{Decode, Encode};
+use frame_support::BoundedVec;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Minted with probability `weight` over the total weight of the box.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Outcome<TokenId, Balance> {
+	pub token_id: TokenId,
+	pub amount: Balance,
+	pub weight: u32,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct LootBox<AccountId, Outcomes> {
+	/// Issuer of the box and of every outcome, the pallet mints on its behalf.
+	pub issuer: AccountId,
+	pub outcomes: Outcomes,
+	/// Sum of the outcome weights, never zero.
+	pub total_weight: u32,
+	/// Boxes are opened in two steps, see `Commitment`.
+	pub commit_reveal: bool,
+}
+
+impl<AccountId, TokenId, Balance, MaxOutcomes>
+	LootBox<AccountId, BoundedVec<Outcome<TokenId, Balance>, MaxOutcomes>>
+{
+	/// The outcome `random` falls on.
+	pub fn pick(&self, random: u64) -> &Outcome<TokenId, Balance> {
+		let mut point = (random % self.total_weight as u64) as u32;
+		for outcome in self.outcomes.iter() {
+			if point < outcome.weight {
+				return outcome
+			}
+			point -= outcome.weight;
+		}
+		// the weights sum up to `total_weight`
+		self.outcomes.last().expect("boxes have at least one outcome; qed")
+	}
+}
+
+/// A box burned by `who` and opened with the randomness of `reveal_at` mixed with the secret
+/// whose hash is `hash`. Neither the opener, who can't see the future randomness, nor the block
+/// author, who doesn't know the secret, can steer the outcome.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Commitment<AccountId, TokenId, BlockNumber> {
+	pub who: AccountId,
+	pub box_id: TokenId,
+	pub hash: [u8; 32],
+	pub reveal_at: BlockNumber,
+}
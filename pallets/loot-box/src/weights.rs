@@ -0,0 +1,133 @@
+//! Weights for pallet_loot_box.
+//!
+//! These are hand-written estimates that follow the storage accesses of each call, not the output
+//! of a benchmark run. Replace them with measured weights by running:
+//! ./target/release/node-template benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_loot_box --extrinsic '*' --steps 50 --repeat 20
+//! --output ./pallets/loot-box/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_loot_box.
+pub trait WeightInfo {
+	fn define(n: u32) -> Weight;
+	fn open(n: u32) -> Weight;
+	fn commit() -> Weight;
+	fn reveal(n: u32) -> Weight;
+	fn on_initialize() -> Weight;
+	fn clear_expired() -> Weight;
+}
+
+/// Weights for pallet_loot_box using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: LootBox Boxes (r:1 w:1)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	fn define(n: u32) -> Weight {
+		(14_260_000 as Weight)
+			.saturating_add((4_180_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: LootBox Boxes (r:1 w:0)
+	// Storage: LootBox Nonce (r:1 w:1)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	// Storage: Erc1155 TotalSupply (r:2 w:2)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	fn open(n: u32) -> Weight {
+		(52_910_000 as Weight)
+			.saturating_add((210_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: LootBox Boxes (r:1 w:0)
+	// Storage: Erc1155 Balances (r:1 w:1)
+	// Storage: Erc1155 TotalSupply (r:1 w:1)
+	// Storage: LootBox NextCommitmentId (r:1 w:1)
+	// Storage: LootBox SeedUsers (r:1 w:1)
+	// Storage: LootBox Commitments (r:0 w:1)
+	fn commit() -> Weight {
+		(31_940_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: LootBox Commitments (r:1 w:1)
+	// Storage: LootBox Seeds (r:1 w:1)
+	// Storage: LootBox Boxes (r:1 w:0)
+	// Storage: LootBox SeedUsers (r:1 w:1)
+	// Storage: Erc1155 Balances (r:1 w:1)
+	// Storage: Erc1155 TotalSupply (r:1 w:1)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	fn reveal(n: u32) -> Weight {
+		(43_350_000 as Weight)
+			.saturating_add((210_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: LootBox SeedUsers (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: LootBox Seeds (r:0 w:1)
+	fn on_initialize() -> Weight {
+		(6_820_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: LootBox Commitments (r:1 w:1)
+	// Storage: LootBox SeedUsers (r:1 w:1)
+	// Storage: LootBox Seeds (r:0 w:1)
+	fn clear_expired() -> Weight {
+		(17_450_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn define(n: u32) -> Weight {
+		(14_260_000 as Weight)
+			.saturating_add((4_180_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn open(n: u32) -> Weight {
+		(52_910_000 as Weight)
+			.saturating_add((210_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn commit() -> Weight {
+		(31_940_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn reveal(n: u32) -> Weight {
+		(43_350_000 as Weight)
+			.saturating_add((210_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn on_initialize() -> Weight {
+		(6_820_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn clear_expired() -> Weight {
+		(17_450_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+}
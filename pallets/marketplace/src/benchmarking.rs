@@ -0,0 +1,68 @@
+//! Benchmarking setup for pallet-marketplace
+
+use super::*;
+
+use crate::Pallet as Marketplace;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Currency;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use traits::{Erc1155, Erc1155Mintable};
+
+const SEED: u32 = 0;
+
+/// List 1000 units of a fresh token owned by the caller for 1000 native units each.
+fn setup<T: Config>() -> (T::AccountId, TokenIdOf<T>)
+where
+	T::Tokens: Erc1155Mintable<T::AccountId>,
+	TokenIdOf<T>: From<u32>,
+{
+	let caller: T::AccountId = whitelisted_caller();
+	let token_id: TokenIdOf<T> = 0u32.into();
+	T::Tokens::create(&caller, token_id).unwrap();
+	T::Tokens::mint(&caller, token_id, 1_000u32.into()).unwrap();
+	Marketplace::<T>::list(
+		RawOrigin::Signed(caller.clone()).into(),
+		token_id,
+		1_000u32.into(),
+		Price::Native(1_000u32.into()),
+	)
+	.unwrap();
+	(caller, token_id)
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T::Tokens: Erc1155Mintable<T::AccountId>,
+			TokenIdOf<T>: From<u32>,
+	}
+
+	list {
+		let caller: T::AccountId = whitelisted_caller();
+		let token_id: TokenIdOf<T> = 0u32.into();
+		T::Tokens::create(&caller, token_id)?;
+		T::Tokens::mint(&caller, token_id, 1_000u32.into())?;
+	}: _(RawOrigin::Signed(caller), token_id, 1_000u32.into(), Price::Native(1_000u32.into()))
+	verify {
+		assert!(Marketplace::<T>::get_listing(0).is_some());
+	}
+
+	buy {
+		let (_, token_id) = setup::<T>();
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		T::Currency::make_free_balance_be(&buyer, NativeBalanceOf::<T>::max_value() / 2u32.into());
+	}: _(RawOrigin::Signed(buyer.clone()), 0, 500u32.into(), Price::Native(500_000u32.into()))
+	verify {
+		assert_eq!(T::Tokens::balance_of(&buyer, token_id)?, 500u32.into());
+	}
+
+	cancel {
+		let (caller, token_id) = setup::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert_eq!(T::Tokens::balance_of(&caller, token_id)?, 1_000u32.into());
+	}
+
+	impl_benchmark_test_suite!(Marketplace, crate::mock::new_test_ext(), crate::mock::Test);
+}
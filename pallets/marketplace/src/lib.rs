@@ -0,0 +1,279 @@
+//! Fixed-price marketplace for `pallet_erc1155` items.
+//!
+//! Sellers escrow items with the pallet and ask a unit price in the native currency or in another
+//! erc1155 token. Buyers can take any part of a listing, the royalty reported by
+//! `Erc1155Royalties` for the item is deducted from the price and paid to its recipient.
+//!
+//! The buyer pays exactly the listed price, bounded by the `max_price` of `buy`. A transfer fee
+//! set by the issuer of a payment token is deducted from what the seller and the royalty
+//! recipient receive, like the royalty is deducted from the proceeds of the seller.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{Listing, Price, WeightInfo};
+	use frame_support::{
+		dispatch::DispatchResult,
+		ensure,
+		pallet_prelude::*,
+		traits::{Currency, ExistenceRequirement},
+		transactional, PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{AccountIdConversion, CheckedMul, CheckedSub, Zero},
+		Perbill,
+	};
+	use sp_std::convert::{TryFrom, TryInto};
+	use traits::{Erc1155, Erc1155Royalties};
+
+	pub type ListingId = u64;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type TokenIdOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::TokenId;
+
+	pub type NativeBalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type PriceOf<T> = Price<TokenIdOf<T>, BalanceOf<T>, NativeBalanceOf<T>>;
+
+	pub type ListingOf<T> = Listing<
+		<T as frame_system::Config>::AccountId,
+		TokenIdOf<T>,
+		BalanceOf<T>,
+		NativeBalanceOf<T>,
+	>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		type Tokens: Erc1155Royalties<Self::AccountId>;
+		/// Currency of native prices.
+		type Currency: Currency<Self::AccountId>;
+		/// Account holding the listed items.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_listing_id)]
+	pub(super) type NextListingId<T: Config> = StorageValue<_, ListingId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_listing)]
+	pub(super) type Listings<T: Config> = StorageMap<_, Blake2_128Concat, ListingId, ListingOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		Listed {
+			listing_id: ListingId,
+			seller: T::AccountId,
+			token_id: TokenIdOf<T>,
+			amount: BalanceOf<T>,
+			unit_price: PriceOf<T>,
+		},
+		/// `buyer` bought `amount` units for `price` in total, royalty included.
+		Sold {
+			listing_id: ListingId,
+			buyer: T::AccountId,
+			amount: BalanceOf<T>,
+			price: PriceOf<T>,
+		},
+		RoyaltyPaid {
+			listing_id: ListingId,
+			recipient: T::AccountId,
+			royalty: PriceOf<T>,
+		},
+		/// The listing was closed and the `amount` left unsold returned to the seller.
+		Cancelled {
+			listing_id: ListingId,
+			amount: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		ZeroAmount,
+		ZeroPrice,
+		UnknownListing,
+		NotSeller,
+		/// The listing has fewer units left than requested.
+		InsufficientAmount,
+		OwnListing,
+		Overflow,
+		/// The total price is above `max_price` or in another currency.
+		PriceTooHigh,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Escrow `amount` of `token_id` and offer it for `unit_price` per unit.
+		#[pallet::weight(T::WeightInfo::list())]
+		#[transactional]
+		pub fn list(
+			origin: OriginFor<T>,
+			token_id: TokenIdOf<T>,
+			amount: BalanceOf<T>,
+			unit_price: PriceOf<T>,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(!Self::is_zero(&unit_price), Error::<T>::ZeroPrice);
+			T::Tokens::transfer(&seller, &Self::account_id(), token_id, amount)?;
+			let listing_id = Self::get_next_listing_id();
+			NextListingId::<T>::put(listing_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+			Listings::<T>::insert(
+				listing_id,
+				Listing { seller: seller.clone(), token_id, amount, unit_price },
+			);
+			Self::deposit_event(Event::Listed { listing_id, seller, token_id, amount, unit_price });
+			Ok(())
+		}
+
+		/// Buy `amount` units of a listing for at most `max_price` in total, the rest stays on
+		/// sale.
+		#[pallet::weight(T::WeightInfo::buy())]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			listing_id: ListingId,
+			amount: BalanceOf<T>,
+			max_price: PriceOf<T>,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			let mut listing = Self::get_listing(listing_id).ok_or(Error::<T>::UnknownListing)?;
+			ensure!(listing.seller != buyer, Error::<T>::OwnListing);
+			let remaining =
+				listing.amount.checked_sub(&amount).ok_or(Error::<T>::InsufficientAmount)?;
+			let price = Self::total_price(&listing.unit_price, amount)?;
+			ensure!(Self::is_within(&price, &max_price), Error::<T>::PriceTooHigh);
+			// royalties are owed on secondary sales only
+			let royalty = T::Tokens::royalty_of(listing.token_id)
+				.filter(|(recipient, _)| recipient != &listing.seller);
+			let proceeds = match royalty {
+				Some((recipient, rate)) => {
+					let (royalty, proceeds) = Self::split(&price, rate);
+					if !Self::is_zero(&royalty) {
+						Self::pay(&buyer, &recipient, &royalty)?;
+						Self::deposit_event(Event::RoyaltyPaid { listing_id, recipient, royalty });
+					}
+					proceeds
+				},
+				None => price,
+			};
+			Self::pay(&buyer, &listing.seller, &proceeds)?;
+			T::Tokens::transfer(&Self::account_id(), &buyer, listing.token_id, amount)?;
+			if remaining.is_zero() {
+				Listings::<T>::remove(listing_id);
+			} else {
+				listing.amount = remaining;
+				Listings::<T>::insert(listing_id, listing);
+			}
+			Self::deposit_event(Event::Sold { listing_id, buyer, amount, price });
+			Ok(())
+		}
+
+		/// Close a listing and take back the unsold units.
+		#[pallet::weight(T::WeightInfo::cancel())]
+		#[transactional]
+		pub fn cancel(origin: OriginFor<T>, listing_id: ListingId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let listing = Self::get_listing(listing_id).ok_or(Error::<T>::UnknownListing)?;
+			ensure!(listing.seller == sender, Error::<T>::NotSeller);
+			T::Tokens::transfer(&Self::account_id(), &sender, listing.token_id, listing.amount)?;
+			Listings::<T>::remove(listing_id);
+			Self::deposit_event(Event::Cancelled { listing_id, amount: listing.amount });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding the items of all open listings.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		fn is_zero(price: &PriceOf<T>) -> bool {
+			match price {
+				Price::Native(amount) => amount.is_zero(),
+				Price::Token(_, amount) => amount.is_zero(),
+			}
+		}
+
+		fn total_price(
+			unit_price: &PriceOf<T>,
+			amount: BalanceOf<T>,
+		) -> Result<PriceOf<T>, DispatchError> {
+			let total = match *unit_price {
+				Price::Native(unit) => {
+					let amount: u128 = amount.try_into().map_err(|_| Error::<T>::Overflow)?;
+					let amount =
+						NativeBalanceOf::<T>::try_from(amount).map_err(|_| Error::<T>::Overflow)?;
+					unit.checked_mul(&amount).map(Price::Native)
+				},
+				Price::Token(token_id, unit) =>
+					unit.checked_mul(&amount).map(|total| Price::Token(token_id, total)),
+			};
+			total.ok_or_else(|| Error::<T>::Overflow.into())
+		}
+
+		fn is_within(price: &PriceOf<T>, max_price: &PriceOf<T>) -> bool {
+			match (*price, *max_price) {
+				(Price::Native(price), Price::Native(max_price)) => price <= max_price,
+				(Price::Token(token_id, price), Price::Token(max_token_id, max_price)) =>
+					token_id == max_token_id && price <= max_price,
+				_ => false,
+			}
+		}
+
+		/// Split `price` into the royalty at `rate` and the remainder.
+		fn split(price: &PriceOf<T>, rate: Perbill) -> (PriceOf<T>, PriceOf<T>) {
+			match *price {
+				Price::Native(total) => {
+					let royalty = rate.mul_floor(total);
+					(Price::Native(royalty), Price::Native(total - royalty))
+				},
+				Price::Token(token_id, total) => {
+					let royalty = rate.mul_floor(total);
+					(Price::Token(token_id, royalty), Price::Token(token_id, total - royalty))
+				},
+			}
+		}
+
+		fn pay(from: &T::AccountId, to: &T::AccountId, price: &PriceOf<T>) -> DispatchResult {
+			match *price {
+				_ if Self::is_zero(price) => Ok(()),
+				Price::Native(amount) =>
+					T::Currency::transfer(from, to, amount, ExistenceRequirement::KeepAlive),
+				Price::Token(token_id, amount) => T::Tokens::transfer(from, to, token_id, amount),
+			}
+		}
+	}
+}
@@ -0,0 +1,217 @@
+use crate::{mock::*, Error, Listing, Price};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use sp_runtime::Perbill;
+
+fn balance_of(who: u64, token_id: u32) -> u128 {
+	Erc1155::get_balance(who, token_id)
+}
+
+fn native_balance_of(who: u64) -> u128 {
+	Balances::free_balance(who)
+}
+
+fn list(token_id: u32, amount: u128, unit_price: Price<u32, u128, u128>) {
+	assert_ok!(Marketplace::list(Origin::signed(ALICE), token_id, amount, unit_price));
+}
+
+#[test]
+fn list_should_work() {
+	new_test_ext().execute_with(|| {
+		list(ITEM_ID, 10, Price::Native(5));
+		System::assert_last_event(Event::Marketplace(crate::Event::Listed {
+			listing_id: 0,
+			seller: ALICE,
+			token_id: ITEM_ID,
+			amount: 10,
+			unit_price: Price::Native(5),
+		}));
+		assert_eq!(balance_of(ALICE, ITEM_ID), 90);
+		assert_eq!(balance_of(Marketplace::account_id(), ITEM_ID), 10);
+		assert_eq!(
+			Marketplace::get_listing(0),
+			Some(Listing {
+				seller: ALICE,
+				token_id: ITEM_ID,
+				amount: 10,
+				unit_price: Price::Native(5)
+			})
+		);
+		assert_eq!(Marketplace::get_next_listing_id(), 1);
+	});
+}
+
+#[test]
+fn list_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Marketplace::list(Origin::signed(ALICE), ITEM_ID, 0, Price::Native(5)),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			Marketplace::list(Origin::signed(ALICE), ITEM_ID, 10, Price::Token(PAYMENT_ID, 0)),
+			Error::<Test>::ZeroPrice
+		);
+	});
+}
+
+#[test]
+fn buy_should_work() {
+	new_test_ext().execute_with(|| {
+		list(ITEM_ID, 10, Price::Native(5));
+		assert_ok!(Marketplace::buy(Origin::signed(BOB), 0, 4, Price::Native(20)));
+		System::assert_has_event(Event::Marketplace(crate::Event::RoyaltyPaid {
+			listing_id: 0,
+			recipient: CHARLIE,
+			royalty: Price::Native(2),
+		}));
+		System::assert_last_event(Event::Marketplace(crate::Event::Sold {
+			listing_id: 0,
+			buyer: BOB,
+			amount: 4,
+			price: Price::Native(20),
+		}));
+		assert_eq!(native_balance_of(BOB), 980);
+		assert_eq!(native_balance_of(ALICE), 1_018);
+		assert_eq!(native_balance_of(CHARLIE), 1_002);
+		assert_eq!(balance_of(BOB, ITEM_ID), 4);
+		assert_eq!(Marketplace::get_listing(0).unwrap().amount, 6);
+
+		assert_ok!(Marketplace::buy(Origin::signed(BOB), 0, 6, Price::Native(30)));
+		assert_eq!(balance_of(BOB, ITEM_ID), 10);
+		assert_eq!(balance_of(Marketplace::account_id(), ITEM_ID), 0);
+		assert_eq!(Marketplace::get_listing(0), None);
+	});
+}
+
+#[test]
+fn buy_with_token_price_should_work() {
+	new_test_ext().execute_with(|| {
+		list(PLAIN_ID, 20, Price::Token(PAYMENT_ID, 7));
+		assert_ok!(Marketplace::buy(Origin::signed(BOB), 0, 10, Price::Token(PAYMENT_ID, 70)));
+		System::assert_last_event(Event::Marketplace(crate::Event::Sold {
+			listing_id: 0,
+			buyer: BOB,
+			amount: 10,
+			price: Price::Token(PAYMENT_ID, 70),
+		}));
+		assert_eq!(balance_of(BOB, PAYMENT_ID), 930);
+		assert_eq!(balance_of(ALICE, PAYMENT_ID), 70);
+		assert_eq!(balance_of(BOB, PLAIN_ID), 10);
+		assert_eq!(balance_of(Marketplace::account_id(), PLAIN_ID), 10);
+	});
+}
+
+#[test]
+fn buy_should_skip_royalty_of_collection_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Erc1155::mint(Origin::signed(CHARLIE), COLLECTION_ID, ITEM_ID, CHARLIE, 10));
+		assert_ok!(Marketplace::list(Origin::signed(CHARLIE), ITEM_ID, 10, Price::Native(5)));
+		assert_ok!(Marketplace::buy(Origin::signed(BOB), 0, 10, Price::Native(50)));
+		assert_eq!(native_balance_of(BOB), 950);
+		assert_eq!(native_balance_of(CHARLIE), 1_050);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			Event::Marketplace(crate::Event::RoyaltyPaid { .. })
+		)));
+	});
+}
+
+#[test]
+fn buy_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Marketplace::buy(Origin::signed(BOB), 0, 1, Price::Native(5)),
+			Error::<Test>::UnknownListing
+		);
+		list(ITEM_ID, 10, Price::Native(5));
+		assert_noop!(
+			Marketplace::buy(Origin::signed(BOB), 0, 0, Price::Native(5)),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			Marketplace::buy(Origin::signed(ALICE), 0, 1, Price::Native(5)),
+			Error::<Test>::OwnListing
+		);
+		assert_noop!(
+			Marketplace::buy(Origin::signed(BOB), 0, 11, Price::Native(55)),
+			Error::<Test>::InsufficientAmount
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		list(ITEM_ID, 10, Price::Native(1_000));
+		assert_noop!(
+			Marketplace::buy(Origin::signed(BOB), 0, 2, Price::Native(2_000)),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		list(PLAIN_ID, 10, Price::Native(u128::MAX));
+		assert_noop!(
+			Marketplace::buy(Origin::signed(BOB), 1, 2, Price::Native(u128::MAX)),
+			Error::<Test>::Overflow
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_3() {
+	new_test_ext().execute_with(|| {
+		list(PLAIN_ID, 10, Price::Token(PAYMENT_ID, 7));
+		assert_noop!(
+			Marketplace::buy(Origin::signed(BOB), 0, 2, Price::Token(PAYMENT_ID, 13)),
+			Error::<Test>::PriceTooHigh
+		);
+		assert_noop!(
+			Marketplace::buy(Origin::signed(BOB), 0, 2, Price::Token(ITEM_ID, 14)),
+			Error::<Test>::PriceTooHigh
+		);
+		assert_noop!(
+			Marketplace::buy(Origin::signed(BOB), 0, 2, Price::Native(14)),
+			Error::<Test>::PriceTooHigh
+		);
+	});
+}
+
+#[test]
+fn buy_should_deduct_transfer_fee_from_proceeds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Erc1155::set_transfer_fee(
+			Origin::signed(BOB),
+			PAYMENT_ID,
+			Perbill::from_percent(10),
+			CHARLIE
+		));
+		list(PLAIN_ID, 20, Price::Token(PAYMENT_ID, 7));
+		assert_ok!(Marketplace::buy(Origin::signed(BOB), 0, 10, Price::Token(PAYMENT_ID, 70)));
+		assert_eq!(balance_of(BOB, PAYMENT_ID), 930);
+		assert_eq!(balance_of(ALICE, PAYMENT_ID), 63);
+		assert_eq!(balance_of(CHARLIE, PAYMENT_ID), 7);
+	});
+}
+
+#[test]
+fn cancel_should_work() {
+	new_test_ext().execute_with(|| {
+		list(ITEM_ID, 10, Price::Native(5));
+		assert_ok!(Marketplace::buy(Origin::signed(BOB), 0, 4, Price::Native(20)));
+		assert_ok!(Marketplace::cancel(Origin::signed(ALICE), 0));
+		System::assert_last_event(Event::Marketplace(crate::Event::Cancelled {
+			listing_id: 0,
+			amount: 6,
+		}));
+		assert_eq!(balance_of(ALICE, ITEM_ID), 96);
+		assert_eq!(balance_of(Marketplace::account_id(), ITEM_ID), 0);
+		assert_eq!(Marketplace::get_listing(0), None);
+	});
+}
+
+#[test]
+fn cancel_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Marketplace::cancel(Origin::signed(ALICE), 0), Error::<Test>::UnknownListing);
+		list(ITEM_ID, 10, Price::Native(5));
+		assert_noop!(Marketplace::cancel(Origin::signed(BOB), 0), Error::<Test>::NotSeller);
+	});
+}
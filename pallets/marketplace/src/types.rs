@@ -0,0 +1,20 @@
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// An amount of the native currency or of an erc1155 token.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum Price<TokenId, Balance, NativeBalance> {
+	Native(NativeBalance),
+	Token(TokenId, Balance),
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Listing<AccountId, TokenId, Balance, NativeBalance> {
+	pub seller: AccountId,
+	pub token_id: TokenId,
+	/// Units still escrowed and for sale.
+	pub amount: Balance,
+	/// Price of a single unit.
+	pub unit_price: Price<TokenId, Balance, NativeBalance>,
+}
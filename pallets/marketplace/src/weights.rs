@@ -0,0 +1,84 @@
+//! Weights for pallet_marketplace.
+//!
+//! These are hand-written estimates that follow the storage accesses of each call, not the output
+//! of a benchmark run. Replace them with measured weights by running:
+//! ./target/release/node-template benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_marketplace --extrinsic '*' --steps 50 --repeat 20
+//! --output ./pallets/marketplace/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_marketplace.
+pub trait WeightInfo {
+	fn list() -> Weight;
+	fn buy() -> Weight;
+	fn cancel() -> Weight;
+}
+
+/// Weights for pallet_marketplace using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Marketplace NextListingId (r:1 w:1)
+	// Storage: Marketplace Listings (r:0 w:1)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	fn list() -> Weight {
+		(63_120_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Marketplace Listings (r:1 w:1)
+	// Storage: Erc1155 TokenCollection (r:1 w:0)
+	// Storage: Erc1155 Collections (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	fn buy() -> Weight {
+		(118_470_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Marketplace Listings (r:1 w:1)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:0)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	fn cancel() -> Weight {
+		(56_030_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn list() -> Weight {
+		(63_120_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn buy() -> Weight {
+		(118_470_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn cancel() -> Weight {
+		(56_030_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+}
@@ -0,0 +1,98 @@
+//! Benchmarking setup for pallet-rental
+
+use super::*;
+
+use crate::Pallet as Rental;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get, Hooks};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use traits::{Erc1155Mintable, UsageRights};
+
+const SEED: u32 = 0;
+
+/// Offer `n` fresh items of the caller for rent, token ids start at 0.
+fn setup<T: Config>(n: u32) -> T::AccountId
+where
+	T::Tokens: Erc1155Mintable<T::AccountId>,
+	TokenIdOf<T>: From<u32>,
+{
+	let caller: T::AccountId = whitelisted_caller();
+	for i in 0..n {
+		let token_id: TokenIdOf<T> = i.into();
+		T::Tokens::create(&caller, token_id).unwrap();
+		T::Tokens::mint(&caller, token_id, 1u32.into()).unwrap();
+		Rental::<T>::offer(
+			RawOrigin::Signed(caller.clone()).into(),
+			token_id,
+			1u32.into(),
+			T::MaxRentalPeriod::get(),
+		)
+		.unwrap();
+	}
+	caller
+}
+
+fn funded_user<T: Config>() -> T::AccountId {
+	let user: T::AccountId = account("user", 0, SEED);
+	T::Currency::make_free_balance_be(&user, NativeBalanceOf::<T>::max_value() / 2u32.into());
+	user
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T::Tokens: Erc1155Mintable<T::AccountId>,
+			TokenIdOf<T>: From<u32>,
+	}
+
+	offer {
+		let caller: T::AccountId = whitelisted_caller();
+		let token_id: TokenIdOf<T> = 0u32.into();
+		T::Tokens::create(&caller, token_id)?;
+		T::Tokens::mint(&caller, token_id, 1u32.into())?;
+	}: _(RawOrigin::Signed(caller), token_id, 1u32.into(), T::MaxRentalPeriod::get())
+	verify {
+		assert!(Rental::<T>::get_offer(token_id).is_some());
+	}
+
+	cancel_offer {
+		let caller = setup::<T>(1);
+		let token_id: TokenIdOf<T> = 0u32.into();
+	}: _(RawOrigin::Signed(caller), token_id)
+	verify {
+		assert!(Rental::<T>::get_offer(token_id).is_none());
+	}
+
+	rent {
+		setup::<T>(1);
+		let user = funded_user::<T>();
+		let token_id: TokenIdOf<T> = 0u32.into();
+		let max_fee = NativeBalanceOf::<T>::max_value();
+	}: _(RawOrigin::Signed(user.clone()), token_id, T::MaxRentalPeriod::get(), max_fee)
+	verify {
+		assert_eq!(Rental::<T>::user_of(token_id), Some(user));
+	}
+
+	on_initialize {
+		let n in 0 .. T::MaxExpiringPerBlock::get();
+		setup::<T>(n);
+		let user = funded_user::<T>();
+		for i in 0..n {
+			Rental::<T>::rent(
+				RawOrigin::Signed(user.clone()).into(),
+				i.into(),
+				T::MaxRentalPeriod::get(),
+				NativeBalanceOf::<T>::max_value(),
+			)?;
+		}
+		let expiry = frame_system::Pallet::<T>::block_number() + T::MaxRentalPeriod::get();
+	}: {
+		Rental::<T>::on_initialize(expiry);
+	}
+	verify {
+		assert!(Rental::<T>::get_expiring_at(expiry).is_empty());
+	}
+
+	impl_benchmark_test_suite!(Rental, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,259 @@
+//! Rentals of `pallet_erc1155` items, in the spirit of ERC-4907.
+//!
+//! The owner of an item offers it for a price per block. A renter pays for a period and becomes
+//! the item's user until it ends, while the item stays with the owner, locked so it can't be
+//! transferred away during the rental. Rentals end in `on_initialize` of their expiry block.
+//! Other pallets query users through `traits::UsageRights`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{Rental, RentalOffer, WeightInfo};
+	use frame_support::{
+		dispatch::DispatchResult,
+		ensure,
+		pallet_prelude::*,
+		traits::{Currency, ExistenceRequirement},
+		transactional,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{CheckedMul, One, SaturatedConversion, Saturating, Zero};
+	use traits::{Erc1155, Erc1155Lockable, LockIdentifier, UsageRights};
+
+	pub const RENTAL_ID: LockIdentifier = *b"rental  ";
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type TokenIdOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::TokenId;
+
+	pub type NativeBalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type RentalOfferOf<T> = RentalOffer<
+		<T as frame_system::Config>::AccountId,
+		NativeBalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	pub type RentalOf<T> =
+		Rental<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		type Tokens: Erc1155Lockable<Self::AccountId>;
+		/// Currency rental fees are paid in.
+		type Currency: Currency<Self::AccountId>;
+		/// Upper bound of the rental period owners can offer.
+		#[pallet::constant]
+		type MaxRentalPeriod: Get<Self::BlockNumber>;
+		/// Maximum number of rentals ending in one block.
+		#[pallet::constant]
+		type MaxExpiringPerBlock: Get<u32>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_offer)]
+	pub(super) type Offers<T: Config> =
+		StorageMap<_, Blake2_128Concat, TokenIdOf<T>, RentalOfferOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_rental)]
+	pub(super) type Rentals<T: Config> = StorageMap<_, Blake2_128Concat, TokenIdOf<T>, RentalOf<T>>;
+
+	/// Rentals ending at the start of a block.
+	#[pallet::storage]
+	#[pallet::getter(fn get_expiring_at)]
+	pub(super) type ExpiringAt<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<TokenIdOf<T>, T::MaxExpiringPerBlock>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		Offered {
+			token_id: TokenIdOf<T>,
+			owner: T::AccountId,
+			price_per_block: NativeBalanceOf<T>,
+			max_duration: T::BlockNumber,
+		},
+		OfferCancelled {
+			token_id: TokenIdOf<T>,
+		},
+		/// `user` may use the item until `expires_at` and paid `fee` to the owner.
+		Rented {
+			token_id: TokenIdOf<T>,
+			owner: T::AccountId,
+			user: T::AccountId,
+			expires_at: T::BlockNumber,
+			fee: NativeBalanceOf<T>,
+		},
+		/// The usage rights of `user` ended and the item was unlocked.
+		RentalExpired {
+			token_id: TokenIdOf<T>,
+			user: T::AccountId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Only the single holder of a token with a supply of one can rent it out.
+		NotItemOwner,
+		InvalidDuration,
+		UnknownOffer,
+		AlreadyRented,
+		OwnItem,
+		/// Too many rentals end in the same block.
+		TooManyExpiring,
+		Overflow,
+		/// The rental fee is above `max_fee`.
+		FeeTooHigh,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let expiring = ExpiringAt::<T>::take(now);
+			for &token_id in expiring.iter() {
+				if let Some(rental) = Rentals::<T>::take(token_id) {
+					T::Tokens::remove_lock(RENTAL_ID, &rental.owner, token_id);
+					Self::deposit_event(Event::RentalExpired { token_id, user: rental.user });
+				}
+			}
+			T::WeightInfo::on_initialize(expiring.len() as u32)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Offer an owned item for rent at `price_per_block` for up to `max_duration` blocks,
+		/// replacing a previous offer.
+		#[pallet::weight(T::WeightInfo::offer())]
+		pub fn offer(
+			origin: OriginFor<T>,
+			token_id: TokenIdOf<T>,
+			price_per_block: NativeBalanceOf<T>,
+			max_duration: T::BlockNumber,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::ensure_item_owner(&owner, token_id)?;
+			ensure!(
+				!max_duration.is_zero() && max_duration <= T::MaxRentalPeriod::get(),
+				Error::<T>::InvalidDuration
+			);
+			Offers::<T>::insert(
+				token_id,
+				RentalOffer { owner: owner.clone(), price_per_block, max_duration },
+			);
+			Self::deposit_event(Event::Offered { token_id, owner, price_per_block, max_duration });
+			Ok(())
+		}
+
+		/// Withdraw the rental offer of an item, running rentals are not affected.
+		#[pallet::weight(T::WeightInfo::cancel_offer())]
+		pub fn cancel_offer(origin: OriginFor<T>, token_id: TokenIdOf<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let offer = Self::get_offer(token_id).ok_or(Error::<T>::UnknownOffer)?;
+			ensure!(offer.owner == sender, Error::<T>::NotItemOwner);
+			Offers::<T>::remove(token_id);
+			Self::deposit_event(Event::OfferCancelled { token_id });
+			Ok(())
+		}
+
+		/// Rent an offered item for `duration` blocks for a fee of at most `max_fee`.
+		#[pallet::weight(T::WeightInfo::rent())]
+		#[transactional]
+		pub fn rent(
+			origin: OriginFor<T>,
+			token_id: TokenIdOf<T>,
+			duration: T::BlockNumber,
+			max_fee: NativeBalanceOf<T>,
+		) -> DispatchResult {
+			let user = ensure_signed(origin)?;
+			let offer = Self::get_offer(token_id).ok_or(Error::<T>::UnknownOffer)?;
+			ensure!(offer.owner != user, Error::<T>::OwnItem);
+			ensure!(!Rentals::<T>::contains_key(token_id), Error::<T>::AlreadyRented);
+			ensure!(
+				!duration.is_zero() && duration <= offer.max_duration,
+				Error::<T>::InvalidDuration
+			);
+			// the item may have changed hands since it was offered
+			Self::ensure_item_owner(&offer.owner, token_id)?;
+			let fee = offer
+				.price_per_block
+				.checked_mul(&duration.saturated_into::<u128>().saturated_into())
+				.ok_or(Error::<T>::Overflow)?;
+			ensure!(fee <= max_fee, Error::<T>::FeeTooHigh);
+			T::Currency::transfer(&user, &offer.owner, fee, ExistenceRequirement::KeepAlive)?;
+			T::Tokens::set_lock(RENTAL_ID, &offer.owner, token_id, One::one())?;
+			let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			ExpiringAt::<T>::try_mutate(expires_at, |expiring| {
+				expiring.try_push(token_id).map_err(|_| Error::<T>::TooManyExpiring)
+			})?;
+			Rentals::<T>::insert(
+				token_id,
+				Rental { owner: offer.owner.clone(), user: user.clone(), expires_at },
+			);
+			Self::deposit_event(Event::Rented {
+				token_id,
+				owner: offer.owner,
+				user,
+				expires_at,
+				fee,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn ensure_item_owner(who: &T::AccountId, token_id: TokenIdOf<T>) -> DispatchResult {
+			ensure!(
+				T::Tokens::total_supply(token_id)?.is_one() &&
+					T::Tokens::balance_of(who, token_id)?.is_one(),
+				Error::<T>::NotItemOwner
+			);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> UsageRights<T::AccountId, TokenIdOf<T>, T::BlockNumber> for Pallet<T> {
+		fn user_of(token_id: TokenIdOf<T>) -> Option<T::AccountId> {
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::get_rental(token_id)
+				.filter(|rental| now < rental.expires_at)
+				.map(|rental| rental.user)
+		}
+
+		fn user_expires(token_id: TokenIdOf<T>) -> Option<T::BlockNumber> {
+			Self::get_rental(token_id).map(|rental| rental.expires_at)
+		}
+	}
+}
@@ -0,0 +1,168 @@
+use crate::{mock::*, Error, Rental as RentalDetails, RentalOffer};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use traits::UsageRights;
+
+fn transfer(from: u64, to: u64, token_id: u32, amount: u128) -> sp_runtime::DispatchResult {
+	<Erc1155 as traits::Erc1155<u64>>::transfer(&from, &to, token_id, amount)
+}
+
+fn offer() {
+	assert_ok!(Rental::offer(Origin::signed(ALICE), ITEM_ID, 2, 50));
+}
+
+#[test]
+fn offer_should_work() {
+	new_test_ext().execute_with(|| {
+		offer();
+		System::assert_last_event(Event::Rental(crate::Event::Offered {
+			token_id: ITEM_ID,
+			owner: ALICE,
+			price_per_block: 2,
+			max_duration: 50,
+		}));
+		assert_eq!(
+			Rental::get_offer(ITEM_ID),
+			Some(RentalOffer { owner: ALICE, price_per_block: 2, max_duration: 50 })
+		);
+	});
+}
+
+#[test]
+fn offer_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Rental::offer(Origin::signed(BOB), ITEM_ID, 2, 50),
+			Error::<Test>::NotItemOwner
+		);
+		assert_noop!(
+			Rental::offer(Origin::signed(ALICE), FUNGIBLE_ID, 2, 50),
+			Error::<Test>::NotItemOwner
+		);
+		assert_noop!(
+			Rental::offer(Origin::signed(ALICE), ITEM_ID, 2, 0),
+			Error::<Test>::InvalidDuration
+		);
+		assert_noop!(
+			Rental::offer(Origin::signed(ALICE), ITEM_ID, 2, 101),
+			Error::<Test>::InvalidDuration
+		);
+	});
+}
+
+#[test]
+fn cancel_offer_should_work() {
+	new_test_ext().execute_with(|| {
+		offer();
+		assert_ok!(Rental::cancel_offer(Origin::signed(ALICE), ITEM_ID));
+		System::assert_last_event(Event::Rental(crate::Event::OfferCancelled {
+			token_id: ITEM_ID,
+		}));
+		assert_eq!(Rental::get_offer(ITEM_ID), None);
+	});
+}
+
+#[test]
+fn cancel_offer_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Rental::cancel_offer(Origin::signed(ALICE), ITEM_ID),
+			Error::<Test>::UnknownOffer
+		);
+		offer();
+		assert_noop!(
+			Rental::cancel_offer(Origin::signed(BOB), ITEM_ID),
+			Error::<Test>::NotItemOwner
+		);
+	});
+}
+
+#[test]
+fn rent_should_work() {
+	new_test_ext().execute_with(|| {
+		offer();
+		assert_ok!(Rental::rent(Origin::signed(BOB), ITEM_ID, 10, 20));
+		System::assert_last_event(Event::Rental(crate::Event::Rented {
+			token_id: ITEM_ID,
+			owner: ALICE,
+			user: BOB,
+			expires_at: 11,
+			fee: 20,
+		}));
+		assert_eq!(Balances::free_balance(ALICE), 1_020);
+		assert_eq!(Balances::free_balance(BOB), 980);
+		assert_eq!(
+			Rental::get_rental(ITEM_ID),
+			Some(RentalDetails { owner: ALICE, user: BOB, expires_at: 11 })
+		);
+		assert_eq!(Rental::user_of(ITEM_ID), Some(BOB));
+		assert_eq!(Rental::user_expires(ITEM_ID), Some(11));
+		// the item stays with its owner and can't be moved during the rental
+		assert_eq!(Erc1155::get_balance(ALICE, ITEM_ID), 1);
+		assert_noop!(
+			transfer(ALICE, CHARLIE, ITEM_ID, 1),
+			pallet_erc1155::Error::<Test>::LiquidityRestrictions
+		);
+	});
+}
+
+#[test]
+fn rental_should_expire() {
+	new_test_ext().execute_with(|| {
+		offer();
+		assert_ok!(Rental::rent(Origin::signed(BOB), ITEM_ID, 10, 20));
+		run_to_block(10);
+		assert_eq!(Rental::user_of(ITEM_ID), Some(BOB));
+		run_to_block(11);
+		System::assert_last_event(Event::Rental(crate::Event::RentalExpired {
+			token_id: ITEM_ID,
+			user: BOB,
+		}));
+		assert_eq!(Rental::user_of(ITEM_ID), None);
+		assert_eq!(Rental::get_rental(ITEM_ID), None);
+		assert!(Rental::get_expiring_at(11).is_empty());
+		assert_ok!(transfer(ALICE, CHARLIE, ITEM_ID, 1));
+
+		// the offer stands until cancelled
+		assert_ok!(Rental::offer(Origin::signed(CHARLIE), ITEM_ID, 1, 20));
+		assert_ok!(Rental::rent(Origin::signed(BOB), ITEM_ID, 20, 40));
+		assert_eq!(Rental::user_of(ITEM_ID), Some(BOB));
+	});
+}
+
+#[test]
+fn rent_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Rental::rent(Origin::signed(BOB), ITEM_ID, 10, 20),
+			Error::<Test>::UnknownOffer
+		);
+		offer();
+		assert_noop!(Rental::rent(Origin::signed(ALICE), ITEM_ID, 10, 20), Error::<Test>::OwnItem);
+		assert_noop!(
+			Rental::rent(Origin::signed(BOB), ITEM_ID, 0, 0),
+			Error::<Test>::InvalidDuration
+		);
+		assert_noop!(
+			Rental::rent(Origin::signed(BOB), ITEM_ID, 51, 102),
+			Error::<Test>::InvalidDuration
+		);
+		assert_noop!(Rental::rent(Origin::signed(BOB), ITEM_ID, 10, 19), Error::<Test>::FeeTooHigh);
+		assert_ok!(Rental::rent(Origin::signed(BOB), ITEM_ID, 10, 20));
+		assert_noop!(
+			Rental::rent(Origin::signed(CHARLIE), ITEM_ID, 10, 20),
+			Error::<Test>::AlreadyRented
+		);
+	});
+}
+
+#[test]
+fn rent_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		offer();
+		assert_ok!(transfer(ALICE, CHARLIE, ITEM_ID, 1));
+		assert_noop!(
+			Rental::rent(Origin::signed(BOB), ITEM_ID, 10, 20),
+			Error::<Test>::NotItemOwner
+		);
+	});
+}
@@ -0,0 +1,20 @@
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Standing offer of the owner of an item to rent it out.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RentalOffer<AccountId, NativeBalance, BlockNumber> {
+	pub owner: AccountId,
+	pub price_per_block: NativeBalance,
+	/// Longest rental period a single `rent` can buy.
+	pub max_duration: BlockNumber,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Rental<AccountId, BlockNumber> {
+	pub owner: AccountId,
+	pub user: AccountId,
+	/// First block the user no longer has usage rights.
+	pub expires_at: BlockNumber,
+}
@@ -0,0 +1,93 @@
+//! Weights for pallet_rental.
+//!
+//! These are hand-written estimates that follow the storage accesses of each call, not the output
+//! of a benchmark run. Replace them with measured weights by running:
+//! ./target/release/node-template benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_rental --extrinsic '*' --steps 50 --repeat 20
+//! --output ./pallets/rental/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_rental.
+pub trait WeightInfo {
+	fn offer() -> Weight;
+	fn cancel_offer() -> Weight;
+	fn rent() -> Weight;
+	fn on_initialize(n: u32) -> Weight;
+}
+
+/// Weights for pallet_rental using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 Balances (r:1 w:0)
+	// Storage: Rental Offers (r:0 w:1)
+	fn offer() -> Weight {
+		(27_640_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Rental Offers (r:1 w:1)
+	fn cancel_offer() -> Weight {
+		(19_850_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Rental Offers (r:1 w:0)
+	// Storage: Rental Rentals (r:1 w:1)
+	// Storage: Rental ExpiringAt (r:1 w:1)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 Balances (r:1 w:0)
+	// Storage: Erc1155 Locks (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn rent() -> Weight {
+		(71_360_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Rental ExpiringAt (r:1 w:1)
+	// Storage: Rental Rentals (r:1 w:1)
+	// Storage: Erc1155 Locks (r:1 w:1)
+	fn on_initialize(n: u32) -> Weight {
+		(3_870_000 as Weight)
+			.saturating_add((14_920_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn offer() -> Weight {
+		(27_640_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_offer() -> Weight {
+		(19_850_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn rent() -> Weight {
+		(71_360_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn on_initialize(n: u32) -> Weight {
+		(3_870_000 as Weight)
+			.saturating_add((14_920_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+}
@@ -0,0 +1,23 @@
+//! Benchmarking setup for pallet-token-payment
+
+use super::*;
+
+use crate::Pallet as TokenPayment;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::{EnsureOrigin, UnfilteredDispatchable};
+use sp_runtime::FixedU128;
+
+benchmarks! {
+	where_clause { where T::TokenId: From<u32> }
+
+	set_fee_rate {
+		let token_id: T::TokenId = 1u32.into();
+		let origin = <T as Config>::FeeRateOrigin::successful_origin();
+		let call = Call::<T>::set_fee_rate { token_id, rate: Some(FixedU128::from(2)) };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(TokenPayment::<T>::get_fee_rate(token_id), Some(FixedU128::from(2)));
+	}
+
+	impl_benchmark_test_suite!(TokenPayment, crate::mock::new_test_ext(), crate::mock::Test);
+}
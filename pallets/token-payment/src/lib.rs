@@ -0,0 +1,383 @@
+//! Pay transaction fees in `pallet_erc1155` tokens.
+//!
+//! `ChargeTokenPayment` replaces `pallet_transaction_payment::ChargeTransactionPayment` in the
+//! runtime's `SignedExtra`. Without a token id it charges the native currency like the extension
+//! it replaces. With a token id the fee is converted at the fixed rate set by
+//! `Config::FeeRateOrigin` or, without one, at the price of the `pallet_dex` pool pairing the token
+//! with `Config::NativeTokenId`. The native token is created at genesis so nobody else can issue
+//! it, and a pool only prices fees while it holds `Config::MinPoolLiquidity` of the native token.
+//! Converted fees are rounded up. The converted fee is withdrawn before dispatch, the unused part
+//! is refunded in the same token afterwards and the rest paid to `Config::FeeCollector`. These
+//! moves bypass the transfer fees and minimum balances of the token, so they cannot fail after
+//! dispatch.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use codec::{Decode, Encode};
+use frame_support::{
+	traits::Get,
+	weights::{DispatchInfo, PostDispatchInfo},
+};
+use pallet_transaction_payment::ChargeTransactionPayment;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{
+		DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SaturatedConversion, Saturating,
+		SignedExtension, Zero,
+	},
+	transaction_validity::{
+		InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError,
+		ValidTransaction,
+	},
+	DispatchResult, FixedPointOperand, Perbill,
+};
+
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::WeightInfo;
+	use frame_support::{pallet_prelude::*, PalletId};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{AccountIdConversion, SaturatedConversion},
+		FixedPointNumber, FixedU128,
+	};
+	use traits::{Erc1155, Erc1155Mintable};
+
+	pub type NativeBalanceOf<T> = pallet_transaction_payment::BalanceOf<T>;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_transaction_payment::Config
+		+ pallet_erc1155::Config
+		+ pallet_dex::Config<Tokens = pallet_erc1155::Pallet<Self>>
+	{
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		/// Token the dex pool prices fees in, one unit of it pays one unit of native fee.
+		#[pallet::constant]
+		type NativeTokenId: Get<Self::TokenId>;
+		/// Native token the dex pool has to hold before it prices fees.
+		#[pallet::constant]
+		type MinPoolLiquidity: Get<Self::Balance>;
+		/// Origin allowed to set fee rates.
+		type FeeRateOrigin: EnsureOrigin<Self::Origin>;
+		/// Receives the fees paid in tokens.
+		type FeeCollector: Get<Self::AccountId>;
+		/// Derives the account holding fees between withdrawal and refund.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// Token units charged per unit of native fee, takes precedence over the dex price.
+	#[pallet::storage]
+	#[pallet::getter(fn get_fee_rate)]
+	pub(super) type FeeRates<T: Config> = StorageMap<_, Blake2_128Concat, T::TokenId, FixedU128>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Issuer of `Config::NativeTokenId`, the pallet account if `None`.
+		pub native_token_issuer: Option<T::AccountId>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { native_token_issuer: None }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			let issuer = self.native_token_issuer.clone().unwrap_or_else(Pallet::<T>::account_id);
+			pallet_erc1155::Pallet::<T>::create(&issuer, T::NativeTokenId::get())
+				.expect("the native token is created first");
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		FeeRateSet {
+			token_id: T::TokenId,
+			rate: Option<FixedU128>,
+		},
+		/// `who` paid `fee` of the native currency with `paid` of `token_id`.
+		TokenFeePaid {
+			who: T::AccountId,
+			token_id: T::TokenId,
+			fee: NativeBalanceOf<T>,
+			paid: T::Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		ZeroFeeRate,
+		/// The token has neither a fixed rate nor a pool with enough of the native token.
+		NoFeeConversion,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the fixed amount of `token_id` charged per unit of native fee, `None` falls back to
+		/// the dex price.
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_rate())]
+		pub fn set_fee_rate(
+			origin: OriginFor<T>,
+			token_id: T::TokenId,
+			rate: Option<FixedU128>,
+		) -> DispatchResult {
+			T::FeeRateOrigin::ensure_origin(origin)?;
+			match rate {
+				Some(rate) => {
+					ensure!(!rate.is_zero(), Error::<T>::ZeroFeeRate);
+					FeeRates::<T>::insert(&token_id, rate);
+				},
+				None => FeeRates::<T>::remove(&token_id),
+			}
+			Self::deposit_event(Event::FeeRateSet { token_id, rate });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding withdrawn fees until the transaction is dispatched.
+		pub fn account_id() -> T::AccountId {
+			<T as Config>::PalletId::get().into_account()
+		}
+
+		/// Amount of `token_id` paying `fee` of the native currency, rounded up so a nonzero fee
+		/// is never free.
+		pub fn fee_in_token(
+			token_id: T::TokenId,
+			fee: NativeBalanceOf<T>,
+		) -> Result<T::Balance, DispatchError> {
+			let fee = fee.saturated_into::<u128>();
+			if let Some(rate) = Self::get_fee_rate(token_id) {
+				let paid = FixedU128::saturating_from_integer(fee).saturating_mul(rate).ceil();
+				return Ok((paid.into_inner() / FixedU128::accuracy()).saturated_into())
+			}
+			let native_id = T::NativeTokenId::get();
+			if token_id == native_id {
+				return Ok(fee.saturated_into())
+			}
+			ensure!(
+				matches!(
+					pallet_dex::Pallet::<T>::get_token_ids(),
+					Some(pair) if pair == (token_id, native_id) || pair == (native_id, token_id)
+				),
+				Error::<T>::NoFeeConversion
+			);
+			let pool =
+				pallet_dex::Pallet::<T>::get_pool_address().ok_or(Error::<T>::NoFeeConversion)?;
+			ensure!(
+				pallet_erc1155::Pallet::<T>::balance_of(&pool, native_id)? >=
+					T::MinPoolLiquidity::get(),
+				Error::<T>::NoFeeConversion
+			);
+			pallet_dex::Pallet::<T>::quote_input(token_id, fee.saturated_into())
+		}
+	}
+}
+
+/// Charges the transaction fee and tip in the native currency or, if `token_id` is set, in that
+/// token.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeTokenPayment<T: Config> {
+	#[codec(compact)]
+	tip: NativeBalanceOf<T>,
+	token_id: Option<T::TokenId>,
+}
+
+impl<T: Config> ChargeTokenPayment<T> {
+	pub fn new(tip: NativeBalanceOf<T>, token_id: Option<T::TokenId>) -> Self {
+		Self { tip, token_id }
+	}
+
+	/// Native fee of the transaction and the amount of `token_id` paying it.
+	fn token_fee(
+		&self,
+		token_id: T::TokenId,
+		info: &DispatchInfoOf<<T as frame_system::Config>::Call>,
+		len: usize,
+	) -> Result<(NativeBalanceOf<T>, T::Balance), TransactionValidityError>
+	where
+		NativeBalanceOf<T>: FixedPointOperand,
+		<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo>,
+	{
+		let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.tip);
+		let paid =
+			Pallet::<T>::fee_in_token(token_id, fee).map_err(|_| InvalidTransaction::Payment)?;
+		Ok((fee, paid))
+	}
+}
+
+impl<T: Config> sp_std::fmt::Debug for ChargeTokenPayment<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ChargeTokenPayment<{:?}, {:?}>", self.tip, self.token_id)
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+pub enum Pre<T: Config>
+where
+	NativeBalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	<T as frame_system::Config>::Call:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	Native(<ChargeTransactionPayment<T> as SignedExtension>::Pre),
+	Token {
+		who: T::AccountId,
+		token_id: T::TokenId,
+		tip: NativeBalanceOf<T>,
+		/// Native fee estimated before dispatch.
+		fee: NativeBalanceOf<T>,
+		/// Tokens withdrawn for `fee`.
+		paid: T::Balance,
+	},
+}
+
+impl<T: Config> Default for Pre<T>
+where
+	NativeBalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	<T as frame_system::Config>::Call:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	fn default() -> Self {
+		Self::Native(Default::default())
+	}
+}
+
+impl<T: Config> SignedExtension for ChargeTokenPayment<T>
+where
+	NativeBalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	<T as frame_system::Config>::Call:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	const IDENTIFIER: &'static str = "ChargeTokenPayment";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::Call;
+	type AdditionalSigned = ();
+	type Pre = Pre<T>;
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		let token_id = match self.token_id {
+			Some(token_id) => token_id,
+			None =>
+				return ChargeTransactionPayment::<T>::from(self.tip).validate(who, call, info, len),
+		};
+		let (_, paid) = self.token_fee(token_id, info, len)?;
+		if pallet_erc1155::Pallet::<T>::transferable_balance(who, &token_id) < paid {
+			return Err(InvalidTransaction::Payment.into())
+		}
+		Ok(ValidTransaction {
+			priority: self.tip.saturated_into::<TransactionPriority>(),
+			..Default::default()
+		})
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let token_id = match self.token_id {
+			Some(token_id) => token_id,
+			None =>
+				return ChargeTransactionPayment::<T>::from(self.tip)
+					.pre_dispatch(who, call, info, len)
+					.map(Pre::Native),
+		};
+		let (fee, paid) = self.token_fee(token_id, info, len)?;
+		if pallet_erc1155::Pallet::<T>::transferable_balance(who, &token_id) < paid {
+			return Err(InvalidTransaction::Payment.into())
+		}
+		if !paid.is_zero() {
+			pallet_erc1155::Pallet::<T>::do_transfer_held(
+				who,
+				&Pallet::<T>::account_id(),
+				&token_id,
+				&paid,
+			)
+			.map_err(|_| InvalidTransaction::Payment)?;
+		}
+		Ok(Pre::Token { who: who.clone(), token_id, tip: self.tip, fee, paid })
+	}
+
+	fn post_dispatch(
+		pre: Self::Pre,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		let (who, token_id, tip, fee, paid) = match pre {
+			Pre::Native(pre) =>
+				return ChargeTransactionPayment::<T>::post_dispatch(
+					pre, info, post_info, len, result,
+				),
+			Pre::Token { who, token_id, tip, fee, paid } => (who, token_id, tip, fee, paid),
+		};
+		let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+			len as u32, info, post_info, tip,
+		);
+		// the token is charged in proportion to the native fee actually due
+		let charged = if fee.is_zero() {
+			paid
+		} else {
+			Perbill::from_rational(actual_fee, fee).mul_ceil(paid)
+		};
+		let refund = paid.saturating_sub(charged);
+		let account = Pallet::<T>::account_id();
+		for (to, amount) in [(who.clone(), refund), (T::FeeCollector::get(), charged)] {
+			if !amount.is_zero() {
+				pallet_erc1155::Pallet::<T>::do_transfer_held(&account, &to, &token_id, &amount)
+					.map_err(|_| InvalidTransaction::Payment)?;
+			}
+		}
+		Pallet::<T>::deposit_event(Event::TokenFeePaid {
+			who,
+			token_id,
+			fee: actual_fee,
+			paid: charged,
+		});
+		Ok(())
+	}
+}
@@ -0,0 +1,238 @@
+use crate::{mock::*, ChargeTokenPayment, Error};
+use frame_support::{
+	assert_noop, assert_ok,
+	weights::{DispatchInfo, PostDispatchInfo, Weight},
+};
+use sp_runtime::{
+	traits::SignedExtension, transaction_validity::InvalidTransaction, DispatchError, FixedU128,
+};
+
+const CALL: &Call = &Call::Balances(pallet_balances::Call::transfer { dest: BOB, value: 10 });
+const LEN: usize = 10;
+
+fn info(weight: Weight) -> DispatchInfo {
+	DispatchInfo { weight, ..Default::default() }
+}
+
+fn post_info(actual_weight: Weight) -> PostDispatchInfo {
+	PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Default::default() }
+}
+
+fn init_tokens() {
+	assert_ok!(<Erc1155 as traits::Erc1155Mintable<u64>>::mint(&ALICE, NATIVE_TOKEN_ID, 10_000));
+	assert_ok!(<Erc1155 as traits::Erc1155<u64>>::init(&ALICE, &vec![TOKEN_ID], &vec![10_000]));
+}
+
+fn init_dex() {
+	<Erc1155 as traits::Erc1155<u64>>::set_approval_for_all(&ALICE, &POOL, true);
+	assert_ok!(Dex::init(Origin::signed(ALICE), POOL, NATIVE_TOKEN_ID, 1000, TOKEN_ID, 1000));
+}
+
+/// Runs the extension around a call of weight 100 that used `actual_weight`, the fee is 110
+/// before and `actual_weight + 10` after dispatch.
+fn charge(who: u64, token_id: Option<u32>, actual_weight: Weight) {
+	let pre = ChargeTokenPayment::<Test>::new(0, token_id)
+		.pre_dispatch(&who, CALL, &info(100), LEN)
+		.unwrap();
+	assert_ok!(ChargeTokenPayment::<Test>::post_dispatch(
+		pre,
+		&info(100),
+		&post_info(actual_weight),
+		LEN,
+		&Ok(())
+	));
+}
+
+#[test]
+fn native_payment_should_work() {
+	new_test_ext().execute_with(|| {
+		charge(ALICE, None, 50);
+		assert_eq!(Balances::free_balance(ALICE), 940);
+	});
+}
+
+#[test]
+fn set_fee_rate_should_work() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+		let rate = FixedU128::from(2);
+		assert_ok!(TokenPayment::set_fee_rate(Origin::root(), TOKEN_ID, Some(rate)));
+		assert_eq!(TokenPayment::get_fee_rate(TOKEN_ID), Some(rate));
+		System::assert_last_event(Event::TokenPayment(crate::Event::FeeRateSet {
+			token_id: TOKEN_ID,
+			rate: Some(rate),
+		}));
+		assert_ok!(TokenPayment::set_fee_rate(Origin::root(), TOKEN_ID, None));
+		assert_eq!(TokenPayment::get_fee_rate(TOKEN_ID), None);
+	});
+}
+
+#[test]
+fn set_fee_rate_should_fail() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+		// token issuers can't set the rate of their own token
+		assert_noop!(
+			TokenPayment::set_fee_rate(Origin::signed(ALICE), TOKEN_ID, Some(FixedU128::from(2))),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			TokenPayment::set_fee_rate(Origin::root(), TOKEN_ID, Some(FixedU128::from(0))),
+			Error::<Test>::ZeroFeeRate
+		);
+	});
+}
+
+#[test]
+fn fixed_rate_payment_should_work() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+		assert_ok!(TokenPayment::set_fee_rate(Origin::root(), TOKEN_ID, Some(FixedU128::from(2))));
+		let pre = ChargeTokenPayment::<Test>::new(0, Some(TOKEN_ID))
+			.pre_dispatch(&ALICE, CALL, &info(100), LEN)
+			.unwrap();
+		assert_eq!(Erc1155::get_balance(ALICE, TOKEN_ID), 10_000 - 220);
+		assert_eq!(Erc1155::get_balance(TokenPayment::account_id(), TOKEN_ID), 220);
+		assert_ok!(ChargeTokenPayment::<Test>::post_dispatch(
+			pre,
+			&info(100),
+			&post_info(50),
+			LEN,
+			&Ok(())
+		));
+		// 60 of the estimated 110 were due, the rest is refunded
+		assert_eq!(Erc1155::get_balance(ALICE, TOKEN_ID), 10_000 - 120);
+		assert_eq!(Erc1155::get_balance(COLLECTOR, TOKEN_ID), 120);
+		assert_eq!(Erc1155::get_balance(TokenPayment::account_id(), TOKEN_ID), 0);
+		assert_eq!(Balances::free_balance(ALICE), 1_000);
+		System::assert_last_event(Event::TokenPayment(crate::Event::TokenFeePaid {
+			who: ALICE,
+			token_id: TOKEN_ID,
+			fee: 60,
+			paid: 120,
+		}));
+	});
+}
+
+#[test]
+fn fixed_rate_payment_should_round_up() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+		assert_ok!(TokenPayment::set_fee_rate(
+			Origin::root(),
+			TOKEN_ID,
+			Some(FixedU128::from_inner(1))
+		));
+		assert_eq!(TokenPayment::fee_in_token(TOKEN_ID, 110), Ok(1));
+		assert_eq!(TokenPayment::fee_in_token(TOKEN_ID, 0), Ok(0));
+	});
+}
+
+#[test]
+fn native_token_should_be_reserved() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Erc1155::get_token(NATIVE_TOKEN_ID).unwrap().owner, Some(ALICE));
+		assert_noop!(
+			Erc1155::init(
+				Origin::signed(BOB),
+				vec![NATIVE_TOKEN_ID].try_into().unwrap(),
+				vec![10_000].try_into().unwrap()
+			),
+			pallet_erc1155::Error::<Test>::AlreadyInitialized
+		);
+	});
+}
+
+#[test]
+fn dex_payment_should_work() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+		init_dex();
+		// 0.99 * 126 * 1000 / (1000 + 124) = 111 >= 110
+		assert_eq!(Dex::quote_input(TOKEN_ID, 110), Ok(126));
+		charge(ALICE, Some(TOKEN_ID), 100);
+		assert_eq!(Erc1155::get_balance(ALICE, TOKEN_ID), 10_000 - 1000 - 126);
+		assert_eq!(Erc1155::get_balance(COLLECTOR, TOKEN_ID), 126);
+
+		// the native token pays fees one to one
+		charge(ALICE, Some(NATIVE_TOKEN_ID), 100);
+		assert_eq!(Erc1155::get_balance(COLLECTOR, NATIVE_TOKEN_ID), 110);
+		assert_eq!(Balances::free_balance(ALICE), 1_000);
+	});
+}
+
+#[test]
+fn token_payment_should_fail() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+		let extension = ChargeTokenPayment::<Test>::new(0, Some(TOKEN_ID));
+		// no rate and no pool
+		assert_eq!(
+			extension.validate(&ALICE, CALL, &info(100), LEN),
+			Err(InvalidTransaction::Payment.into())
+		);
+
+		// a pool without enough of the native token doesn't price fees
+		<Erc1155 as traits::Erc1155<u64>>::set_approval_for_all(&ALICE, &POOL, true);
+		assert_ok!(Dex::init(Origin::signed(ALICE), POOL, NATIVE_TOKEN_ID, 499, TOKEN_ID, 1000));
+		assert_eq!(
+			extension.validate(&ALICE, CALL, &info(100), LEN),
+			Err(InvalidTransaction::Payment.into())
+		);
+		assert_ok!(Dex::deposit(Origin::signed(ALICE), NATIVE_TOKEN_ID, 1));
+		assert_eq!(
+			extension.validate(&BOB, CALL, &info(100), LEN),
+			Err(InvalidTransaction::Payment.into())
+		);
+		assert!(extension.clone().pre_dispatch(&BOB, CALL, &info(100), LEN).is_err());
+		assert!(extension.validate(&ALICE, CALL, &info(100), LEN).is_ok());
+	});
+}
+
+#[test]
+fn token_payment_should_ignore_min_balance() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+		assert_ok!(TokenPayment::set_fee_rate(Origin::root(), TOKEN_ID, Some(FixedU128::from(2))));
+		assert_ok!(Erc1155::set_min_balance(
+			Origin::signed(ALICE),
+			TOKEN_ID,
+			500,
+			pallet_erc1155::DustPolicy::SweepToRecipient
+		));
+		// neither the escrowed fee nor the payout reaches the minimum balance
+		charge(ALICE, Some(TOKEN_ID), 50);
+		assert_eq!(Erc1155::get_balance(ALICE, TOKEN_ID), 10_000 - 120);
+		assert_eq!(Erc1155::get_balance(COLLECTOR, TOKEN_ID), 120);
+		assert_eq!(Erc1155::get_balance(TokenPayment::account_id(), TOKEN_ID), 0);
+		assert_eq!(Erc1155::get_total_supply(TOKEN_ID), 10_000);
+	});
+}
+
+#[test]
+fn token_payment_should_respect_locks() {
+	new_test_ext().execute_with(|| {
+		init_tokens();
+		assert_ok!(TokenPayment::set_fee_rate(Origin::root(), TOKEN_ID, Some(FixedU128::from(2))));
+		let extension = ChargeTokenPayment::<Test>::new(0, Some(TOKEN_ID));
+		assert_ok!(<Erc1155 as traits::Erc1155Lockable<u64>>::set_lock(
+			*b"locked!!",
+			&ALICE,
+			TOKEN_ID,
+			10_000 - 200
+		));
+		assert_eq!(
+			extension.validate(&ALICE, CALL, &info(100), LEN),
+			Err(InvalidTransaction::Payment.into())
+		);
+		assert!(extension.clone().pre_dispatch(&ALICE, CALL, &info(100), LEN).is_err());
+
+		assert_ok!(<Erc1155 as traits::Erc1155Lockable<u64>>::set_lock(
+			*b"locked!!",
+			&ALICE,
+			TOKEN_ID,
+			10_000 - 220
+		));
+		assert!(extension.validate(&ALICE, CALL, &info(100), LEN).is_ok());
+	});
+}
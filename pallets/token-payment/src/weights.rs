@@ -0,0 +1,37 @@
+//! Weights for pallet_token_payment.
+//!
+//! These are hand-written estimates that follow the storage accesses of each call, not the output
+//! of a benchmark run. Replace them with measured weights by running:
+//! ./target/release/node-template benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_token_payment --extrinsic '*' --steps 50 --repeat 20
+//! --output ./pallets/token-payment/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_token_payment.
+pub trait WeightInfo {
+	fn set_fee_rate() -> Weight;
+}
+
+/// Weights for pallet_token_payment using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: TokenPayment FeeRates (r:0 w:1)
+	fn set_fee_rate() -> Weight {
+		(12_480_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_fee_rate() -> Weight {
+		(12_480_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
@@ -0,0 +1,79 @@
+//! Benchmarking setup for pallet-token-vesting
+
+use super::*;
+
+use crate::Pallet as TokenVesting;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::vec;
+use traits::Erc1155;
+
+const SEED: u32 = 0;
+
+fn schedule<T: Config>() -> VestingScheduleOf<T> {
+	VestingSchedule {
+		locked: T::MinVestedTransfer::get(),
+		start: 0u32.into(),
+		cliff: 0u32.into(),
+		end: 100u32.into(),
+	}
+}
+
+/// Give the caller tokens and `s` schedules towards `target`.
+fn setup<T: Config>(target: &T::AccountId, s: u32) -> (T::AccountId, TokenIdOf<T>)
+where
+	TokenIdOf<T>: From<u32>,
+{
+	let caller: T::AccountId = whitelisted_caller();
+	let token_id: TokenIdOf<T> = 0u32.into();
+	T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value() / 2u32.into());
+	T::Tokens::init(&caller, &vec![token_id], &vec![1_000_000u32.into()]).unwrap();
+	for _ in 0..s {
+		TokenVesting::<T>::vested_transfer(
+			RawOrigin::Signed(caller.clone()).into(),
+			target.clone(),
+			token_id,
+			schedule::<T>(),
+		)
+		.unwrap();
+	}
+	(caller, token_id)
+}
+
+benchmarks! {
+	where_clause { where TokenIdOf<T>: From<u32> }
+
+	vest {
+		let s in 1 .. T::MaxVestingSchedules::get();
+		let target: T::AccountId = account("target", 0, SEED);
+		let (_, token_id) = setup::<T>(&target, s);
+		frame_system::Pallet::<T>::set_block_number(50u32.into());
+	}: _(RawOrigin::Signed(target.clone()), token_id)
+	verify {
+		let unvested = TokenVesting::<T>::vesting_balance(&target, token_id);
+		assert!(unvested < T::MinVestedTransfer::get() * s.into());
+	}
+
+	vested_transfer {
+		let s in 0 .. T::MaxVestingSchedules::get() - 1;
+		let target: T::AccountId = account("target", 0, SEED);
+		let (caller, token_id) = setup::<T>(&target, s);
+	}: _(RawOrigin::Signed(caller), target.clone(), token_id, schedule::<T>())
+	verify {
+		assert_eq!(TokenVesting::<T>::get_vesting(&target, token_id).len() as u32, s + 1);
+	}
+
+	merge_schedules {
+		let s in 2 .. T::MaxVestingSchedules::get();
+		let target: T::AccountId = account("target", 0, SEED);
+		let (_, token_id) = setup::<T>(&target, s);
+		frame_system::Pallet::<T>::set_block_number(50u32.into());
+	}: _(RawOrigin::Signed(target.clone()), token_id, 0, 1)
+	verify {
+		assert_eq!(TokenVesting::<T>::get_vesting(&target, token_id).len() as u32, s - 1);
+	}
+
+	impl_benchmark_test_suite!(TokenVesting, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,292 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+mod types;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{VestingSchedule, WeightInfo};
+	use frame_support::{
+		dispatch::DispatchResult,
+		ensure,
+		pallet_prelude::*,
+		traits::{Currency, ReservableCurrency},
+		transactional,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Saturating, Zero};
+	use traits::{Erc1155, Erc1155Lockable, LockIdentifier};
+
+	pub const VESTING_ID: LockIdentifier = *b"vesting ";
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type TokenIdOf<T> =
+		<<T as Config>::Tokens as Erc1155<<T as frame_system::Config>::AccountId>>::TokenId;
+
+	pub type DepositBalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type VestingScheduleOf<T> =
+		VestingSchedule<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		type Tokens: Erc1155Lockable<Self::AccountId>;
+		/// Minimum amount that can be transferred with a vesting schedule.
+		#[pallet::constant]
+		type MinVestedTransfer: Get<BalanceOf<Self>>;
+		/// Maximum number of schedules per account and token.
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+		/// Currency used for schedule deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Deposit reserved from the sender of a vested transfer. It is returned once every
+		/// schedule of the target for the token has ended, so filling up the schedules of an
+		/// account isn't free.
+		#[pallet::constant]
+		type ScheduleDeposit: Get<DepositBalanceOf<Self>>;
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_vesting)]
+	pub(super) type Vesting<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		TokenIdOf<T>,
+		BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules>,
+		ValueQuery,
+	>;
+
+	/// Deposits reserved for the schedules of an account and token, by sender.
+	#[pallet::storage]
+	#[pallet::getter(fn get_schedule_deposits)]
+	pub(super) type ScheduleDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		TokenIdOf<T>,
+		BoundedVec<(T::AccountId, DepositBalanceOf<T>), T::MaxVestingSchedules>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		VestingCreated {
+			account: T::AccountId,
+			token_id: TokenIdOf<T>,
+			schedule: VestingScheduleOf<T>,
+		},
+		VestingUpdated {
+			account: T::AccountId,
+			token_id: TokenIdOf<T>,
+			unvested: BalanceOf<T>,
+		},
+		VestingCompleted {
+			account: T::AccountId,
+			token_id: TokenIdOf<T>,
+		},
+		SchedulesMerged {
+			account: T::AccountId,
+			token_id: TokenIdOf<T>,
+			schedule: VestingScheduleOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		NotVesting,
+		AtMaxVestingSchedules,
+		AmountLow,
+		InvalidScheduleParams,
+		ScheduleIndexOutOfBounds,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Unlock the vested part of the sender's balance of `token_id`.
+		#[pallet::weight(T::WeightInfo::vest(T::MaxVestingSchedules::get()))]
+		#[transactional]
+		pub fn vest(origin: OriginFor<T>, token_id: TokenIdOf<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(Vesting::<T>::contains_key(&sender, &token_id), Error::<T>::NotVesting);
+			Self::update_lock(&sender, token_id)
+		}
+
+		/// Transfer tokens to `target` and lock what it receives under `schedule`, reserving
+		/// `Config::ScheduleDeposit` from the sender.
+		#[pallet::weight(T::WeightInfo::vested_transfer(T::MaxVestingSchedules::get()))]
+		#[transactional]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			target: T::AccountId,
+			token_id: TokenIdOf<T>,
+			schedule: VestingScheduleOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(schedule.is_valid(), Error::<T>::InvalidScheduleParams);
+			ensure!(schedule.locked >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+			let deposit = T::ScheduleDeposit::get();
+			T::Currency::reserve(&sender, deposit)?;
+			ScheduleDeposits::<T>::try_mutate(&target, &token_id, |deposits| {
+				match deposits.iter_mut().find(|(depositor, _)| depositor == &sender) {
+					Some((_, reserved)) => *reserved = reserved.saturating_add(deposit),
+					None => deposits
+						.try_push((sender.clone(), deposit))
+						.map_err(|_| Error::<T>::AtMaxVestingSchedules)?,
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+			// only what the target received after the transfer fee of the token gets locked
+			let balance = T::Tokens::balance_of(&target, token_id)?;
+			T::Tokens::transfer(&sender, &target, token_id, schedule.locked)?;
+			let updated_balance = T::Tokens::balance_of(&target, token_id)?;
+			let received = if sender == target {
+				schedule.locked.saturating_sub(balance.saturating_sub(updated_balance))
+			} else {
+				updated_balance.saturating_sub(balance)
+			};
+			let schedule = VestingSchedule { locked: received, ..schedule };
+			ensure!(schedule.is_valid(), Error::<T>::AmountLow);
+			Vesting::<T>::try_mutate(&target, &token_id, |schedules| {
+				schedules.try_push(schedule).map_err(|_| Error::<T>::AtMaxVestingSchedules)
+			})?;
+			Self::deposit_event(Event::VestingCreated {
+				account: target.clone(),
+				token_id,
+				schedule,
+			});
+			Self::update_lock(&target, token_id)
+		}
+
+		/// Replace two schedules of the sender with one that releases their remaining amount
+		/// from now until the later end.
+		#[pallet::weight(T::WeightInfo::merge_schedules(T::MaxVestingSchedules::get()))]
+		#[transactional]
+		pub fn merge_schedules(
+			origin: OriginFor<T>,
+			token_id: TokenIdOf<T>,
+			schedule1_index: u32,
+			schedule2_index: u32,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			if schedule1_index == schedule2_index {
+				return Ok(())
+			}
+			let now = frame_system::Pallet::<T>::block_number();
+			let merged = Vesting::<T>::try_mutate(&sender, &token_id, |schedules| {
+				ensure!(!schedules.is_empty(), Error::<T>::NotVesting);
+				let (first, second) = if schedule1_index < schedule2_index {
+					(schedule1_index as usize, schedule2_index as usize)
+				} else {
+					(schedule2_index as usize, schedule1_index as usize)
+				};
+				ensure!(second < schedules.len(), Error::<T>::ScheduleIndexOutOfBounds);
+				let schedule2 = schedules.remove(second);
+				let schedule1 = schedules.remove(first);
+				let merged = Self::merge(schedule1, schedule2, now);
+				if let Some(merged) = merged {
+					schedules.try_push(merged).map_err(|_| Error::<T>::AtMaxVestingSchedules)?;
+				}
+				Ok::<_, DispatchError>(merged)
+			})?;
+			if let Some(schedule) = merged {
+				Self::deposit_event(Event::SchedulesMerged {
+					account: sender.clone(),
+					token_id,
+					schedule,
+				});
+			}
+			Self::update_lock(&sender, token_id)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Amount of `token_id` held by `who` that has not vested yet.
+		pub fn vesting_balance(who: &T::AccountId, token_id: TokenIdOf<T>) -> BalanceOf<T> {
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::get_vesting(who, token_id)
+				.iter()
+				.fold(Zero::zero(), |total: BalanceOf<T>, schedule| {
+					total.saturating_add(schedule.locked_at(now))
+				})
+		}
+
+		/// Drop finished schedules and set the lock to the unvested amount, the schedule deposits
+		/// are returned once no schedule is left.
+		fn update_lock(who: &T::AccountId, token_id: TokenIdOf<T>) -> DispatchResult {
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut schedules = Self::get_vesting(who, token_id);
+			schedules.retain(|schedule| !schedule.locked_at(now).is_zero());
+			let unvested = schedules.iter().fold(Zero::zero(), |total: BalanceOf<T>, schedule| {
+				total.saturating_add(schedule.locked_at(now))
+			});
+			if schedules.is_empty() {
+				Vesting::<T>::remove(who, token_id);
+				T::Tokens::remove_lock(VESTING_ID, who, token_id);
+				for (depositor, deposit) in ScheduleDeposits::<T>::take(who, token_id) {
+					T::Currency::unreserve(&depositor, deposit);
+				}
+				Self::deposit_event(Event::VestingCompleted { account: who.clone(), token_id });
+			} else {
+				Vesting::<T>::insert(who, token_id, schedules);
+				T::Tokens::set_lock(VESTING_ID, who, token_id, unvested)?;
+				Self::deposit_event(Event::VestingUpdated {
+					account: who.clone(),
+					token_id,
+					unvested,
+				});
+			}
+			Ok(())
+		}
+
+		fn merge(
+			schedule1: VestingScheduleOf<T>,
+			schedule2: VestingScheduleOf<T>,
+			now: T::BlockNumber,
+		) -> Option<VestingScheduleOf<T>> {
+			let locked1 = schedule1.locked_at(now);
+			let locked2 = schedule2.locked_at(now);
+			match (locked1.is_zero(), locked2.is_zero()) {
+				(true, true) => None,
+				(false, true) => Some(schedule1),
+				(true, false) => Some(schedule2),
+				(false, false) => {
+					let start = now.max(schedule1.start).max(schedule2.start);
+					let end = schedule1.end.max(schedule2.end);
+					let cliff = schedule1.cliff.max(schedule2.cliff).max(start).min(end);
+					Some(VestingSchedule {
+						locked: locked1.saturating_add(locked2),
+						start,
+						cliff,
+						end,
+					})
+				},
+			}
+		}
+	}
+}
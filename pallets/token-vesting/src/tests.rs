@@ -0,0 +1,219 @@
+use crate::{mock::*, Error, VestingSchedule, VESTING_ID};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Perbill;
+use traits::Erc1155Lockable;
+
+fn linear(locked: u128, start: u64, end: u64) -> VestingSchedule<u128, u64> {
+	VestingSchedule { locked, start, cliff: start, end }
+}
+
+fn transfer(from: u64, to: u64, amount: u128) -> sp_runtime::DispatchResult {
+	<Erc1155 as traits::Erc1155<u64>>::transfer(&from, &to, TOKEN_ID, amount)
+}
+
+#[test]
+fn vested_transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenVesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			TOKEN_ID,
+			linear(1000, 10, 20)
+		));
+		System::assert_last_event(Event::TokenVesting(crate::Event::VestingUpdated {
+			account: BOB,
+			token_id: TOKEN_ID,
+			unvested: 1000,
+		}));
+		assert_eq!(Erc1155::get_balance(BOB, TOKEN_ID), 1000);
+		assert_eq!(Erc1155::locked_balance(&BOB, TOKEN_ID), 1000);
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+		assert_eq!(
+			TokenVesting::get_schedule_deposits(BOB, TOKEN_ID).into_inner(),
+			vec![(ALICE, 10)]
+		);
+		assert_eq!(
+			transfer(BOB, CHARLIE, 1),
+			Err(pallet_erc1155::Error::<Test>::LiquidityRestrictions.into())
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_should_lock_received_amount() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Erc1155::set_transfer_fee(
+			Origin::signed(ALICE),
+			TOKEN_ID,
+			Perbill::from_percent(10),
+			CHARLIE
+		));
+		assert_ok!(TokenVesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			TOKEN_ID,
+			linear(1000, 10, 20)
+		));
+		assert_eq!(Erc1155::get_balance(BOB, TOKEN_ID), 900);
+		assert_eq!(
+			TokenVesting::get_vesting(BOB, TOKEN_ID).into_inner(),
+			vec![linear(900, 10, 20)]
+		);
+		assert_eq!(Erc1155::locked_balance(&BOB, TOKEN_ID), 900);
+	});
+}
+
+#[test]
+fn vested_transfer_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TokenVesting::vested_transfer(Origin::signed(ALICE), BOB, TOKEN_ID, linear(99, 10, 20)),
+			Error::<Test>::AmountLow
+		);
+		assert_noop!(
+			TokenVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				TOKEN_ID,
+				VestingSchedule { locked: 100, start: 10, cliff: 30, end: 20 }
+			),
+			Error::<Test>::InvalidScheduleParams
+		);
+		assert_noop!(
+			TokenVesting::vested_transfer(
+				Origin::signed(BOB),
+				CHARLIE,
+				TOKEN_ID,
+				linear(100, 10, 20)
+			),
+			pallet_erc1155::Error::<Test>::InsufficientFunds
+		);
+		assert_ok!(transfer(ALICE, CHARLIE, 100));
+		assert_noop!(
+			TokenVesting::vested_transfer(
+				Origin::signed(CHARLIE),
+				BOB,
+				TOKEN_ID,
+				linear(100, 10, 20)
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_should_fail_2() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..3 {
+			assert_ok!(TokenVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				TOKEN_ID,
+				linear(100, 10, 20)
+			));
+		}
+		assert_noop!(
+			TokenVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				TOKEN_ID,
+				linear(100, 10, 20)
+			),
+			Error::<Test>::AtMaxVestingSchedules
+		);
+	});
+}
+
+#[test]
+fn vest_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenVesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			TOKEN_ID,
+			linear(1000, 10, 20)
+		));
+		System::set_block_number(15);
+		assert_eq!(TokenVesting::vesting_balance(&BOB, TOKEN_ID), 500);
+		// the lock only moves when vesting is claimed
+		assert_eq!(Erc1155::locked_balance(&BOB, TOKEN_ID), 1000);
+		assert_ok!(TokenVesting::vest(Origin::signed(BOB), TOKEN_ID));
+		assert_eq!(Erc1155::locked_balance(&BOB, TOKEN_ID), 500);
+		assert_ok!(transfer(BOB, CHARLIE, 500));
+		assert!(transfer(BOB, CHARLIE, 1).is_err());
+
+		System::set_block_number(20);
+		assert_ok!(TokenVesting::vest(Origin::signed(BOB), TOKEN_ID));
+		System::assert_last_event(Event::TokenVesting(crate::Event::VestingCompleted {
+			account: BOB,
+			token_id: TOKEN_ID,
+		}));
+		assert!(Erc1155::get_locks(BOB, TOKEN_ID).iter().all(|lock| lock.id != VESTING_ID));
+		assert!(TokenVesting::get_vesting(BOB, TOKEN_ID).is_empty());
+		assert!(TokenVesting::get_schedule_deposits(BOB, TOKEN_ID).is_empty());
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_ok!(transfer(BOB, CHARLIE, 500));
+		assert_noop!(TokenVesting::vest(Origin::signed(BOB), TOKEN_ID), Error::<Test>::NotVesting);
+	});
+}
+
+#[test]
+fn cliff_should_lock_until_reached() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenVesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			TOKEN_ID,
+			VestingSchedule { locked: 1000, start: 10, cliff: 15, end: 20 }
+		));
+		System::set_block_number(14);
+		assert_eq!(TokenVesting::vesting_balance(&BOB, TOKEN_ID), 1000);
+		System::set_block_number(15);
+		assert_eq!(TokenVesting::vesting_balance(&BOB, TOKEN_ID), 500);
+	});
+}
+
+#[test]
+fn merge_schedules_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TokenVesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			TOKEN_ID,
+			linear(1000, 0, 20)
+		));
+		assert_ok!(TokenVesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			TOKEN_ID,
+			linear(600, 10, 40)
+		));
+		System::set_block_number(10);
+		assert_ok!(TokenVesting::merge_schedules(Origin::signed(BOB), TOKEN_ID, 0, 1));
+		assert_eq!(
+			TokenVesting::get_vesting(BOB, TOKEN_ID).into_inner(),
+			vec![linear(1100, 10, 40)]
+		);
+		assert_eq!(Erc1155::locked_balance(&BOB, TOKEN_ID), 1100);
+	});
+}
+
+#[test]
+fn merge_schedules_should_fail_1() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TokenVesting::merge_schedules(Origin::signed(BOB), TOKEN_ID, 0, 1),
+			Error::<Test>::NotVesting
+		);
+		assert_ok!(TokenVesting::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			TOKEN_ID,
+			linear(1000, 0, 20)
+		));
+		assert_noop!(
+			TokenVesting::merge_schedules(Origin::signed(BOB), TOKEN_ID, 0, 1),
+			Error::<Test>::ScheduleIndexOutOfBounds
+		);
+	});
+}
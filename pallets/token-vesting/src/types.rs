@@ -0,0 +1,39 @@
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Zero},
+	Perbill, RuntimeDebug,
+};
+
+/// Releases `locked` linearly from `start` until `end`, nothing is released before `cliff`.
+/// A schedule with `cliff == end` releases everything at once.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VestingSchedule<Balance, BlockNumber> {
+	pub locked: Balance,
+	pub start: BlockNumber,
+	pub cliff: BlockNumber,
+	pub end: BlockNumber,
+}
+
+impl<Balance, BlockNumber> VestingSchedule<Balance, BlockNumber>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	pub fn is_valid(&self) -> bool {
+		!self.locked.is_zero() && self.start <= self.cliff && self.cliff <= self.end
+	}
+
+	/// Amount still locked at block `now`.
+	pub fn locked_at(&self, now: BlockNumber) -> Balance {
+		if now < self.cliff {
+			self.locked
+		} else if now >= self.end {
+			Zero::zero()
+		} else {
+			let vested = Perbill::from_rational(now - self.start, self.end - self.start)
+				.mul_floor(self.locked);
+			self.locked - vested
+		}
+	}
+}
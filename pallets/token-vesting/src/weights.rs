@@ -0,0 +1,94 @@
+//! Weights for pallet_token_vesting.
+//!
+//! These are hand-written estimates that follow the storage accesses of each call, not the output
+//! of a benchmark run. Replace them with measured weights by running:
+//! ./target/release/node-template benchmark --chain dev --execution wasm --wasm-execution compiled
+//! --pallet pallet_token_vesting --extrinsic '*' --steps 50 --repeat 20
+//! --output ./pallets/token-vesting/src/weights.rs
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_token_vesting.
+pub trait WeightInfo {
+	fn vest(s: u32) -> Weight;
+	fn vested_transfer(s: u32) -> Weight;
+	fn merge_schedules(s: u32) -> Weight;
+}
+
+/// Weights for pallet_token_vesting using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: TokenVesting Vesting (r:1 w:1)
+	// Storage: Erc1155 Locks (r:1 w:1)
+	// Storage: TokenVesting ScheduleDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1 per depositor)
+	fn vest(s: u32) -> Weight {
+		(32_870_000 as Weight)
+			.saturating_add((183_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	// Storage: TokenVesting Vesting (r:2 w:1)
+	// Storage: Erc1155 Tokens (r:1 w:0)
+	// Storage: Erc1155 TotalSupply (r:1 w:0)
+	// Storage: Erc1155 FrozenTokens (r:1 w:0)
+	// Storage: Erc1155 Locks (r:2 w:1)
+	// Storage: Erc1155 Balances (r:2 w:2)
+	// Storage: Erc1155 CheckpointedTokens (r:2 w:0)
+	// Storage: Erc1155 BalanceCheckpoints (r:2 w:2)
+	// Storage: TokenVesting ScheduleDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn vested_transfer(s: u32) -> Weight {
+		(96_310_000 as Weight)
+			.saturating_add((214_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: TokenVesting Vesting (r:2 w:2)
+	// Storage: Erc1155 Locks (r:1 w:1)
+	// Storage: TokenVesting ScheduleDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1 per depositor)
+	fn merge_schedules(s: u32) -> Weight {
+		(40_680_000 as Weight)
+			.saturating_add((297_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn vest(s: u32) -> Weight {
+		(32_870_000 as Weight)
+			.saturating_add((183_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	fn vested_transfer(s: u32) -> Weight {
+		(96_310_000 as Weight)
+			.saturating_add((214_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn merge_schedules(s: u32) -> Weight {
+		(40_680_000 as Weight)
+			.saturating_add((297_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+}
@@ -11,10 +11,14 @@ use pallet_grandpa::{
 };
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
+use pallet_evm::{EnsureAddressTruncated, HashedAddressMapping};
+use sp_core::{crypto::KeyTypeId, OpaqueMetadata, U256};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
+	traits::{
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount,
+		NumberFor, Verify,
+	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
@@ -22,6 +26,7 @@ use sp_std::prelude::*;
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
+use traits::{AttributeNamespace, Erc1155Checkpoints};
 
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
@@ -31,7 +36,7 @@ pub use frame_support::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
@@ -40,6 +45,9 @@ use pallet_transaction_payment::CurrencyAdapter;
 pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
 
+mod precompiles;
+use precompiles::Precompiles;
+
 /// Import the template pallet.
 pub use pallet_dex;
 pub use pallet_erc1155;
@@ -99,10 +107,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 116,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 3,
 };
 
 /// This determines the average expected block time that we are targeting.
@@ -241,11 +249,12 @@ impl pallet_timestamp::Config for Runtime {
 parameter_types! {
 	pub const ExistentialDeposit: u128 = 500;
 	pub const MaxLocks: u32 = 50;
+	pub const MaxReserves: u32 = 50;
 }
 
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = MaxLocks;
-	type MaxReserves = ();
+	type MaxReserves = MaxReserves;
 	type ReserveIdentifier = [u8; 8];
 	/// The type for recording an account's balance.
 	type Balance = Balance;
@@ -277,13 +286,45 @@ impl pallet_sudo::Config for Runtime {
 
 parameter_types! {
     pub const Decimals: u32 = 6;
+    pub const MaxCheckpoints: u32 = 256;
+    pub const MigrationItemsPerBlock: u32 = 500;
+    pub const MaxBatchSize: u32 = 64;
+    pub const MaxDataLength: u32 = 256;
+    pub const StringLimit: u32 = 128;
+    pub const AttributeDepositBase: Balance = 5_000;
+    pub const DepositPerByte: Balance = 1;
+    pub const KeyLimit: u32 = 32;
+    pub const ValueLimit: u32 = 256;
+    pub const MaxEmissionSchedules: u32 = 16;
+    pub const Erc1155PalletId: PalletId = PalletId(*b"py/e1155");
+    pub const MaxNestingDepth: u32 = 8;
 }
 
 impl pallet_erc1155::Config for Runtime {
 	type Event = Event;
 	type TokenId = u32;
 	type Balance = Balance;
+	type CollectionId = u32;
 	type Decimals = Decimals;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxCheckpoints = MaxCheckpoints;
+	type MigrationItemsPerBlock = MigrationItemsPerBlock;
+	type MaxBatchSize = MaxBatchSize;
+	type MaxDataLength = MaxDataLength;
+	type StringLimit = StringLimit;
+	type OnReceived = ();
+	type Currency = Balances;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type MaxEmissionSchedules = MaxEmissionSchedules;
+	type MaxLocks = MaxLocks;
+	type PalletId = Erc1155PalletId;
+	type MaxNestingDepth = MaxNestingDepth;
+	type OnDust = ();
+	type FeeExempt = TokenFeeExempt;
+	type WeightInfo = pallet_erc1155::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -294,6 +335,255 @@ impl pallet_dex::Config for Runtime {
 	type Event = Event;
 	type Tokens = Erc1155;
 	type Fee = Fee;
+	type GovernanceOrigin = pallet_governance::EnsureGovernance;
+	type WeightInfo = pallet_dex::weights::SubstrateWeight<Runtime>;
+}
+
+/// Dex pools, the fee account of `pallet_token_payment` and the htlc, marketplace, auction and
+/// airdrop escrows never pay token transfer fees.
+pub struct TokenFeeExempt;
+impl frame_support::traits::Contains<AccountId> for TokenFeeExempt {
+	fn contains(who: &AccountId) -> bool {
+		pallet_dex::PoolAccount::<Runtime>::contains(who) ||
+			who == &TokenPayment::account_id() ||
+			who == &Htlc::account_id() ||
+			who == &Marketplace::account_id() ||
+			who == &Auction::account_id() ||
+			who == &Airdrop::account_id()
+	}
+}
+
+parameter_types! {
+    // erc1155 token standing in for the native currency in the dex pool, created at genesis
+    pub const NativeTokenId: u32 = 0;
+    pub const MinPoolLiquidity: Balance = 1_000_000_000;
+    pub const TokenPaymentPalletId: PalletId = PalletId(*b"py/tkpay");
+    pub TokenFeeCollector: AccountId = PalletId(*b"py/tkfee").into_account();
+}
+
+impl pallet_token_payment::Config for Runtime {
+	type Event = Event;
+	type NativeTokenId = NativeTokenId;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type FeeRateOrigin = pallet_governance::EnsureGovernance;
+	type FeeCollector = TokenFeeCollector;
+	type PalletId = TokenPaymentPalletId;
+	type WeightInfo = pallet_token_payment::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const MinVestedTransfer: Balance = 1_000_000;
+    pub const MaxVestingSchedules: u32 = 28;
+    pub const ScheduleDeposit: Balance = 10_000;
+}
+
+impl pallet_token_vesting::Config for Runtime {
+	type Event = Event;
+	type Tokens = Erc1155;
+	type MinVestedTransfer = MinVestedTransfer;
+	type MaxVestingSchedules = MaxVestingSchedules;
+	type Currency = Balances;
+	type ScheduleDeposit = ScheduleDeposit;
+	type WeightInfo = pallet_token_vesting::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const FractionalizationPalletId: PalletId = PalletId(*b"py/fract");
+}
+
+impl pallet_fractionalization::Config for Runtime {
+	type Event = Event;
+	type Tokens = Erc1155;
+	type Currency = Balances;
+	type PalletId = FractionalizationPalletId;
+	type WeightInfo = pallet_fractionalization::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const HtlcPalletId: PalletId = PalletId(*b"py/htlc0");
+    pub const MaxPreimageLength: u32 = 64;
+    pub const MinHtlcTimeout: BlockNumber = HOURS;
+    pub const MaxHtlcTimeout: BlockNumber = 30 * DAYS;
+}
+
+impl pallet_htlc::Config for Runtime {
+	type Event = Event;
+	type Tokens = Erc1155;
+	type PalletId = HtlcPalletId;
+	type MaxPreimageLength = MaxPreimageLength;
+	type MinTimeout = MinHtlcTimeout;
+	type MaxTimeout = MaxHtlcTimeout;
+	type WeightInfo = pallet_htlc::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const MarketplacePalletId: PalletId = PalletId(*b"py/marke");
+}
+
+impl pallet_marketplace::Config for Runtime {
+	type Event = Event;
+	type Tokens = Erc1155;
+	type Currency = Balances;
+	type PalletId = MarketplacePalletId;
+	type WeightInfo = pallet_marketplace::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const AuctionPalletId: PalletId = PalletId(*b"py/auctn");
+    pub const MinAuctionDuration: BlockNumber = 10 * MINUTES;
+    pub const MaxAuctionDuration: BlockNumber = 30 * DAYS;
+    pub const AuctionExtensionPeriod: BlockNumber = 5 * MINUTES;
+    pub const MaxAuctionsEndingPerBlock: u32 = 50;
+}
+
+impl pallet_auction::Config for Runtime {
+	type Event = Event;
+	type Tokens = Erc1155;
+	type Currency = Balances;
+	type PalletId = AuctionPalletId;
+	type MinDuration = MinAuctionDuration;
+	type MaxDuration = MaxAuctionDuration;
+	type ExtensionPeriod = AuctionExtensionPeriod;
+	type MaxEndingPerBlock = MaxAuctionsEndingPerBlock;
+	type WeightInfo = pallet_auction::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const MaxRentalPeriod: BlockNumber = 90 * DAYS;
+    pub const MaxRentalsExpiringPerBlock: u32 = 50;
+}
+
+impl pallet_rental::Config for Runtime {
+	type Event = Event;
+	type Tokens = Erc1155;
+	type Currency = Balances;
+	type MaxRentalPeriod = MaxRentalPeriod;
+	type MaxExpiringPerBlock = MaxRentalsExpiringPerBlock;
+	type WeightInfo = pallet_rental::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    /// Erc1155 token whose holders vote on proposals.
+    pub const GovernanceTokenId: u32 = 1;
+    pub const ProposalThreshold: Balance = 1_000 * 1_000_000; // 1000 tokens with 6 decimals
+    pub const VotingPeriod: BlockNumber = 7 * DAYS;
+    pub const VoteLockPeriod: BlockNumber = 7 * DAYS;
+    pub const GovernanceQuorum: Perbill = Perbill::from_percent(20);
+    pub const ProposalDeposit: Balance = 1_000_000_000;
+    pub const MaxVoters: u32 = 1_024;
+}
+
+impl pallet_governance::Config for Runtime {
+	type Event = Event;
+	type Tokens = Erc1155;
+	type Origin = Origin;
+	type Proposal = Call;
+	type GovernanceTokenId = GovernanceTokenId;
+	type ProposalThreshold = ProposalThreshold;
+	type Currency = Balances;
+	type ProposalDeposit = ProposalDeposit;
+	type MaxVoters = MaxVoters;
+	type VotingPeriod = VotingPeriod;
+	type LockPeriod = VoteLockPeriod;
+	type Quorum = GovernanceQuorum;
+	type WeightInfo = pallet_governance::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const AirdropPalletId: PalletId = PalletId(*b"py/airdr");
+    pub const MaxAirdropTokens: u32 = 16;
+    // trees of up to 2^24 claims
+    pub const MaxAirdropProofLength: u32 = 24;
+    pub const MinAirdropClaimPeriod: BlockNumber = 7 * DAYS;
+}
+
+impl pallet_airdrop::Config for Runtime {
+	type Event = Event;
+	type Tokens = Erc1155;
+	type PalletId = AirdropPalletId;
+	type MaxTokens = MaxAirdropTokens;
+	type MaxProofLength = MaxAirdropProofLength;
+	type MinClaimPeriod = MinAirdropClaimPeriod;
+	type WeightInfo = pallet_airdrop::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const MaxLootBoxOutcomes: u32 = 32;
+    // seeds mix in the hash of a block that doesn't exist when the box is burned
+    pub const LootBoxRevealDelay: BlockNumber = 2;
+    pub const LootBoxRevealPeriod: BlockNumber = DAYS;
+}
+
+impl pallet_loot_box::Config for Runtime {
+	type Event = Event;
+	type Tokens = Erc1155;
+	type Randomness = RandomnessCollectiveFlip;
+	type MaxOutcomes = MaxLootBoxOutcomes;
+	type RevealDelay = LootBoxRevealDelay;
+	type RevealPeriod = LootBoxRevealPeriod;
+	type WeightInfo = pallet_loot_box::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const MaxCraftingItems: u32 = 16;
+}
+
+impl pallet_crafting::Config for Runtime {
+	type Event = Event;
+	type Tokens = Erc1155;
+	type MaxItems = MaxCraftingItems;
+	type WeightInfo = pallet_crafting::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const ChainId: u64 = 42;
+    pub BlockGasLimit: U256 = U256::from(u32::max_value());
+    pub PrecompilesValue: Precompiles<Runtime> = Precompiles::<_>::new();
+}
+
+impl pallet_evm::Config for Runtime {
+	type FeeCalculator = ();
+	type GasWeightMapping = ();
+	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+	type CallOrigin = EnsureAddressTruncated;
+	type WithdrawOrigin = EnsureAddressTruncated;
+	type AddressMapping = HashedAddressMapping<BlakeTwo256>;
+	type Currency = Balances;
+	type Event = Event;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type PrecompilesType = Precompiles<Self>;
+	type PrecompilesValue = PrecompilesValue;
+	type ChainId = ChainId;
+	type BlockGasLimit = BlockGasLimit;
+	type OnChargeTransaction = ();
+	type FindAuthor = ();
+}
+
+parameter_types! {
+    pub const ContractDeposit: Balance = 1_000_000;
+    pub const DeletionQueueDepth: u32 = 128;
+    // The lazy deletion runs inside on_initialize.
+    pub DeletionWeightLimit: Weight = Perbill::from_percent(10) *
+        BlockWeights::get().max_block;
+    pub Schedule: pallet_contracts::Schedule<Runtime> = Default::default();
+}
+
+impl pallet_contracts::Config for Runtime {
+	type Time = Timestamp;
+	type Randomness = RandomnessCollectiveFlip;
+	type Currency = Balances;
+	type Event = Event;
+	type Call = Call;
+	/// Contracts interact with the chain through the chain extension only.
+	type CallFilter = frame_support::traits::Nothing;
+	type ContractDeposit = ContractDeposit;
+	type CallStack = [pallet_contracts::Frame<Self>; 31];
+	type WeightPrice = pallet_transaction_payment::Pallet<Self>;
+	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
+	type ChainExtension = pallet_chain_extension::Erc1155Extension;
+	type DeletionQueueDepth = DeletionQueueDepth;
+	type DeletionWeightLimit = DeletionWeightLimit;
+	type Schedule = Schedule;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -313,6 +603,19 @@ construct_runtime!(
 		Sudo: pallet_sudo,
 		Dex: pallet_dex,
 		Erc1155: pallet_erc1155,
+		TokenVesting: pallet_token_vesting,
+		Fractionalization: pallet_fractionalization,
+		EVM: pallet_evm,
+		Contracts: pallet_contracts,
+		TokenPayment: pallet_token_payment,
+		Htlc: pallet_htlc,
+		Marketplace: pallet_marketplace,
+		Auction: pallet_auction,
+		Rental: pallet_rental,
+		Governance: pallet_governance,
+		Airdrop: pallet_airdrop,
+		LootBox: pallet_loot_box,
+		Crafting: pallet_crafting,
 	}
 );
 
@@ -330,7 +633,7 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_token_payment::ChargeTokenPayment<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -474,6 +777,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_erc1155_runtime_api::Erc1155Api<Block, AccountId, u32, Balance, BlockNumber> for Runtime {
+		fn balance_of_at(account: AccountId, token_id: u32, block: BlockNumber) -> Option<Balance> {
+			Erc1155::balance_of_at(&account, token_id, block).ok()
+		}
+
+		fn total_supply_at(token_id: u32, block: BlockNumber) -> Option<Balance> {
+			Erc1155::total_supply_at(token_id, block).ok()
+		}
+
+		fn attributes(token_id: u32) -> Vec<(AttributeNamespace<AccountId>, Vec<u8>, Vec<u8>)> {
+			Erc1155::get_attributes(token_id)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -491,7 +808,19 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, frame_system, SystemBench::<Runtime>);
 			list_benchmark!(list, extra, pallet_balances, Balances);
 			list_benchmark!(list, extra, pallet_timestamp, Timestamp);
-			list_benchmark!(list, extra, pallet_template, TemplateModule);
+			list_benchmark!(list, extra, pallet_erc1155, Erc1155);
+			list_benchmark!(list, extra, pallet_dex, Dex);
+			list_benchmark!(list, extra, pallet_token_vesting, TokenVesting);
+			list_benchmark!(list, extra, pallet_fractionalization, Fractionalization);
+			list_benchmark!(list, extra, pallet_htlc, Htlc);
+			list_benchmark!(list, extra, pallet_marketplace, Marketplace);
+			list_benchmark!(list, extra, pallet_auction, Auction);
+			list_benchmark!(list, extra, pallet_rental, Rental);
+			list_benchmark!(list, extra, pallet_governance, Governance);
+			list_benchmark!(list, extra, pallet_airdrop, Airdrop);
+			list_benchmark!(list, extra, pallet_loot_box, LootBox);
+			list_benchmark!(list, extra, pallet_crafting, Crafting);
+			list_benchmark!(list, extra, pallet_token_payment, TokenPayment);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -529,7 +858,19 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, frame_system, SystemBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
-			add_benchmark!(params, batches, pallet_template, TemplateModule);
+			add_benchmark!(params, batches, pallet_erc1155, Erc1155);
+			add_benchmark!(params, batches, pallet_dex, Dex);
+			add_benchmark!(params, batches, pallet_token_vesting, TokenVesting);
+			add_benchmark!(params, batches, pallet_fractionalization, Fractionalization);
+			add_benchmark!(params, batches, pallet_htlc, Htlc);
+			add_benchmark!(params, batches, pallet_marketplace, Marketplace);
+			add_benchmark!(params, batches, pallet_auction, Auction);
+			add_benchmark!(params, batches, pallet_rental, Rental);
+			add_benchmark!(params, batches, pallet_governance, Governance);
+			add_benchmark!(params, batches, pallet_airdrop, Airdrop);
+			add_benchmark!(params, batches, pallet_loot_box, LootBox);
+			add_benchmark!(params, batches, pallet_crafting, Crafting);
+			add_benchmark!(params, batches, pallet_token_payment, TokenPayment);
 
 			Ok(batches)
 		}
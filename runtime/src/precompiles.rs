@@ -0,0 +1,58 @@
+use pallet_erc1155_precompile::Erc1155Precompile;
+use pallet_evm::{Context, Precompile, PrecompileResult, PrecompileSet};
+use pallet_evm_precompile_simple::{ECRecover, Identity, Ripemd160, Sha256};
+use sp_core::H160;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Address of the IERC1155 precompile for `pallet_erc1155` tokens.
+pub const ERC1155_PRECOMPILE: u64 = 1024;
+
+pub struct Precompiles<R>(PhantomData<R>);
+
+impl<R> Precompiles<R>
+where
+	R: pallet_evm::Config,
+{
+	pub fn new() -> Self {
+		Self(Default::default())
+	}
+
+	pub fn used_addresses() -> Vec<H160> {
+		[1, 2, 3, 4, ERC1155_PRECOMPILE].into_iter().map(hash).collect()
+	}
+}
+
+impl<R> PrecompileSet for Precompiles<R>
+where
+	R: pallet_evm::Config + pallet_erc1155::Config,
+	Erc1155Precompile<R>: Precompile,
+{
+	fn execute(
+		&self,
+		address: H160,
+		input: &[u8],
+		target_gas: Option<u64>,
+		context: &Context,
+		is_static: bool,
+	) -> Option<PrecompileResult> {
+		match address {
+			// Ethereum precompiles
+			a if a == hash(1) => Some(ECRecover::execute(input, target_gas, context, is_static)),
+			a if a == hash(2) => Some(Sha256::execute(input, target_gas, context, is_static)),
+			a if a == hash(3) => Some(Ripemd160::execute(input, target_gas, context, is_static)),
+			a if a == hash(4) => Some(Identity::execute(input, target_gas, context, is_static)),
+			// Non-Ethereum precompiles
+			a if a == hash(ERC1155_PRECOMPILE) =>
+				Some(Erc1155Precompile::<R>::execute(input, target_gas, context, is_static)),
+			_ => None,
+		}
+	}
+
+	fn is_precompile(&self, address: H160) -> bool {
+		Self::used_addresses().contains(&address)
+	}
+}
+
+fn hash(a: u64) -> H160 {
+	H160::from_low_u64_be(a)
+}
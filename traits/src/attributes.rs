@@ -0,0 +1,29 @@
+use crate::Erc1155;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchResult, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// Who controls an attribute of a token.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum AttributeNamespace<AccountId> {
+	/// Written by the token issuer or the admins of its collection.
+	Issuer,
+	/// Written by a holder of the token, one namespace per holder.
+	Owner(AccountId),
+	/// Written only by other pallets through `Erc1155Attributes`.
+	Pallet,
+}
+
+/// Attribute access for pallets, writes go to the `Pallet` namespace and take no deposit.
+pub trait Erc1155Attributes<AccountId>: Erc1155<AccountId> {
+	fn attribute(
+		token_id: Self::TokenId,
+		namespace: &AttributeNamespace<AccountId>,
+		key: &[u8],
+	) -> Option<Vec<u8>>;
+
+	fn set_attribute(token_id: Self::TokenId, key: &[u8], value: &[u8]) -> DispatchResult;
+
+	fn clear_attribute(token_id: Self::TokenId, key: &[u8]) -> DispatchResult;
+}
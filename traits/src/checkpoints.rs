@@ -0,0 +1,18 @@
+use crate::Erc1155;
+use sp_runtime::DispatchError;
+
+/// Historical balance queries for tokens with checkpointing enabled.
+pub trait Erc1155Checkpoints<AccountId, BlockNumber>: Erc1155<AccountId> {
+	/// Balance of `account` at the end of `block`.
+	fn balance_of_at(
+		account: &AccountId,
+		token_id: Self::TokenId,
+		block: BlockNumber,
+	) -> Result<Self::Balance, DispatchError>;
+
+	/// Total supply of `token_id` at the end of `block`.
+	fn total_supply_at(
+		token_id: Self::TokenId,
+		block: BlockNumber,
+	) -> Result<Self::Balance, DispatchError>;
+}
@@ -0,0 +1,9 @@
+/// Handler for balances left below the minimum balance of their token. The dust has already been
+/// burned when the handler is called.
+pub trait OnErc1155Dust<AccountId, TokenId, Balance> {
+	fn on_dust(who: &AccountId, token_id: TokenId, amount: Balance);
+}
+
+impl<AccountId, TokenId, Balance> OnErc1155Dust<AccountId, TokenId, Balance> for () {
+	fn on_dust(_who: &AccountId, _token_id: TokenId, _amount: Balance) {}
+}
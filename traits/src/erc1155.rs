@@ -25,6 +25,13 @@ pub trait Erc1155<AccountId> {
 		+ Debug
 		+ Default
 		+ scale_info::TypeInfo;
+	type CollectionId: FullCodec
+		+ Eq
+		+ PartialEq
+		+ Copy
+		+ MaybeSerializeDeserialize
+		+ Debug
+		+ scale_info::TypeInfo;
 
 	fn init(
 		who: &AccountId,
@@ -44,6 +51,7 @@ pub trait Erc1155<AccountId> {
 		token_ids: Vec<Self::TokenId>,
 	) -> Result<Vec<Self::Balance>, sp_runtime::DispatchError>;
 
+	/// Move tokens from `from` to `to`, which pulls them as an operator approved by `from`.
 	fn transfer_from_single(
 		from: &AccountId,
 		to: &AccountId,
@@ -51,6 +59,7 @@ pub trait Erc1155<AccountId> {
 		amount: &Self::Balance,
 	) -> DispatchResult;
 
+	/// Batch version of `transfer_from_single`.
 	fn transfer_from_batch(
 		from: &AccountId,
 		to: &AccountId,
@@ -64,6 +73,10 @@ pub trait Erc1155<AccountId> {
 		token_id: Self::TokenId,
 		amount: Self::Balance,
 	) -> DispatchResult;
-	
+
+	fn collection_of(token_id: Self::TokenId) -> Option<Self::CollectionId>;
+
+	fn is_collection_admin(collection_id: Self::CollectionId, who: &AccountId) -> bool;
+
 	fn set_approval_for_all(owner: &AccountId, operator: &AccountId, approved: bool);
 }
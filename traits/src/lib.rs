@@ -1,5 +1,21 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub use attributes::{AttributeNamespace, Erc1155Attributes};
+pub use checkpoints::Erc1155Checkpoints;
+pub use dust::OnErc1155Dust;
 pub use erc1155::Erc1155;
+pub use lockable::{Erc1155Lockable, LockIdentifier};
+pub use mintable::Erc1155Mintable;
+pub use receiver::OnErc1155Received;
+pub use royalties::Erc1155Royalties;
+pub use usage::UsageRights;
 
+pub mod attributes;
+pub mod checkpoints;
+pub mod dust;
 pub mod erc1155;
+pub mod lockable;
+pub mod mintable;
+pub mod receiver;
+pub mod royalties;
+pub mod usage;
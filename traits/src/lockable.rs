@@ -0,0 +1,20 @@
+use crate::Erc1155;
+use sp_runtime::DispatchResult;
+
+pub type LockIdentifier = [u8; 8];
+
+/// Named locks on token balances. Locks overlap, the largest one decides how much of the
+/// balance cannot be transferred.
+pub trait Erc1155Lockable<AccountId>: Erc1155<AccountId> {
+	/// Lock `amount` of the balance of `who` under `id`, replacing any lock with the same id.
+	fn set_lock(
+		id: LockIdentifier,
+		who: &AccountId,
+		token_id: Self::TokenId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	fn remove_lock(id: LockIdentifier, who: &AccountId, token_id: Self::TokenId);
+
+	fn locked_balance(who: &AccountId, token_id: Self::TokenId) -> Self::Balance;
+}
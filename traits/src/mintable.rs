@@ -0,0 +1,23 @@
+use crate::Erc1155;
+use sp_runtime::DispatchResult;
+
+/// Supply management for pallets. Amounts are in base units, unlike `Erc1155::init`.
+pub trait Erc1155Mintable<AccountId>: Erc1155<AccountId> {
+	/// Register `token_id` with zero supply, issued by `owner`.
+	fn create(owner: &AccountId, token_id: Self::TokenId) -> DispatchResult;
+
+	fn mint(to: &AccountId, token_id: Self::TokenId, amount: Self::Balance) -> DispatchResult;
+
+	fn burn(from: &AccountId, token_id: Self::TokenId, amount: Self::Balance) -> DispatchResult;
+
+	/// Credit `amount` arriving from another chain. Unlike `mint` it does not use up the cap or
+	/// the emission schedules of the token, only the total supply has to stay within the cap.
+	fn deposit(to: &AccountId, token_id: Self::TokenId, amount: Self::Balance) -> DispatchResult;
+
+	/// Debit `amount` leaving for another chain, so it can be deposited again on return.
+	fn withdraw(from: &AccountId, token_id: Self::TokenId, amount: Self::Balance)
+		-> DispatchResult;
+
+	/// Whether `who` issues `token_id`, as its owner or an admin of its collection.
+	fn is_issuer(who: &AccountId, token_id: Self::TokenId) -> bool;
+}
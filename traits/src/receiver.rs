@@ -0,0 +1,51 @@
+use sp_runtime::DispatchResult;
+
+/// Receiver hook run after tokens are credited to `to`. Returning an error rejects the transfer.
+pub trait OnErc1155Received<AccountId, TokenId, Balance> {
+	fn on_received(
+		operator: &AccountId,
+		from: &AccountId,
+		to: &AccountId,
+		token_id: TokenId,
+		amount: Balance,
+		data: &[u8],
+	) -> DispatchResult;
+
+	fn on_batch_received(
+		operator: &AccountId,
+		from: &AccountId,
+		to: &AccountId,
+		token_ids: &[TokenId],
+		amounts: &[Balance],
+		data: &[u8],
+	) -> DispatchResult
+	where
+		TokenId: Copy,
+		Balance: Copy,
+	{
+		for (token_id, amount) in token_ids.iter().zip(amounts) {
+			Self::on_received(operator, from, to, *token_id, *amount, data)?;
+		}
+		Ok(())
+	}
+
+	/// Weight of notifying `count` receipts, charged on top of the transfer itself.
+	fn on_received_weight(count: u32) -> u64;
+}
+
+impl<AccountId, TokenId, Balance> OnErc1155Received<AccountId, TokenId, Balance> for () {
+	fn on_received(
+		_operator: &AccountId,
+		_from: &AccountId,
+		_to: &AccountId,
+		_token_id: TokenId,
+		_amount: Balance,
+		_data: &[u8],
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn on_received_weight(_count: u32) -> u64 {
+		0
+	}
+}
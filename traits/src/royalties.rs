@@ -0,0 +1,9 @@
+use crate::Erc1155;
+use sp_runtime::Perbill;
+
+/// Royalties owed on secondary sales, in the spirit of EIP-2981.
+pub trait Erc1155Royalties<AccountId>: Erc1155<AccountId> {
+	/// Recipient and share of the sale price owed for selling `token_id`, `None` if no royalty
+	/// applies.
+	fn royalty_of(token_id: Self::TokenId) -> Option<(AccountId, Perbill)>;
+}
@@ -0,0 +1,18 @@
+/// Time-bounded rights to use an item without owning it, in the spirit of ERC-4907.
+pub trait UsageRights<AccountId, TokenId, BlockNumber> {
+	/// Account allowed to use `token_id`, `None` if nobody but the owner may use it.
+	fn user_of(token_id: TokenId) -> Option<AccountId>;
+
+	/// Block the current usage rights on `token_id` end at.
+	fn user_expires(token_id: TokenId) -> Option<BlockNumber>;
+}
+
+impl<AccountId, TokenId, BlockNumber> UsageRights<AccountId, TokenId, BlockNumber> for () {
+	fn user_of(_token_id: TokenId) -> Option<AccountId> {
+		None
+	}
+
+	fn user_expires(_token_id: TokenId) -> Option<BlockNumber> {
+		None
+	}
+}